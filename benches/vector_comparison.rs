@@ -0,0 +1,90 @@
+// benches/vector_comparison.rs : test_help-rs
+//
+// Compares the cost of evaluating large `f64` vectors via the trait-object
+// form, `evaluate_vector_eq_approx()`, with that of the monomorphized form,
+// `evaluate_vector_eq_approx_with()`, for the stock margin and multiplier
+// evaluators.
+//
+// Run with:
+//
+//     cargo bench --bench vector_comparison
+
+use test_helpers::{
+    evaluate_vector_eq_approx,
+    evaluate_vector_eq_approx_with,
+    margin,
+    multiplier,
+    traits::ApproximateEqualityEvaluator,
+};
+
+use std::{
+    hint::black_box,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+
+const LENGTH : usize = 1_000_000;
+const ITERATIONS : u32 = 50;
+
+fn time<F>(mut f : F) -> Duration
+where
+    F : FnMut(),
+{
+    // warm up
+    f();
+
+    let started = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        f();
+    }
+
+    started.elapsed() / ITERATIONS
+}
+
+fn bench<E>(
+    name : &str,
+    expected : &[f64],
+    actual : &[f64],
+    evaluator : E,
+) where
+    E : ApproximateEqualityEvaluator,
+{
+    let dyn_evaluator : &dyn ApproximateEqualityEvaluator = &evaluator;
+
+    let dyn_elapsed = time(|| {
+        black_box(evaluate_vector_eq_approx(
+            black_box(&expected),
+            black_box(&actual),
+            black_box(dyn_evaluator),
+        ));
+    });
+    let generic_elapsed = time(|| {
+        black_box(evaluate_vector_eq_approx_with(
+            black_box(&expected),
+            black_box(&actual),
+            black_box(&evaluator),
+        ));
+    });
+
+    println!(
+        "{name:<12} dyn: {dyn_elapsed:>12?}  generic: {generic_elapsed:>12?}  speedup: {:.2}x",
+        dyn_elapsed.as_secs_f64() / generic_elapsed.as_secs_f64()
+    );
+}
+
+fn main() {
+    let expected : Vec<f64> = (0..LENGTH).map(|i| (i as f64).sin() * 1000.0).collect();
+    let actual : Vec<f64> = expected.iter().map(|x| x * (1.0 + 1e-12)).collect();
+
+    println!("comparing vectors of {LENGTH} elements, averaged over {ITERATIONS} iterations:");
+
+    bench("margin", &expected, &actual, margin(1e-6));
+    bench("multiplier", &expected, &actual, multiplier(1e-6));
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -33,6 +33,28 @@ pub mod constants {
 
     /// The default multiplier.
     pub const DEFAULT_MULTIPLIER : f64 = 0.000001;
+
+    /// The default margin for comparands of type `f32`, as used by its
+    /// implementation of [`DefaultTolerance`](crate::traits::DefaultTolerance).
+    pub const DEFAULT_F32_MARGIN : f64 = 0.0001;
+
+    /// The default multiplier for comparands of type `f32`, as used by its
+    /// implementation of [`DefaultTolerance`](crate::traits::DefaultTolerance),
+    /// which is looser than [`DEFAULT_MULTIPLIER`] in keeping with the
+    /// (approximately 7 significant decimal digit) precision of `f32`.
+    pub const DEFAULT_F32_MULTIPLIER : f64 = 0.00001;
+
+    /// The name of the environment variable that, when set to a valid
+    /// non-negative value, overrides [`DEFAULT_MARGIN`] (and
+    /// [`DEFAULT_F32_MARGIN`]) for the two-parameter forms of the assertion
+    /// macros.
+    pub const DEFAULT_MARGIN_ENVIRONMENT_VARIABLE : &str = "TEST_HELP_DEFAULT_MARGIN";
+
+    /// The name of the environment variable that, when set to a valid
+    /// non-negative value, overrides [`DEFAULT_MULTIPLIER`] (and
+    /// [`DEFAULT_F32_MULTIPLIER`]) for the two-parameter forms of the
+    /// assertion macros.
+    pub const DEFAULT_MULTIPLIER_ENVIRONMENT_VARIABLE : &str = "TEST_HELP_DEFAULT_MULTIPLIER";
 }
 
 
@@ -40,12 +62,34 @@ pub mod constants {
 // types
 
 /// Comparison result type.
+///
+/// # Ordering
+///
+/// The (derived) ordering is a scale of *severity*, in which a greater
+/// result is a worse one:
+///
+/// ```text
+/// ExactlyEqual < BothNaN < ApproximatelyEqual < Unequal
+/// ```
+///
+/// so that, e.g., `r > ComparisonResult::ApproximatelyEqual` holds only for
+/// a failed comparison, and [`ComparisonResult::combine()`] yields the
+/// greater of its operands. It does *not* order by closeness of the
+/// comparands - two `ApproximatelyEqual` results are equal, however near
+/// each came to the edge of its tolerance - and a failure is "greater"
+/// than a pass, which may surprise those who expect the reverse. Prefer
+/// [`ComparisonResult::passed()`] and [`ComparisonResult::failed()`] where
+/// only pass/fail is of interest.
 #[derive(Debug)]
 #[derive(PartialEq)]
 #[derive(PartialOrd)]
 pub enum ComparisonResult {
     /// The comparands are exactly equal.
     ExactlyEqual,
+    /// The comparands are both NaN, and so are deemed equal by virtue of
+    /// the `"nan-equality"` feature, without which this is never the
+    /// result of the stock evaluators.
+    BothNaN,
     /// The comparands are equal within the tolerance of the given margin or
     /// multiplier.
     ApproximatelyEqual,
@@ -54,8 +98,83 @@ pub enum ComparisonResult {
     Unequal,
 }
 
+impl ComparisonResult {
+    /// Combines two results into the worst case of the two, wherein
+    /// `Unequal` dominates `ApproximatelyEqual`, which dominates `BothNaN`,
+    /// which in turn dominates `ExactlyEqual`.
+    pub fn combine(
+        self,
+        other : ComparisonResult,
+    ) -> ComparisonResult {
+        match (self, other) {
+            (ComparisonResult::Unequal, _) | (_, ComparisonResult::Unequal) => ComparisonResult::Unequal,
+            (ComparisonResult::ApproximatelyEqual, _) | (_, ComparisonResult::ApproximatelyEqual) => {
+                ComparisonResult::ApproximatelyEqual
+            },
+            (ComparisonResult::BothNaN, _) | (_, ComparisonResult::BothNaN) => ComparisonResult::BothNaN,
+            (ComparisonResult::ExactlyEqual, ComparisonResult::ExactlyEqual) => ComparisonResult::ExactlyEqual,
+        }
+    }
+
+    /// Indicates whether the comparison passed, i.e. whether the comparands
+    /// are exactly or approximately equal, or are both NaN (when that is
+    /// deemed equal).
+    pub fn passed(&self) -> bool {
+        matches!(
+            self,
+            ComparisonResult::ExactlyEqual | ComparisonResult::BothNaN | ComparisonResult::ApproximatelyEqual
+        )
+    }
+
+    /// Indicates whether the comparison failed, i.e. whether the comparands
+    /// are unequal.
+    pub fn failed(&self) -> bool {
+        !self.passed()
+    }
+
+    /// Indicates whether the comparands are unequal, which is the passing
+    /// outcome of an inequality assertion.
+    pub fn is_unequal(&self) -> bool {
+        matches!(self, ComparisonResult::Unequal)
+    }
+
+    /// Indicates whether the comparands are exactly equal.
+    pub fn is_exact(&self) -> bool {
+        matches!(self, ComparisonResult::ExactlyEqual)
+    }
+
+    /// Indicates whether the comparands are approximately, but not
+    /// exactly, equal.
+    pub fn is_approximate(&self) -> bool {
+        matches!(self, ComparisonResult::ApproximatelyEqual)
+    }
+
+    /// Indicates whether the comparands are both NaN, and so deemed equal
+    /// by virtue of the `"nan-equality"` feature.
+    pub fn is_both_nan(&self) -> bool {
+        matches!(self, ComparisonResult::BothNaN)
+    }
+
+    /// Obtains a compact, machine-parseable tag summarising the result,
+    /// for use by custom reporters. The tag grammar, which is stable, is:
+    ///
+    /// ```text
+    /// tag := "PASS_EXACT" | "PASS_BOTH_NAN" | "PASS_APPROX" | "FAIL_UNEQUAL"
+    /// ```
+    pub fn summary_tag(&self) -> String {
+        match self {
+            ComparisonResult::ExactlyEqual => "PASS_EXACT",
+            ComparisonResult::BothNaN => "PASS_BOTH_NAN",
+            ComparisonResult::ApproximatelyEqual => "PASS_APPROX",
+            ComparisonResult::Unequal => "FAIL_UNEQUAL",
+        }
+        .into()
+    }
+}
+
 /// Vector comparison result type.
 #[derive(Debug)]
+#[derive(PartialEq)]
 pub enum VectorComparisonResult {
     ExactlyEqual,
     ApproximatelyEqual,
@@ -70,1170 +189,15590 @@ pub enum VectorComparisonResult {
     },
 }
 
-
-/// Traits.
-pub mod traits {
-    use super::ComparisonResult;
-
-    use base_traits::ToF64;
-
-    use std::fmt as std_fmt;
-
-
-    /// Trait that defines a mechanism for performing approximate equality
-    /// evaluation.
-    pub trait ApproximateEqualityEvaluator {
-        fn evaluate(
-            &self,
-            expected : f64,
-            actual : f64,
-        ) -> (
-            ComparisonResult, // comparison_result
-            Option<f64>,      // margin_factor
-            Option<f64>,      // multiplier_factor
-        );
+impl VectorComparisonResult {
+    /// Indicates whether the comparison passed, i.e. whether the vectors
+    /// are exactly or approximately equal.
+    pub fn passed(&self) -> bool {
+        matches!(
+            self,
+            VectorComparisonResult::ExactlyEqual | VectorComparisonResult::ApproximatelyEqual
+        )
     }
 
-    /// Trait that allows an implementing type instance to be evaluated with the
-    /// constructs of this crate.
-    ///
-    /// NOTE: it is implemented for any types that implement
-    /// `base_traits::ToF64` (and `std::fmt::Debug`).
-    pub trait TestableAsF64: std_fmt::Debug {
-        fn testable_as_f64(&self) -> f64;
+    /// Indicates whether the comparison failed, i.e. whether the vectors
+    /// differ in length or have unequal elements.
+    pub fn failed(&self) -> bool {
+        !self.passed()
     }
 
-    impl<T> TestableAsF64 for T
-    where
-        T : ToF64 + std_fmt::Debug,
-    {
-        fn testable_as_f64(&self) -> f64 {
-            self.to_f64()
+    /// Obtains a compact, machine-parseable tag summarising the result,
+    /// for use by custom reporters. The tag grammar, which is stable, is:
+    ///
+    /// ```text
+    /// tag := "PASS_EXACT"
+    ///      | "PASS_APPROX"
+    ///      | "FAIL_LENGTH@" expected-length ":" actual-length
+    ///      | "FAIL_UNEQUAL@" index
+    /// ```
+    ///
+    /// where `expected-length`, `actual-length`, and `index` are unsigned
+    /// decimal integers, the latter being that of the first unequal
+    /// element.
+    pub fn summary_tag(&self) -> String {
+        match self {
+            VectorComparisonResult::ExactlyEqual => "PASS_EXACT".into(),
+            VectorComparisonResult::ApproximatelyEqual => "PASS_APPROX".into(),
+            VectorComparisonResult::DifferentLengths {
+                expected_length,
+                actual_length,
+            } => format!("FAIL_LENGTH@{expected_length}:{actual_length}"),
+            VectorComparisonResult::UnequalElements {
+                index_of_first_unequal_element,
+                ..
+            } => format!("FAIL_UNEQUAL@{index_of_first_unequal_element}"),
         }
     }
 }
 
+/// An unequal element of a vector comparison.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct VectorElementMismatch {
+    pub index :          usize,
+    pub expected_value : f64,
+    pub actual_value :   f64,
+}
 
-mod internal {
-
-    use super::{
-        traits::ApproximateEqualityEvaluator,
-        utils::{
-            compare_approximate_equality_by_margin,
-            compare_approximate_equality_by_multiplier,
-            compare_approximate_equality_by_zero_margin_or_multiplier,
-        },
-        ComparisonResult,
-    };
-
-
-    /// T.B.C.
-    #[derive(Debug)]
-    pub struct MarginEvaluator {
-        pub(crate) factor : f64,
-    }
+/// Unordered (multiset) vector comparison result type.
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum UnorderedComparisonResult {
+    ExactlyEqual,
+    ApproximatelyEqual,
+    /// Some elements could not be matched, listed in their original order.
+    Unmatched {
+        unmatched_expected_values : Vec<f64>,
+        unmatched_actual_values :   Vec<f64>,
+    },
+}
 
-    /// T.B.C.
-    #[derive(Debug)]
-    pub struct MultiplierEvaluator {
-        pub(crate) factor : f64,
-    }
+/// Set comparison result type, as used by [`evaluate_set_eq_approx()`].
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum SetComparisonResult {
+    ExactlyEqual,
+    ApproximatelyEqual,
+    /// The first (in the order given) expected value that has no
+    /// approximately-equal partner in the actual set.
+    UnmatchedExpectedValue {
+        expected_value : f64,
+    },
+    /// The first (in the order given) actual value that has no
+    /// approximately-equal partner in the expected set, reported only when
+    /// every expected value has been matched.
+    UnmatchedActualValue {
+        actual_value : f64,
+    },
+}
 
-    /// T.B.C.
-    #[derive(Debug)]
-    pub struct ZeroMarginOrMultiplierEvaluator {
-        pub(crate) multiplier_factor :  f64,
-        pub(crate) zero_margin_factor : f64,
-    }
+/// Optional vector comparison result type, as used by
+/// [`evaluate_optional_vector_eq_approx()`].
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum OptionalVectorComparisonResult {
+    /// All corresponding elements are `None`, or exactly equal.
+    ExactlyEqual,
+    ApproximatelyEqual,
+    DifferentLengths {
+        expected_length : usize,
+        actual_length :   usize,
+    },
+    /// At the given index one of the elements is `None` and the other is
+    /// not; exactly one of `expected_value` and `actual_value` is `None`.
+    MismatchedPresence {
+        index :          usize,
+        expected_value : Option<f64>,
+        actual_value :   Option<f64>,
+    },
+    UnequalElements {
+        index_of_first_unequal_element :          usize,
+        expected_value_of_first_unequal_element : f64,
+        actual_value_of_first_unequal_element :   f64,
+    },
+}
 
-    // Trait implementations
+/// Result comparison result type, as used by
+/// [`evaluate_result_eq_approx()`].
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum ResultComparisonResult {
+    /// Both are `Ok`, and their values compare as given.
+    Ok(ComparisonResult),
+    /// Both are `Err`, and are deemed equal in accordance with the given
+    /// [`BothErrDisposition`].
+    BothErr,
+    /// Both are `Err`, and are deemed unequal in accordance with the given
+    /// [`BothErrDisposition`]; the errors are given in `Debug` form.
+    UnequalErrs {
+        expected_error : String,
+        actual_error :   String,
+    },
+    /// Exactly one is `Err`; that error is given in `Debug` form, and the
+    /// other (`Ok`) value as `f64`.
+    MismatchedErr {
+        expected : Result<f64, String>,
+        actual :   Result<f64, String>,
+    },
+}
 
-    impl ApproximateEqualityEvaluator for MarginEvaluator {
-        fn evaluate(
-            &self,
-            expected : f64,
-            actual : f64,
-        ) -> (
-            ComparisonResult, // comparison_result
-            Option<f64>,      // margin_factor
-            Option<f64>,      // multiplier_factor
-        ) {
-            let comparison_result = compare_approximate_equality_by_margin(expected, actual, self.factor);
+/// Labeled vector comparison result type, as used by
+/// [`evaluate_vector_eq_approx_labeled()`].
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum LabeledVectorComparisonResult {
+    ExactlyEqual,
+    ApproximatelyEqual,
+    DifferentLengths {
+        expected_length : usize,
+        actual_length :   usize,
+    },
+    /// The number of labels differs from the (common) length of the
+    /// vectors.
+    DifferentLabelCount {
+        length :      usize,
+        label_count : usize,
+    },
+    UnequalElements {
+        label_of_first_unequal_element :          String,
+        index_of_first_unequal_element :          usize,
+        expected_value_of_first_unequal_element : f64,
+        actual_value_of_first_unequal_element :   f64,
+    },
+}
 
-            (comparison_result, Some(self.factor), None)
-        }
-    }
+/// Tuple vector comparison result type, as used by
+/// [`evaluate_tuple_vector_eq_approx()`].
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum TupleVectorComparisonResult {
+    ExactlyEqual,
+    ApproximatelyEqual,
+    DifferentLengths {
+        expected_length : usize,
+        actual_length :   usize,
+    },
+    /// The first component, of the first element, that is not
+    /// approximately equal.
+    UnequalComponents {
+        index :          usize,
+        component :      usize,
+        expected_value : f64,
+        actual_value :   f64,
+    },
+}
 
-    impl ApproximateEqualityEvaluator for MultiplierEvaluator {
-        fn evaluate(
-            &self,
-            expected : f64,
-            actual : f64,
-        ) -> (
-            ComparisonResult, // comparison_result
-            Option<f64>,      // margin_factor
-            Option<f64>,      // multiplier_factor
-        ) {
-            let comparison_result = compare_approximate_equality_by_multiplier(expected, actual, self.factor);
+/// Byte-buffer comparison result type, as used by
+/// [`evaluate_bytes_as_f64_eq_approx()`] and
+/// [`evaluate_bytes_as_f32_eq_approx()`].
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum BytesComparisonResult {
+    ExactlyEqual,
+    ApproximatelyEqual,
+    DifferentByteLengths {
+        expected_byte_length : usize,
+        actual_byte_length :   usize,
+    },
+    /// The (common) byte length is not a multiple of the size of the
+    /// floating-point type.
+    IncompleteChunk {
+        byte_length : usize,
+        chunk_size :  usize,
+    },
+    UnequalElements {
+        byte_offset_of_first_unequal_element :    usize,
+        expected_value_of_first_unequal_element : f64,
+        actual_value_of_first_unequal_element :   f64,
+    },
+}
 
-            (comparison_result, None, Some(self.factor))
-        }
-    }
+/// Complex polar comparison result type.
+#[cfg(feature = "num-complex")]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum ComplexPolarComparisonResult {
+    ExactlyEqual,
+    ApproximatelyEqual,
+    /// The magnitudes diverged.
+    UnequalMagnitudes {
+        expected_magnitude : f64,
+        actual_magnitude :   f64,
+    },
+    /// The phases diverged, the difference being in `(-π, π]`.
+    UnequalPhases {
+        expected_phase :   f64,
+        actual_phase :     f64,
+        phase_difference : f64,
+    },
+}
 
-    impl ApproximateEqualityEvaluator for ZeroMarginOrMultiplierEvaluator {
-        fn evaluate(
-            &self,
-            expected : f64,
-            actual : f64,
-        ) -> (
-            ComparisonResult, // comparison_result
-            Option<f64>,      // margin_factor
-            Option<f64>,      // multiplier_factor
-        ) {
-            let comparison_result = compare_approximate_equality_by_zero_margin_or_multiplier(
-                expected,
-                actual,
-                self.multiplier_factor,
-                self.zero_margin_factor,
-            );
+/// JSON numeric array comparison result type, in which each `path`
+/// identifies an element in the manner of a JSON pointer, as in `"/1/0"`,
+/// with the empty path identifying the top-level value.
+#[cfg(feature = "serde_json")]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum JsonComparisonResult {
+    ExactlyEqual,
+    ApproximatelyEqual,
+    /// Arrays at `path` that differ in length.
+    DifferentLengths {
+        path :            String,
+        expected_length : usize,
+        actual_length :   usize,
+    },
+    /// Either value at `path` is neither a number nor an array, as in a
+    /// string, a boolean, `null`, or an object.
+    NonNumericElement {
+        path :           String,
+        expected_value : serde_json::Value,
+        actual_value :   serde_json::Value,
+    },
+    /// One value at `path` is an array and the other a number.
+    MismatchedStructure {
+        path :           String,
+        expected_value : serde_json::Value,
+        actual_value :   serde_json::Value,
+    },
+    /// The first unequal numbers, in document order.
+    UnequalElements {
+        path :           String,
+        expected_value : f64,
+        actual_value :   f64,
+    },
+}
 
-            (
-                comparison_result,
-                Some(self.zero_margin_factor),
-                Some(self.multiplier_factor),
-            )
-        }
-    }
+/// `ndarray` array comparison result type.
+#[cfg(feature = "ndarray")]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum NdarrayComparisonResult {
+    ExactlyEqual,
+    ApproximatelyEqual,
+    DifferentShapes {
+        expected_shape : Vec<usize>,
+        actual_shape :   Vec<usize>,
+    },
+    /// The first unequal element, in logical order, by its
+    /// multi-dimensional index.
+    UnequalElements {
+        index :          Vec<usize>,
+        expected_value : f64,
+        actual_value :   f64,
+    },
 }
 
+/// Matrix comparison result type.
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum MatrixComparisonResult {
+    ExactlyEqual,
+    ApproximatelyEqual,
+    DifferentRowCounts {
+        expected_row_count : usize,
+        actual_row_count :   usize,
+    },
+    DifferentRowLengths {
+        row_index :       usize,
+        expected_length : usize,
+        actual_length :   usize,
+    },
+    UnequalElements {
+        row_index :      usize,
+        column_index :   usize,
+        expected_value : f64,
+        actual_value :   f64,
+    },
+}
 
-mod utils {
+/// Comparison result type for [`evaluate_matrix_eq_approx_or_transpose()`],
+/// wherein a pass records whether it was the transpose of the actual matrix
+/// that matched, and a failure records the outcome of each comparison
+/// attempted.
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum TransposableMatrixComparisonResult {
+    ExactlyEqual {
+        transposed : bool,
+    },
+    ApproximatelyEqual {
+        transposed : bool,
+    },
+    Unequal {
+        direct :     MatrixComparisonResult,
+        /// `None` when the actual matrix is ragged, and so has no transpose.
+        transposed : Option<MatrixComparisonResult>,
+    },
+}
+
+/// Rank-3 tensor comparison result type.
+///
+/// Shape mismatches are distinguished by level: the outermost (`i`), the
+/// middle (`j`), and the innermost (`k`).
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum Tensor3ComparisonResult {
+    ExactlyEqual,
+    ApproximatelyEqual,
+    DifferentOuterLengths {
+        expected_length : usize,
+        actual_length :   usize,
+    },
+    DifferentMiddleLengths {
+        i :               usize,
+        expected_length : usize,
+        actual_length :   usize,
+    },
+    DifferentInnerLengths {
+        i :               usize,
+        j :               usize,
+        expected_length : usize,
+        actual_length :   usize,
+    },
+    UnequalElements {
+        i :              usize,
+        j :              usize,
+        k :              usize,
+        expected_value : f64,
+        actual_value :   f64,
+    },
+}
+
+/// Function comparison result type.
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum FunctionComparisonResult {
+    ExactlyEqual,
+    ApproximatelyEqual,
+    UnequalAtPoint {
+        x :              f64,
+        expected_value : f64,
+        actual_value :   f64,
+    },
+}
+
+/// Range comparison result type.
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum RangeComparisonResult {
+    /// The value lies within the closed interval.
+    WithinRange,
+    /// The value lies outside the closed interval, but is approximately
+    /// equal to the nearer bound.
+    ApproximatelyWithinRange,
+    /// The value lies below the lower bound (or is NaN), by the given
+    /// amount.
+    BelowLowerBound { lower_bound : f64, shortfall : f64 },
+    /// The value lies above the upper bound, by the given amount.
+    AboveUpperBound { upper_bound : f64, excess : f64 },
+}
+
+/// Periodicity comparison result type.
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum PeriodicComparisonResult {
+    ExactlyEqual,
+    ApproximatelyEqual,
+    /// There are too few samples for any sample to be compared with that
+    /// one period later.
+    InsufficientSamples {
+        length : usize,
+        period : f64,
+    },
+    /// The first sample that is not approximately equal to the (possibly
+    /// interpolated) sample one period later, at `index + period`.
+    UnequalSamples {
+        index :        usize,
+        value :        f64,
+        period_value : f64,
+    },
+}
+
+/// Distribution normalisation error type.
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum DistributionError {
+    /// The expected bins sum to zero, so cannot be normalised.
+    ExpectedSumIsZero,
+    /// The actual bins sum to zero, so cannot be normalised.
+    ActualSumIsZero,
+}
+
+/// Evaluator spec parse error type, as reported by [`parse_evaluator()`].
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum ParseError {
+    /// The spec is empty (or entirely whitespace).
+    Empty,
+    /// The mode is not one of those supported.
+    UnknownMode { mode : String },
+    /// The mode is given the wrong number of arguments.
+    WrongArgumentCount {
+        mode :     String,
+        expected : usize,
+        actual :   usize,
+    },
+    /// An argument could not be parsed as a number of the required type,
+    /// or is outside the range valid for the mode.
+    InvalidArgument { mode : String, argument : String },
+}
+
+impl std_fmt::Display for ParseError {
+    fn fmt(
+        &self,
+        f : &mut std_fmt::Formatter<'_>,
+    ) -> std_fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "evaluator spec is empty"),
+            ParseError::UnknownMode {
+                mode,
+            } => {
+                write!(
+                    f,
+                    "unknown evaluator mode '{mode}'; expected one of: {}",
+                    PARSE_EVALUATOR_MODES_.join(", ")
+                )
+            },
+            ParseError::WrongArgumentCount {
+                mode,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "evaluator mode '{mode}' requires {expected} argument(s), but {actual} given"
+                )
+            },
+            ParseError::InvalidArgument {
+                mode,
+                argument,
+            } => write!(f, "invalid argument '{argument}' for evaluator mode '{mode}'"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+}
+
+/// An evaluator invariant violation, as reported by
+/// [`check_evaluator_invariants()`].
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum EvaluatorInvariantViolation {
+    /// `ComparisonResult::ExactlyEqual` was reported for comparands that
+    /// are not equal, including two NaNs (for which
+    /// `ComparisonResult::BothNaN` is the only accepted equality).
+    ExactlyEqualForUnequalComparands { expected : f64, actual : f64 },
+    /// `ComparisonResult::Unequal` was reported for a finite value compared
+    /// with itself.
+    NotReflexive { value : f64 },
+}
+
+/// Rounding mode, as used by
+/// [`significant_figures_with_rounding_mode()`].
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+pub enum RoundingMode {
+    /// Round to nearest, with ties going to the even digit ("banker's
+    /// rounding").
+    #[default]
+    HalfEven,
+    /// Round to nearest, with ties going away from zero.
+    HalfUp,
+    /// Truncate, i.e. round toward zero.
+    TowardZero,
+}
+
+/// The evaluator to which an `expected` value of zero is dispatched by the
+/// evaluator created by [`sign_dependent_with_zero_dispatch()`].
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+pub enum ZeroDispatch {
+    /// Zero, of either sign, is dispatched to the positive evaluator.
+    #[default]
+    Positive,
+    /// Zero, of either sign, is dispatched to the negative evaluator.
+    Negative,
+    /// `+0.0` is dispatched to the positive evaluator and `-0.0` to the
+    /// negative evaluator.
+    SignBit,
+}
+
+/// How NaN elements are ordered and matched by
+/// [`evaluate_vector_eq_approx_sorted_with_nan_ordering()`] and
+/// [`evaluate_vector_eq_approx_unordered_with_nan_ordering()`].
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+pub enum NanOrdering {
+    /// NaNs, of either sign, are sorted last, and are compared by the
+    /// evaluator, and so are equal only by virtue of the `"nan-equality"`
+    /// feature.
+    #[default]
+    Last,
+    /// NaNs are ordered as by `f64::total_cmp()` - negative NaNs before
+    /// `-inf` and positive NaNs after `+inf` - and a NaN is equal to (and
+    /// only to) a NaN with which it compares equal by `f64::total_cmp()`,
+    /// irrespective of the `"nan-equality"` feature.
+    TotalCmp,
+}
+
+/// How two `Err` values are compared by [`evaluate_result_eq_approx()`].
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+pub enum BothErrDisposition {
+    /// Two errors are equal if their `Debug` forms are the same.
+    #[default]
+    CompareDebug,
+    /// Any two errors are equal.
+    Equal,
+    /// No two errors are equal, i.e. both must be `Ok`.
+    Unequal,
+}
+
+/// The reference value against which each element is compared by
+/// [`evaluate_vector_constant_approx()`].
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+pub enum ConstantReference {
+    /// The first element.
+    #[default]
+    First,
+    /// The arithmetic mean of the elements.
+    Mean,
+    /// A given target value, as in "all of these should be ≈ 5.0".
+    Target(f64),
+}
+
+/// Constancy comparison result type.
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum ConstantComparisonResult {
+    /// All elements are exactly equal to the reference value (including
+    /// when there are no elements).
+    ExactlyEqual,
+    ApproximatelyEqual,
+    /// The first element that is not approximately equal to the reference
+    /// value, and its deviation (`value - reference_value`) therefrom.
+    StrayElement {
+        index :           usize,
+        reference_value : f64,
+        value :           f64,
+        deviation :       f64,
+    },
+}
+
+/// The means by which the sample one period later is obtained by
+/// [`evaluate_periodic_approx()`] when the period is not an integral number
+/// of samples.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+pub enum PeriodInterpolation {
+    /// Linear interpolation between the two neighbouring samples.
+    #[default]
+    Linear,
+    /// The nearest sample (with ties going to the later sample).
+    Nearest,
+}
+
+/// Criterion by which two values with measurement uncertainties are deemed
+/// equal, as used by [`evaluate_scalar_eq_approx_with_uncertainty()`].
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+pub enum UncertaintyCriterion {
+    /// The uncertainty intervals `[value - u, value + u]` overlap, i.e.
+    /// `|expected - actual| <= expected_uncertainty + actual_uncertainty`.
+    #[default]
+    IntervalsOverlap,
+    /// The separation is within the combined uncertainty, i.e.
+    /// `|expected - actual| <= sqrt(expected_uncertainty^2 +
+    /// actual_uncertainty^2)`, which is the stricter.
+    CombinedUncertainty,
+}
+
+/// Unit in which angles are expressed, as used by
+/// [`assert_angles_complementary_approx!()`] and
+/// [`assert_angles_supplementary_approx!()`].
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+pub enum AngleUnit {
+    #[default]
+    Degrees,
+    Radians,
+}
+
+impl AngleUnit {
+    /// A right angle, i.e. 90° or π/2, in this unit.
+    pub fn right_angle(&self) -> f64 {
+        match self {
+            AngleUnit::Degrees => 90.0,
+            AngleUnit::Radians => std::f64::consts::FRAC_PI_2,
+        }
+    }
+
+    /// A straight angle, i.e. 180° or π, in this unit.
+    pub fn straight_angle(&self) -> f64 {
+        match self {
+            AngleUnit::Degrees => 180.0,
+            AngleUnit::Radians => std::f64::consts::PI,
+        }
+    }
+}
+
+/// Byte order in which floating-point values are encoded, as used by
+/// [`evaluate_bytes_as_f64_eq_approx()`] and
+/// [`evaluate_bytes_as_f32_eq_approx()`].
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
+/// Weights error type.
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum WeightsError {
+    /// The number of weights differs from the number of expected elements.
+    DifferentLengths {
+        expected_length : usize,
+        weights_length :  usize,
+    },
+}
+
+/// Correlation error type, as used by [`evaluate_correlation()`].
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum CorrelationError {
+    /// The number of actual elements differs from the number of expected
+    /// elements.
+    DifferentLengths {
+        expected_length : usize,
+        actual_length :   usize,
+    },
+    /// There are fewer than two elements, so the correlation is undefined.
+    InsufficientElements { length : usize },
+    /// One or both vectors is constant (has zero variance), so the
+    /// correlation is undefined.
+    ZeroVariance {
+        expected_is_constant : bool,
+        actual_is_constant :   bool,
+    },
+}
+
+/// Root-mean-square error type, as used by [`evaluate_rmse()`].
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum RmseError {
+    /// The number of actual elements differs from the number of expected
+    /// elements.
+    DifferentLengths {
+        expected_length : usize,
+        actual_length :   usize,
+    },
+}
+
+/// An expected value that carries its own tolerance, so that tables of
+/// `(Approx, actual)` pairs may embed a per-row margin.
+///
+/// It may be given only as the expected comparand of
+/// [`assert_scalar_eq_approx!()`] and [`assert_scalar_ne_approx!()`]: in
+/// their two-parameter forms its margin is used in place of the default
+/// tolerance; an explicitly given evaluator takes precedence over it.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct Approx {
+    pub(crate) value :  f64,
+    pub(crate) margin : f64,
+}
+
+impl Approx {
+    /// Creates an expected value that is deemed approximately equal to any
+    /// actual value within `margin` of it, as per [`margin()`].
+    pub fn new(
+        value : f64,
+        margin : f64,
+    ) -> Self {
+        debug_assert!(margin >= 0.0, "`margin` must not be negative, but {margin} given");
+
+        Self {
+            value,
+            margin,
+        }
+    }
+
+    /// The expected value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// The margin within which an actual value is deemed approximately
+    /// equal to the expected value.
+    pub fn margin(&self) -> f64 {
+        self.margin
+    }
+}
+
+impl traits::CarriesTolerance for Approx {
+    fn carried_value(&self) -> f64 {
+        self.value
+    }
+
+    fn tolerance(&self) -> Box<dyn traits::ApproximateEqualityEvaluator> {
+        use traits::ApproximateEqualityEvaluator as _;
+
+        margin(self.margin).boxed()
+    }
+}
+
+/// Fluent configuration of an approximate-equality assertion, which builds
+/// the appropriate evaluator and performs the assertion with the same
+/// diagnostics as the corresponding macro, as in
+/// `Approximately::new().margin(1e-6).nan_equal(true).assert_eq(expected, actual)`.
+///
+/// The evaluator is:
+/// - [`margin()`], if only a margin is given;
+/// - [`multiplier()`], if only a multiplier is given;
+/// - [`zero_margin_or_multiplier()`], if both are given;
+/// - [`default_evaluator()`], if neither is given;
+///
+/// and, if `nan_equal` is given, two NaN comparands are deemed equal
+/// (`true`) or any NaN comparand is deemed unequal (`false`, as by
+/// [`nan_strict()`]), irrespective of the `"nan-equality"` feature.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+pub struct Approximately {
+    margin_factor :     Option<f64>,
+    multiplier_factor : Option<f64>,
+    nan_equal :         Option<bool>,
+}
+
+impl Approximately {
+    /// Creates a configuration that uses the [`default_evaluator()`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies the given margin factor.
+    pub fn margin(
+        self,
+        margin_factor : f64,
+    ) -> Self {
+        Self {
+            margin_factor : Some(margin_factor),
+            ..self
+        }
+    }
+
+    /// Applies the given multiplier factor.
+    pub fn multiplier(
+        self,
+        multiplier_factor : f64,
+    ) -> Self {
+        Self {
+            multiplier_factor : Some(multiplier_factor),
+            ..self
+        }
+    }
+
+    /// Specifies whether two NaN comparands are deemed equal.
+    pub fn nan_equal(
+        self,
+        nan_equal : bool,
+    ) -> Self {
+        Self {
+            nan_equal : Some(nan_equal),
+            ..self
+        }
+    }
+
+    /// Builds the configured evaluator.
+    pub fn evaluator(&self) -> Box<dyn traits::ApproximateEqualityEvaluator> {
+        use traits::ApproximateEqualityEvaluator as _;
+
+        let evaluator = match (self.margin_factor, self.multiplier_factor) {
+            (Some(margin_factor), None) => margin(margin_factor).boxed(),
+            (None, Some(multiplier_factor)) => multiplier(multiplier_factor).boxed(),
+            (Some(margin_factor), Some(multiplier_factor)) => {
+                zero_margin_or_multiplier(multiplier_factor, margin_factor).boxed()
+            },
+            (None, None) => default_evaluator().boxed(),
+        };
+
+        match self.nan_equal {
+            None => evaluator,
+            Some(true) => {
+                evaluators::NanEqualEvaluator {
+                    inner : evaluator
+                }
+                .boxed()
+            },
+            Some(false) => nan_strict(evaluator).boxed(),
+        }
+    }
+
+    /// Asserts approximate equality of `expected` and `actual`, as by
+    /// [`assert_scalar_eq_approx!()`].
+    #[track_caller]
+    pub fn assert_eq<T_expected, T_actual>(
+        &self,
+        expected : T_expected,
+        actual : T_actual,
+    ) where
+        T_expected : traits::TestableAsF64 + std_fmt::Debug,
+        T_actual : traits::TestableAsF64 + std_fmt::Debug,
+    {
+        let evaluator = self.evaluator();
+
+        assert_scalar_eq_approx!(expected, actual, evaluator);
+    }
+
+    /// Asserts approximate inequality of `expected` and `actual`, as by
+    /// [`assert_scalar_ne_approx!()`].
+    #[track_caller]
+    pub fn assert_ne<T_expected, T_actual>(
+        &self,
+        expected : T_expected,
+        actual : T_actual,
+    ) where
+        T_expected : traits::TestableAsF64 + std_fmt::Debug,
+        T_actual : traits::TestableAsF64 + std_fmt::Debug,
+    {
+        let evaluator = self.evaluator();
+
+        assert_scalar_ne_approx!(expected, actual, evaluator);
+    }
+
+    /// Asserts approximate equality of the `expected` and `actual` vectors,
+    /// as by [`assert_vector_eq_approx!()`].
+    #[track_caller]
+    pub fn assert_vector_eq<T_expected, T_actual, T_expectedElement, T_actualElement>(
+        &self,
+        expected : T_expected,
+        actual : T_actual,
+    ) where
+        T_expected : std_convert::AsRef<[T_expectedElement]>,
+        T_actual : std_convert::AsRef<[T_actualElement]>,
+        T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+        T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+    {
+        let evaluator = self.evaluator();
+
+        assert_vector_eq_approx!(expected.as_ref(), actual.as_ref(), evaluator);
+    }
+
+    /// Asserts approximate inequality of the `expected` and `actual`
+    /// vectors, as by [`assert_vector_ne_approx!()`].
+    #[track_caller]
+    pub fn assert_vector_ne<T_expected, T_actual, T_expectedElement, T_actualElement>(
+        &self,
+        expected : T_expected,
+        actual : T_actual,
+    ) where
+        T_expected : std_convert::AsRef<[T_expectedElement]>,
+        T_actual : std_convert::AsRef<[T_actualElement]>,
+        T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+        T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+    {
+        let evaluator = self.evaluator();
+
+        assert_vector_ne_approx!(expected.as_ref(), actual.as_ref(), evaluator);
+    }
+}
+
+/// Comparator that compares a stream of actual values, pushed one at a
+/// time, against an expected sequence, without buffering the actual values.
+#[derive(Debug)]
+pub struct StreamingVectorComparator<'a, T_expectedElement, E = evaluators::ZeroMarginOrMultiplierEvaluator> {
+    expected :    &'a [T_expectedElement],
+    evaluator :   E,
+    num_pushed :  usize,
+    accumulator : SequenceAccumulator_<usize>,
+}
+
+impl<'a, T_expectedElement> StreamingVectorComparator<'a, T_expectedElement>
+where
+    T_expectedElement : traits::TestableAsF64,
+{
+    /// Creates a comparator for the given expected sequence that uses the
+    /// evaluator obtained from [`default_evaluator()`].
+    pub fn new<T_expected>(expected : &'a T_expected) -> Self
+    where
+        T_expected : std_convert::AsRef<[T_expectedElement]> + ?Sized,
+    {
+        Self::with_evaluator(expected, default_evaluator())
+    }
+}
+
+impl<'a, T_expectedElement, E> StreamingVectorComparator<'a, T_expectedElement, E>
+where
+    T_expectedElement : traits::TestableAsF64,
+    E : traits::ApproximateEqualityEvaluator,
+{
+    /// Creates a comparator for the given expected sequence that uses the
+    /// given evaluator.
+    pub fn with_evaluator<T_expected>(
+        expected : &'a T_expected,
+        evaluator : E,
+    ) -> Self
+    where
+        T_expected : std_convert::AsRef<[T_expectedElement]> + ?Sized,
+    {
+        Self {
+            expected : expected.as_ref(),
+            evaluator,
+            num_pushed : 0,
+            accumulator : SequenceAccumulator_::new(),
+        }
+    }
+
+    /// Compares the next actual value with the corresponding expected
+    /// value, returning the result.
+    ///
+    /// NOTE: values pushed beyond the end of the expected sequence are
+    /// deemed `ComparisonResult::Unequal`; the length mismatch is reported
+    /// by [`Self::finish()`].
+    pub fn push<T_actualElement>(
+        &mut self,
+        actual : T_actualElement,
+    ) -> ComparisonResult
+    where
+        T_actualElement : traits::TestableAsF64,
+    {
+        let index = self.num_pushed;
+
+        self.num_pushed += 1;
+
+        let expected = match self.expected.get(index) {
+            Some(expected) => expected.testable_as_f64(),
+            None => return ComparisonResult::Unequal,
+        };
+        let actual = actual.testable_as_f64();
+
+        self.accumulator.record(
+            index,
+            expected,
+            actual,
+            evaluate_f64_eq_approx_(expected, actual, &self.evaluator),
+        )
+    }
+
+    /// The number of actual values pushed thus far.
+    pub fn num_pushed(&self) -> usize {
+        self.num_pushed
+    }
+
+    /// Completes the comparison, verifying that the expected sequence was
+    /// fully consumed, and obtaining the overall result in the same form as
+    /// [`evaluate_vector_eq_approx()`]. A length mismatch takes precedence
+    /// over any unequal element.
+    pub fn finish(
+        self
+    ) -> (
+        VectorComparisonResult, // comparison_result
+        Option<f64>,            // margin_factor
+        Option<f64>,            // multiplier_factor
+    ) {
+        if self.num_pushed != self.expected.len() {
+            return (
+                VectorComparisonResult::DifferentLengths {
+                    expected_length : self.expected.len(),
+                    actual_length :   self.num_pushed,
+                },
+                None,
+                None,
+            );
+        }
+
+        self.accumulator.finish_vector()
+    }
+}
+
+
+/// Simple in-memory metrics sink, for use with [`set_metrics_sink()`],
+/// that accumulates the minimum, maximum, and mean headroom of the
+/// comparisons reported to it, the headroom of a comparison being the
+/// fraction of its allowed band left unused, i.e.
+/// `1 - delta / allowed_band`. Thus, a headroom of 1 indicates an exact
+/// comparison, a headroom close to 0 one that only just passed, and a
+/// negative headroom one that failed.
+#[derive(Debug)]
+#[derive(Default)]
+pub struct InMemoryMetricsSink {
+    statistics : std::sync::Mutex<MetricsStatistics_>,
+}
+
+#[derive(Debug)]
+#[derive(Default)]
+struct MetricsStatistics_ {
+    num_comparisons : usize,
+    min_headroom :    f64,
+    max_headroom :    f64,
+    sum_headroom :    f64,
+}
+
+impl InMemoryMetricsSink {
+    /// Creates an empty sink.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of comparisons recorded.
+    pub fn num_comparisons(&self) -> usize {
+        self.lock_().num_comparisons
+    }
+
+    /// The minimum headroom, or `None` if no comparisons have been
+    /// recorded.
+    pub fn min_headroom(&self) -> Option<f64> {
+        let statistics = self.lock_();
+
+        (0 != statistics.num_comparisons).then_some(statistics.min_headroom)
+    }
+
+    /// The maximum headroom, or `None` if no comparisons have been
+    /// recorded.
+    pub fn max_headroom(&self) -> Option<f64> {
+        let statistics = self.lock_();
+
+        (0 != statistics.num_comparisons).then_some(statistics.max_headroom)
+    }
+
+    /// The mean headroom, or `None` if no comparisons have been recorded.
+    pub fn mean_headroom(&self) -> Option<f64> {
+        let statistics = self.lock_();
+
+        (0 != statistics.num_comparisons).then(|| statistics.sum_headroom / statistics.num_comparisons as f64)
+    }
+
+    fn lock_(&self) -> std::sync::MutexGuard<'_, MetricsStatistics_> {
+        self.statistics
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl traits::MetricsSink for InMemoryMetricsSink {
+    fn record(
+        &self,
+        delta : f64,
+        allowed_band : f64,
+    ) {
+        let headroom = 1.0 - delta / allowed_band;
+
+        let mut statistics = self.lock_();
+
+        if 0 == statistics.num_comparisons {
+            statistics.min_headroom = headroom;
+            statistics.max_headroom = headroom;
+        } else {
+            statistics.min_headroom = statistics.min_headroom.min(headroom);
+            statistics.max_headroom = statistics.max_headroom.max(headroom);
+        }
+
+        statistics.num_comparisons += 1;
+        statistics.sum_headroom += headroom;
+    }
+}
+
+
+/// Traits.
+pub mod traits {
     use super::ComparisonResult;
 
+    use base_traits::ToF64;
+
+    use std::fmt as std_fmt;
+
+
+    /// Trait that defines a mechanism for performing approximate equality
+    /// evaluation.
+    pub trait ApproximateEqualityEvaluator {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        );
+
+        /// Provides an optional evaluator-specific description of the
+        /// evaluation of the given comparands, which is appended to the
+        /// failure messages of the assertion macros.
+        ///
+        /// The default implementation returns `None`.
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            let _ = (expected, actual);
+
+            None
+        }
+
+        /// Provides the least value of the factor reported by
+        /// [`evaluate()`](ApproximateEqualityEvaluator::evaluate) - the margin
+        /// factor if it is the only factor, or if both are reported and
+        /// either comparand is zero; otherwise the multiplier factor - at
+        /// which the given comparands would be evaluated as equal, which is
+        /// stated as a hint in the failure messages of the assertion macros.
+        ///
+        /// The default implementation returns `None`, in which case no hint
+        /// is given.
+        fn passing_factor(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<f64> {
+            let _ = (expected, actual);
+
+            None
+        }
+
+        /// Boxes the evaluator, allowing evaluators of different types to
+        /// be stored together, as in
+        /// `Vec<Box<dyn ApproximateEqualityEvaluator>>`.
+        fn boxed(self) -> Box<dyn ApproximateEqualityEvaluator>
+        where
+            Self : Sized + 'static,
+        {
+            Box::new(self)
+        }
+    }
+
+    impl<E> ApproximateEqualityEvaluator for &E
+    where
+        E : ApproximateEqualityEvaluator + ?Sized,
+    {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            (**self).evaluate(expected, actual)
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            (**self).describe(expected, actual)
+        }
+
+        fn passing_factor(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<f64> {
+            (**self).passing_factor(expected, actual)
+        }
+    }
+
+    impl<E> ApproximateEqualityEvaluator for Box<E>
+    where
+        E : ApproximateEqualityEvaluator + ?Sized,
+    {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            (**self).evaluate(expected, actual)
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            (**self).describe(expected, actual)
+        }
+
+        fn passing_factor(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<f64> {
+            (**self).passing_factor(expected, actual)
+        }
+    }
+
+    /// A bare `f64` is interpreted as a margin factor, so that it may be
+    /// passed directly as the evaluator argument of the assertion macros,
+    /// as in `assert_scalar_eq_approx!(a, b, 1e-6)`; it is equivalent to
+    /// [`margin()`](crate::margin).
+    impl ApproximateEqualityEvaluator for f64 {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            let comparison_result = super::utils::compare_approximate_equality_by_margin(expected, actual, *self);
+
+            (comparison_result, Some(*self), None)
+        }
+
+        fn passing_factor(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<f64> {
+            Some(crate::suggest_margin(&[(expected, actual)]))
+        }
+    }
+
+    /// Trait that defines a receiver of per-comparison metrics, which may
+    /// be installed via [`set_metrics_sink()`](crate::set_metrics_sink) to
+    /// collect, across a whole test suite, how close each comparison came
+    /// to the edge of its tolerance.
+    ///
+    /// NOTE: because the sink is shared by all threads, implementations
+    /// must be `Send` and `Sync`.
+    pub trait MetricsSink: Send + Sync {
+        /// Records a comparison whose comparands differ by `delta`, which
+        /// was evaluated against an acceptance band of (half-)width
+        /// `allowed_band`.
+        fn record(
+            &self,
+            delta : f64,
+            allowed_band : f64,
+        );
+    }
+
+    /// Trait that allows an implementing type instance to be evaluated with the
+    /// constructs of this crate.
+    ///
+    /// NOTE: it is implemented for any types that implement
+    /// `base_traits::ToF64` (and `std::fmt::Debug`).
+    pub trait TestableAsF64: std_fmt::Debug {
+        fn testable_as_f64(&self) -> f64;
+    }
+
+    impl<T> TestableAsF64 for T
+    where
+        T : ToF64 + std_fmt::Debug,
+    {
+        fn testable_as_f64(&self) -> f64 {
+            self.to_f64()
+        }
+    }
+
+    /// Trait that allows an implementing type instance to be evaluated,
+    /// fallibly, with the constructs of this crate, obtaining a description
+    /// of the failure if it cannot be.
+    ///
+    /// NOTE: it is implemented for any types that implement
+    /// `TestableAsF64`, and for `str`, whose instances are parsed (having
+    /// been trimmed of whitespace) via `f64::from_str()`. Because the scalar
+    /// assertion macros also apply it to the target of a comparand that
+    /// dereferences (once or twice) to an implementing type, `&str` and
+    /// `String` comparands may also be used.
+    pub trait TryTestableAsF64 {
+        fn try_testable_as_f64(&self) -> Result<f64, String>;
+    }
+
+    impl<T> TryTestableAsF64 for T
+    where
+        T : TestableAsF64,
+    {
+        fn try_testable_as_f64(&self) -> Result<f64, String> {
+            Ok(self.testable_as_f64())
+        }
+    }
+
+    impl TryTestableAsF64 for str {
+        fn try_testable_as_f64(&self) -> Result<f64, String> {
+            self.trim()
+                .parse::<f64>()
+                .map_err(|_| format!("could not parse '{self}' as f64"))
+        }
+    }
+
+    /// Trait that allows instances of types that do not - and, because of
+    /// the blanket implementation of `TestableAsF64` in terms of
+    /// `base_traits::ToF64`, cannot be made to - implement `TestableAsF64`
+    /// to be evaluated with the scalar assertion macros.
+    ///
+    /// NOTE: the scalar assertion macros select between it and
+    /// `TryTestableAsF64` via [`utils::TestableProbe`](crate::utils::TestableProbe).
+    /// It is implemented for:
+    /// - `bool`, mapping `false` to `0.0` and `true` to `1.0`;
+    /// - `char`, converting its code point via `u32::from(c) as f64` (a
+    ///   dedicated implementation being required because `base_traits`
+    ///   does not implement `ToF64` for `char`);
+    /// - the `std::num::NonZero*` family, converting via `get() as f64`,
+    ///   which (as for the underlying integer types) loses precision for
+    ///   magnitudes greater than 2^53;
+    /// - `half::f16` and `half::bf16` (with the `"half"` feature),
+    ///   converting via `f32::from(x) as f64`, which is exact; note, though,
+    ///   the coarse resolution of 16-bit floating-point values - `f16` has
+    ///   11 significant bits, so adjacent values near `1.0` are ~9.8e-4
+    ///   apart, and `bf16` has 8, so they are ~7.8e-3 apart - for which see
+    ///   [`presets::half_precision()`](crate::presets::half_precision) and
+    ///   [`presets::bfloat16()`](crate::presets::bfloat16);
+    /// - `rust_decimal::Decimal` (with the `"rust_decimal"` feature),
+    ///   converting via its `to_f64()`, which is subject to binary
+    ///   floating-point rounding (so that, e.g., `0.1` is not exactly
+    ///   representable), and loses precision for values having more than
+    ///   15-17 significant digits; for exact decimal comparison use
+    ///   `evaluate_decimal_eq_approx()`;
+    pub trait ExtendedTestableAsF64 {
+        fn extended_testable_as_f64(&self) -> Result<f64, String>;
+    }
+
+    impl ExtendedTestableAsF64 for bool {
+        fn extended_testable_as_f64(&self) -> Result<f64, String> {
+            Ok(if *self { 1.0 } else { 0.0 })
+        }
+    }
+
+    impl ExtendedTestableAsF64 for char {
+        fn extended_testable_as_f64(&self) -> Result<f64, String> {
+            Ok(u32::from(*self) as f64)
+        }
+    }
+
+    #[cfg(feature = "half")]
+    impl ExtendedTestableAsF64 for half::f16 {
+        fn extended_testable_as_f64(&self) -> Result<f64, String> {
+            Ok(f32::from(*self) as f64)
+        }
+    }
+
+    #[cfg(feature = "half")]
+    impl ExtendedTestableAsF64 for half::bf16 {
+        fn extended_testable_as_f64(&self) -> Result<f64, String> {
+            Ok(f32::from(*self) as f64)
+        }
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    impl ExtendedTestableAsF64 for rust_decimal::Decimal {
+        fn extended_testable_as_f64(&self) -> Result<f64, String> {
+            use rust_decimal::prelude::ToPrimitive as _;
+
+            self.to_f64()
+                .ok_or_else(|| format!("could not convert '{self}' to f64"))
+        }
+    }
+
+    macro_rules! impl_ExtendedTestableAsF64_for_NonZero_ {
+        ($($type:ty),+ $(,)?) => {
+            $(
+                impl ExtendedTestableAsF64 for $type {
+                    fn extended_testable_as_f64(&self) -> Result<f64, String> {
+                        Ok(self.get() as f64)
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_ExtendedTestableAsF64_for_NonZero_!(
+        std::num::NonZeroI8,
+        std::num::NonZeroI16,
+        std::num::NonZeroI32,
+        std::num::NonZeroI64,
+        std::num::NonZeroI128,
+        std::num::NonZeroIsize,
+        std::num::NonZeroU8,
+        std::num::NonZeroU16,
+        std::num::NonZeroU32,
+        std::num::NonZeroU64,
+        std::num::NonZeroU128,
+        std::num::NonZeroUsize,
+    );
+
+    /// Trait that allows instances of types that implement `Into<f64>` (and
+    /// `Copy`), but not `base_traits::ToF64`, such as many third-party
+    /// numeric types, to be evaluated with the scalar assertion macros.
+    ///
+    /// NOTE: it is not possible to provide, instead, a blanket
+    /// implementation of `TestableAsF64` for `T : Into<f64>`, even behind a
+    /// feature, since it would overlap that for `T : ToF64`, which coherence
+    /// forbids. Instead, the scalar assertion macros select it via
+    /// [`utils::TestableProbe`](crate::utils::TestableProbe), preferring
+    /// `TryTestableAsF64` (and `ExtendedTestableAsF64`) when applicable. The
+    /// tradeoff is that it is not available to the (generic) evaluation
+    /// functions; for vectors of such types use
+    /// [`evaluate_vector_eq_approx_into()`](crate::evaluate_vector_eq_approx_into).
+    pub trait IntoTestableAsF64 {
+        fn converted_testable_as_f64(&self) -> Result<f64, String>;
+    }
+
+    impl<T> IntoTestableAsF64 for T
+    where
+        T : Into<f64> + Copy,
+    {
+        fn converted_testable_as_f64(&self) -> Result<f64, String> {
+            Ok((*self).into())
+        }
+    }
+
+    /// Trait that defines the default tolerance of a comparand type, which
+    /// the two-parameter forms of [`assert_scalar_eq_approx!()`],
+    /// [`assert_scalar_ne_approx!()`], [`assert_vector_eq_approx!()`], and
+    /// [`assert_vector_ne_approx!()`] use in place of
+    /// [`default_evaluator()`](crate::default_evaluator).
+    ///
+    /// NOTE: the dispatch is on the (element) type of the *expected*
+    /// comparand, and is resolved at compile time: if it implements this
+    /// trait then its default tolerance is used, otherwise
+    /// `default_evaluator()` is. It is implemented for:
+    /// - `f64`, as `default_evaluator()` (and, so, is subject to the
+    ///   environment overrides);
+    /// - `f32`, as `zero_margin_or_multiplier(DEFAULT_F32_MULTIPLIER,
+    ///   DEFAULT_F32_MARGIN)`, subject to the same environment overrides;
+    ///
+    /// Since unsuffixed floating-point literals default to `f64`, an
+    /// expected comparand such as `1.5` obtains the `f64` tolerance.
+    ///
+    /// [`assert_scalar_eq_approx!()`]: crate::assert_scalar_eq_approx
+    /// [`assert_scalar_ne_approx!()`]: crate::assert_scalar_ne_approx
+    /// [`assert_vector_eq_approx!()`]: crate::assert_vector_eq_approx
+    /// [`assert_vector_ne_approx!()`]: crate::assert_vector_ne_approx
+    pub trait DefaultTolerance {
+        fn default_tolerance() -> Box<dyn ApproximateEqualityEvaluator>;
+    }
+
+    impl DefaultTolerance for f64 {
+        fn default_tolerance() -> Box<dyn ApproximateEqualityEvaluator> {
+            crate::default_evaluator().boxed()
+        }
+    }
+
+    impl DefaultTolerance for f32 {
+        fn default_tolerance() -> Box<dyn ApproximateEqualityEvaluator> {
+            let (zero_margin_factor, multiplier_factor) = crate::utils::default_factors((
+                crate::constants::DEFAULT_F32_MARGIN,
+                crate::constants::DEFAULT_F32_MULTIPLIER,
+            ));
+
+            crate::zero_margin_or_multiplier(multiplier_factor, zero_margin_factor).boxed()
+        }
+    }
+
+    /// Trait that allows an expected comparand to carry its own tolerance,
+    /// as does [`Approx`](crate::Approx), which the two-parameter forms of
+    /// [`assert_scalar_eq_approx!()`] and [`assert_scalar_ne_approx!()`]
+    /// prefer to that of [`DefaultTolerance`].
+    ///
+    /// NOTE: implementing types are accepted only as the expected comparand
+    /// of those macros, whose value is obtained via `carried_value()`.
+    ///
+    /// [`assert_scalar_eq_approx!()`]: crate::assert_scalar_eq_approx
+    /// [`assert_scalar_ne_approx!()`]: crate::assert_scalar_ne_approx
+    pub trait CarriesTolerance {
+        fn carried_value(&self) -> f64;
+
+        fn tolerance(&self) -> Box<dyn ApproximateEqualityEvaluator>;
+    }
+
+    /// Trait that allows a tuple of logically-`f64` components, such as an
+    /// RGB or XYZ triple, to be evaluated component-wise with the constructs
+    /// of this crate.
+    ///
+    /// NOTE: it is implemented for tuples of arity 2, 3, and 4 whose
+    /// components each implement `TestableAsF64`.
+    pub trait TestableTupleAsF64: std_fmt::Debug {
+        /// The number of components.
+        const ARITY : usize;
+
+        /// Obtains the given component, which must be less than `ARITY`, as
+        /// `f64`.
+        fn testable_component_as_f64(
+            &self,
+            component : usize,
+        ) -> f64;
+    }
+
+    macro_rules! impl_TestableTupleAsF64_for_tuple_ {
+        ($arity:literal; $($type:ident : $index:tt),+) => {
+            impl<$($type),+> TestableTupleAsF64 for ($($type,)+)
+            where
+                $($type : TestableAsF64,)+
+            {
+                const ARITY : usize = $arity;
+
+                fn testable_component_as_f64(
+                    &self,
+                    component : usize,
+                ) -> f64 {
+                    match component {
+                        $($index => self.$index.testable_as_f64(),)+
+                        _ => panic!("component {component} is out of range for a tuple of arity {}", $arity),
+                    }
+                }
+            }
+        };
+    }
+
+    impl_TestableTupleAsF64_for_tuple_!(2; T0 : 0, T1 : 1);
+    impl_TestableTupleAsF64_for_tuple_!(3; T0 : 0, T1 : 1, T2 : 2);
+    impl_TestableTupleAsF64_for_tuple_!(4; T0 : 0, T1 : 1, T2 : 2, T3 : 3);
+}
+
+
+/// Evaluators.
+pub mod evaluators {
+
+    use super::{
+        traits::ApproximateEqualityEvaluator,
+        utils::{
+            compare_approximate_equality_by_asymmetric_margin,
+            compare_approximate_equality_by_log_scale,
+            compare_approximate_equality_by_margin,
+            compare_approximate_equality_by_multiplier,
+            compare_approximate_equality_by_numpy_close,
+            compare_approximate_equality_by_relative_geomean,
+            compare_approximate_equality_by_significant_figures,
+            compare_approximate_equality_by_ulps,
+            compare_approximate_equality_by_zero_margin_or_multiplier,
+            flush_subnormal_to_zero,
+            round_to_significant_figures,
+            ulps_distance,
+        },
+        ComparisonResult,
+        RoundingMode,
+        ZeroDispatch,
+    };
+
+
+    /// Evaluator that applies a factor as a margin, as created by
+    /// [`margin()`](crate::margin).
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub struct MarginEvaluator {
+        pub(crate) factor : f64,
+    }
+
+    /// Evaluator that applies a factor as a multiplier, as created by
+    /// [`multiplier()`](crate::multiplier).
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub struct MultiplierEvaluator {
+        pub(crate) factor : f64,
+    }
+
+    /// Evaluator that applies a factor as a margin when either comparand is
+    /// zero and another as a multiplier otherwise, as created by
+    /// [`zero_margin_or_multiplier()`](crate::zero_margin_or_multiplier).
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub struct ZeroMarginOrMultiplierEvaluator {
+        pub(crate) multiplier_factor :  f64,
+        pub(crate) zero_margin_factor : f64,
+    }
+
+    /// Evaluator that applies independent lower and upper margins, as
+    /// created by [`asymmetric_margin()`](crate::asymmetric_margin).
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub struct AsymmetricMarginEvaluator {
+        pub(crate) lower_factor : f64,
+        pub(crate) upper_factor : f64,
+    }
+
+    /// Evaluator that applies a factor as a multiplier of the geometric
+    /// mean of the comparands' magnitudes, optionally applying another as a
+    /// margin when either comparand is zero, as created by
+    /// [`relative_geomean()`](crate::relative_geomean) and
+    /// [`zero_margin_or_relative_geomean()`](crate::zero_margin_or_relative_geomean).
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub struct RelativeGeomeanEvaluator {
+        pub(crate) multiplier_factor :  f64,
+        pub(crate) zero_margin_factor : Option<f64>,
+    }
+
+    /// Evaluator that applies a fraction of a fixed full-scale range as a
+    /// margin, as created by [`full_scale()`](crate::full_scale).
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub struct FullScaleEvaluator {
+        pub(crate) range :    f64,
+        pub(crate) fraction : f64,
+    }
+
+    /// Evaluator that applies a relative tolerance expressed in parts per
+    /// million, as created by [`ppm()`](crate::ppm).
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub struct PpmEvaluator {
+        pub(crate) parts_per_million : f64,
+    }
+
+    /// Evaluator that applies the absolute and relative tolerances of
+    /// numpy's `isclose()`, as created by [`numpy_close()`](crate::numpy_close).
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub struct NumpyCloseEvaluator {
+        pub(crate) relative_tolerance : f64,
+        pub(crate) absolute_tolerance : f64,
+    }
+
+    /// Evaluator that applies a margin to the logarithms of the comparands,
+    /// as created by [`log_scale()`](crate::log_scale).
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub struct LogScaleEvaluator {
+        pub(crate) base :          f64,
+        pub(crate) margin_factor : f64,
+    }
+
+    /// Evaluator that accepts comparands no more than a given number of
+    /// units in the last place (ULPs) apart, as created by
+    /// [`ulps()`](crate::ulps).
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub struct UlpsEvaluator {
+        pub(crate) max_ulps : u64,
+    }
+
+    /// Evaluator that deems comparands equal if they are the same when
+    /// rounded to a given number of significant figures, as created by
+    /// [`significant_figures()`](crate::significant_figures) and
+    /// [`significant_figures_with_rounding_mode()`](crate::significant_figures_with_rounding_mode).
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub struct SignificantFiguresEvaluator {
+        pub(crate) figures :       u32,
+        pub(crate) rounding_mode : RoundingMode,
+    }
+
+    /// Evaluator that wraps another evaluator and reports any NaN
+    /// comparand as `ComparisonResult::Unequal`, regardless of the
+    /// `"nan-equality"` feature.
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub struct NanStrictEvaluator<E> {
+        pub(crate) inner : E,
+    }
+
+    /// Evaluator that wraps another evaluator and compares any NaN comparand
+    /// by `f64::total_cmp()`, as used for [`NanOrdering::TotalCmp`](crate::NanOrdering::TotalCmp).
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub(crate) struct TotalCmpNanEvaluator<E> {
+        pub(crate) inner : E,
+    }
+
+    /// Evaluator that wraps another evaluator and reports two NaN
+    /// comparands as `ComparisonResult::BothNaN`, regardless of the
+    /// `"nan-equality"` feature, as used by [`Approximately`](crate::Approximately).
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub(crate) struct NanEqualEvaluator<E> {
+        pub(crate) inner : E,
+    }
+
+    /// Evaluator that wraps another evaluator and flushes any subnormal
+    /// comparand to (signed) zero before delegating, as created by
+    /// [`flush_subnormals_to_zero()`](crate::flush_subnormals_to_zero).
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub struct FlushSubnormalsEvaluator<E> {
+        pub(crate) inner : E,
+    }
+
+    /// Evaluator that wraps another evaluator and reports a comparison of
+    /// zeros of different sign, which it would deem exactly equal, as
+    /// `ComparisonResult::ApproximatelyEqual`, as created by
+    /// [`signed_zero_as_approx()`](crate::signed_zero_as_approx).
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub struct SignedZeroAsApproxEvaluator<E> {
+        pub(crate) inner : E,
+    }
+
+    /// Evaluator that wraps another evaluator and deems any two comparands
+    /// whose magnitudes are both no greater than a noise floor to be
+    /// approximately equal, delegating in all other cases.
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub struct NoiseFloorEvaluator<E> {
+        pub(crate) floor : f64,
+        pub(crate) inner : E,
+    }
+
+    /// Evaluator that wraps another evaluator and deems any two comparands
+    /// of the same sign whose magnitudes both exceed a saturation limit to
+    /// be approximately equal, delegating in all other cases, as created by
+    /// [`saturating()`](crate::saturating).
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub struct SaturatingEvaluator<E> {
+        pub(crate) limit : f64,
+        pub(crate) inner : E,
+    }
+
+    /// Evaluator that dispatches to one of two evaluators according to the
+    /// sign of the expected value, as created by
+    /// [`sign_dependent()`](crate::sign_dependent) and
+    /// [`sign_dependent_with_zero_dispatch()`](crate::sign_dependent_with_zero_dispatch).
+    #[derive(Clone)]
+    #[derive(Copy)]
+    #[derive(Debug)]
+    pub struct SignDependentEvaluator<E_positive, E_negative> {
+        pub(crate) positive :      E_positive,
+        pub(crate) negative :      E_negative,
+        pub(crate) zero_dispatch : ZeroDispatch,
+    }
+
+    // API functions
+
+    impl MarginEvaluator {
+        /// The margin factor.
+        pub fn factor(&self) -> f64 {
+            self.factor
+        }
+    }
+
+    impl MultiplierEvaluator {
+        /// The multiplier factor.
+        pub fn factor(&self) -> f64 {
+            self.factor
+        }
+    }
+
+    impl ZeroMarginOrMultiplierEvaluator {
+        /// The multiplier factor, applied when neither comparand is zero.
+        pub fn multiplier_factor(&self) -> f64 {
+            self.multiplier_factor
+        }
+
+        /// The margin factor, applied when either comparand is zero.
+        pub fn zero_margin_factor(&self) -> f64 {
+            self.zero_margin_factor
+        }
+    }
+
+    impl AsymmetricMarginEvaluator {
+        /// The margin factor applied below the expected value.
+        pub fn lower_factor(&self) -> f64 {
+            self.lower_factor
+        }
+
+        /// The margin factor applied above the expected value.
+        pub fn upper_factor(&self) -> f64 {
+            self.upper_factor
+        }
+    }
+
+    impl RelativeGeomeanEvaluator {
+        /// The multiplier factor, applied to the geometric mean of the
+        /// comparands' magnitudes when neither comparand is zero.
+        pub fn multiplier_factor(&self) -> f64 {
+            self.multiplier_factor
+        }
+
+        /// The margin factor, if any, applied when either comparand is zero.
+        pub fn zero_margin_factor(&self) -> Option<f64> {
+            self.zero_margin_factor
+        }
+    }
+
+    impl FullScaleEvaluator {
+        /// The full-scale range.
+        pub fn range(&self) -> f64 {
+            self.range
+        }
+
+        /// The fraction of the full-scale range.
+        pub fn fraction(&self) -> f64 {
+            self.fraction
+        }
+
+        /// The effective margin factor, which is `range * fraction`.
+        pub fn margin_factor(&self) -> f64 {
+            self.range * self.fraction
+        }
+    }
+
+    impl PpmEvaluator {
+        /// The tolerance, in parts per million.
+        pub fn parts_per_million(&self) -> f64 {
+            self.parts_per_million
+        }
+
+        /// The effective multiplier factor, which is
+        /// `parts_per_million / 1e6`.
+        pub fn multiplier_factor(&self) -> f64 {
+            self.parts_per_million / 1e6
+        }
+    }
+
+    impl NumpyCloseEvaluator {
+        /// The relative tolerance, `rtol`.
+        pub fn rtol(&self) -> f64 {
+            self.relative_tolerance
+        }
+
+        /// The absolute tolerance, `atol`.
+        pub fn atol(&self) -> f64 {
+            self.absolute_tolerance
+        }
+    }
+
+    impl LogScaleEvaluator {
+        /// The base of the logarithms.
+        pub fn base(&self) -> f64 {
+            self.base
+        }
+
+        /// The margin factor, applied in log space.
+        pub fn margin_factor(&self) -> f64 {
+            self.margin_factor
+        }
+    }
+
+    impl UlpsEvaluator {
+        /// The maximum number of ULPs by which the comparands may differ.
+        pub fn max_ulps(&self) -> u64 {
+            self.max_ulps
+        }
+    }
+
+    impl SignificantFiguresEvaluator {
+        /// The number of significant figures.
+        pub fn figures(&self) -> u32 {
+            self.figures
+        }
+
+        /// The rounding mode.
+        pub fn rounding_mode(&self) -> RoundingMode {
+            self.rounding_mode
+        }
+    }
+
+    impl<E> NanStrictEvaluator<E> {
+        /// The wrapped evaluator.
+        pub fn inner(&self) -> &E {
+            &self.inner
+        }
+    }
+
+    impl<E> FlushSubnormalsEvaluator<E> {
+        /// The wrapped evaluator.
+        pub fn inner(&self) -> &E {
+            &self.inner
+        }
+    }
+
+    impl<E> SignedZeroAsApproxEvaluator<E> {
+        /// The wrapped evaluator.
+        pub fn inner(&self) -> &E {
+            &self.inner
+        }
+    }
+
+    impl<E> NoiseFloorEvaluator<E> {
+        /// The noise floor.
+        pub fn floor(&self) -> f64 {
+            self.floor
+        }
+
+        /// The wrapped evaluator.
+        pub fn inner(&self) -> &E {
+            &self.inner
+        }
+    }
+
+    impl<E> SaturatingEvaluator<E> {
+        /// The saturation limit.
+        pub fn limit(&self) -> f64 {
+            self.limit
+        }
+
+        /// The wrapped evaluator.
+        pub fn inner(&self) -> &E {
+            &self.inner
+        }
+    }
+
+    impl<E_positive, E_negative> SignDependentEvaluator<E_positive, E_negative> {
+        /// The evaluator applied to positive expected values.
+        pub fn positive(&self) -> &E_positive {
+            &self.positive
+        }
+
+        /// The evaluator applied to negative expected values.
+        pub fn negative(&self) -> &E_negative {
+            &self.negative
+        }
+
+        /// The dispatch of an expected value of zero.
+        pub fn zero_dispatch(&self) -> ZeroDispatch {
+            self.zero_dispatch
+        }
+
+        /// Indicates whether the given expected value is dispatched to the
+        /// negative evaluator.
+        fn dispatches_to_negative(
+            &self,
+            expected : f64,
+        ) -> bool {
+            if 0.0 == expected {
+                match self.zero_dispatch {
+                    ZeroDispatch::Positive => false,
+                    ZeroDispatch::Negative => true,
+                    ZeroDispatch::SignBit => expected.is_sign_negative(),
+                }
+            } else {
+                expected < 0.0
+            }
+        }
+    }
+
+    // Trait implementations
+
+    impl From<f64> for MarginEvaluator {
+        fn from(factor : f64) -> Self {
+            Self {
+                factor,
+            }
+        }
+    }
+
+    impl ApproximateEqualityEvaluator for MarginEvaluator {
+        #[inline]
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            let comparison_result = compare_approximate_equality_by_margin(expected, actual, self.factor);
+
+            (comparison_result, Some(self.factor), None)
+        }
+
+        fn passing_factor(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<f64> {
+            Some(crate::suggest_margin(&[(expected, actual)]))
+        }
+    }
+
+    impl ApproximateEqualityEvaluator for MultiplierEvaluator {
+        #[inline]
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            let comparison_result = compare_approximate_equality_by_multiplier(expected, actual, self.factor);
+
+            (comparison_result, None, Some(self.factor))
+        }
+
+        fn passing_factor(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<f64> {
+            Some(crate::suggest_multiplier(&[(expected, actual)]))
+        }
+    }
+
+    impl ApproximateEqualityEvaluator for ZeroMarginOrMultiplierEvaluator {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            let comparison_result = compare_approximate_equality_by_zero_margin_or_multiplier(
+                expected,
+                actual,
+                self.multiplier_factor,
+                self.zero_margin_factor,
+            );
+
+            (
+                comparison_result,
+                Some(self.zero_margin_factor),
+                Some(self.multiplier_factor),
+            )
+        }
+
+        fn passing_factor(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<f64> {
+            if 0.0 == expected || 0.0 == actual {
+                Some(crate::suggest_margin(&[(expected, actual)]))
+            } else {
+                Some(crate::suggest_multiplier(&[(expected, actual)]))
+            }
+        }
+    }
+
+    impl ApproximateEqualityEvaluator for AsymmetricMarginEvaluator {
+        /// Evaluates the comparands, reporting the greater of the lower and
+        /// upper factors as the margin factor.
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            let comparison_result = compare_approximate_equality_by_asymmetric_margin(
+                expected,
+                actual,
+                self.lower_factor,
+                self.upper_factor,
+            );
+
+            (comparison_result, Some(self.lower_factor.max(self.upper_factor)), None)
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            let _ = actual;
+
+            Some(format!(
+                "acceptance interval [{}, {}] (lower_factor={}, upper_factor={})",
+                expected - self.lower_factor,
+                expected + self.upper_factor,
+                self.lower_factor,
+                self.upper_factor,
+            ))
+        }
+    }
+
+    impl ApproximateEqualityEvaluator for RelativeGeomeanEvaluator {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            let comparison_result = compare_approximate_equality_by_relative_geomean(
+                expected,
+                actual,
+                self.multiplier_factor,
+                self.zero_margin_factor,
+            );
+
+            (comparison_result, self.zero_margin_factor, Some(self.multiplier_factor))
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            if 0.0 == expected || 0.0 == actual {
+                None
+            } else {
+                let geomean = (expected * actual).abs().sqrt();
+
+                Some(format!(
+                    "geometric mean {geomean}, acceptance band ±{}",
+                    self.multiplier_factor * geomean
+                ))
+            }
+        }
+
+        fn passing_factor(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<f64> {
+            if 0.0 == expected || 0.0 == actual {
+                self.zero_margin_factor
+                    .map(|_| crate::suggest_margin(&[(expected, actual)]))
+            } else {
+                let mut multiplier_factor = (actual - expected).abs() / (expected * actual).abs().sqrt();
+
+                if !multiplier_factor.is_finite() {
+                    return None;
+                }
+
+                // allow for rounding in the computation of the acceptance interval
+                while ComparisonResult::Unequal
+                    == compare_approximate_equality_by_relative_geomean(expected, actual, multiplier_factor, None)
+                {
+                    multiplier_factor = multiplier_factor.next_up();
+                }
+
+                Some(multiplier_factor)
+            }
+        }
+    }
+
+    impl ApproximateEqualityEvaluator for FullScaleEvaluator {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            let margin_factor = self.margin_factor();
+
+            let comparison_result = compare_approximate_equality_by_margin(expected, actual, margin_factor);
+
+            (comparison_result, Some(margin_factor), None)
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            let _ = (expected, actual);
+
+            Some(format!("full-scale range {}, fraction {}", self.range, self.fraction))
+        }
+    }
+
+    impl ApproximateEqualityEvaluator for PpmEvaluator {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            let multiplier_factor = self.multiplier_factor();
+
+            let comparison_result = compare_approximate_equality_by_multiplier(expected, actual, multiplier_factor);
+
+            (comparison_result, None, Some(multiplier_factor))
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            if 0.0 == expected {
+                Some(format!(
+                    "error cannot be expressed in ppm of a zero expected value (tolerance {:.3} ppm)",
+                    self.parts_per_million
+                ))
+            } else {
+                let error_ppm = (actual - expected).abs() / expected.abs() * 1e6;
+                let relation = if error_ppm > self.parts_per_million {
+                    "exceeds"
+                } else {
+                    "is within"
+                };
+
+                Some(format!(
+                    "error {error_ppm:.3} ppm {relation} {:.3} ppm",
+                    self.parts_per_million
+                ))
+            }
+        }
+    }
+
+    impl ApproximateEqualityEvaluator for NumpyCloseEvaluator {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            let comparison_result = compare_approximate_equality_by_numpy_close(
+                expected,
+                actual,
+                self.relative_tolerance,
+                self.absolute_tolerance,
+            );
+
+            // NOTE: the tolerances are not reported as margin/multiplier
+            // factors, since they combine additively rather than either/or
+            (comparison_result, None, None)
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            let difference = (actual - expected).abs();
+            let tolerance = self.absolute_tolerance + self.relative_tolerance * actual.abs();
+            let relation = if difference <= tolerance { "<=" } else { "exceeds" };
+
+            Some(format!(
+                "|actual - expected| = {difference:e} {relation} atol + rtol * |actual| = {tolerance:e} (rtol={}, atol={})",
+                self.relative_tolerance, self.absolute_tolerance
+            ))
+        }
+    }
+
+    impl ApproximateEqualityEvaluator for LogScaleEvaluator {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            let comparison_result =
+                compare_approximate_equality_by_log_scale(expected, actual, self.base, self.margin_factor);
+
+            (comparison_result, Some(self.margin_factor), None)
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            if expected.is_nan() || actual.is_nan() {
+                None
+            } else if expected <= 0.0 || actual <= 0.0 {
+                Some(format!(
+                    "non-positive comparand cannot be compared in log space (base {})",
+                    self.base
+                ))
+            } else {
+                Some(format!(
+                    "log-space difference {} (base {})",
+                    (actual.log(self.base) - expected.log(self.base)).abs(),
+                    self.base
+                ))
+            }
+        }
+    }
+
+    impl ApproximateEqualityEvaluator for UlpsEvaluator {
+        /// Evaluates the comparands, reporting neither margin factor nor
+        /// multiplier factor, since neither applies.
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            let comparison_result = compare_approximate_equality_by_ulps(expected, actual, self.max_ulps);
+
+            (comparison_result, None, None)
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            ulps_distance(expected, actual)
+                .map(|distance| format!("ULP distance {distance} (allowed {})", self.max_ulps))
+        }
+    }
+
+    impl ApproximateEqualityEvaluator for SignificantFiguresEvaluator {
+        /// Evaluates the comparands, reporting neither margin factor nor
+        /// multiplier factor, since neither applies.
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            let comparison_result =
+                compare_approximate_equality_by_significant_figures(expected, actual, self.figures, self.rounding_mode);
+
+            (comparison_result, None, None)
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            Some(format!(
+                "rounded to {} significant figure(s) ({:?}): expected {}, actual {}",
+                self.figures,
+                self.rounding_mode,
+                round_to_significant_figures(expected, self.figures, self.rounding_mode),
+                round_to_significant_figures(actual, self.figures, self.rounding_mode),
+            ))
+        }
+    }
+
+    impl<E> ApproximateEqualityEvaluator for NanStrictEvaluator<E>
+    where
+        E : ApproximateEqualityEvaluator,
+    {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            let (comparison_result, margin_factor, multiplier_factor) = self.inner.evaluate(expected, actual);
+
+            if expected.is_nan() || actual.is_nan() {
+                (ComparisonResult::Unequal, margin_factor, multiplier_factor)
+            } else {
+                (comparison_result, margin_factor, multiplier_factor)
+            }
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            if expected.is_nan() || actual.is_nan() {
+                Some("comparand is NaN".into())
+            } else {
+                self.inner.describe(expected, actual)
+            }
+        }
+
+        fn passing_factor(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<f64> {
+            self.inner.passing_factor(expected, actual)
+        }
+    }
+
+    impl<E> ApproximateEqualityEvaluator for TotalCmpNanEvaluator<E>
+    where
+        E : ApproximateEqualityEvaluator,
+    {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            if expected.is_nan() || actual.is_nan() {
+                if expected.total_cmp(&actual).is_eq() {
+                    (ComparisonResult::BothNaN, None, None)
+                } else {
+                    (ComparisonResult::Unequal, None, None)
+                }
+            } else {
+                self.inner.evaluate(expected, actual)
+            }
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            if expected.is_nan() || actual.is_nan() {
+                Some("NaN differs by total order".into())
+            } else {
+                self.inner.describe(expected, actual)
+            }
+        }
+
+        fn passing_factor(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<f64> {
+            self.inner.passing_factor(expected, actual)
+        }
+    }
+
+    impl<E> ApproximateEqualityEvaluator for NanEqualEvaluator<E>
+    where
+        E : ApproximateEqualityEvaluator,
+    {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            if expected.is_nan() && actual.is_nan() {
+                (ComparisonResult::BothNaN, None, None)
+            } else {
+                self.inner.evaluate(expected, actual)
+            }
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            self.inner.describe(expected, actual)
+        }
+
+        fn passing_factor(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<f64> {
+            self.inner.passing_factor(expected, actual)
+        }
+    }
+
+    impl<E> ApproximateEqualityEvaluator for FlushSubnormalsEvaluator<E>
+    where
+        E : ApproximateEqualityEvaluator,
+    {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            self.inner
+                .evaluate(flush_subnormal_to_zero(expected), flush_subnormal_to_zero(actual))
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            self.inner
+                .describe(flush_subnormal_to_zero(expected), flush_subnormal_to_zero(actual))
+        }
+
+        fn passing_factor(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<f64> {
+            self.inner
+                .passing_factor(flush_subnormal_to_zero(expected), flush_subnormal_to_zero(actual))
+        }
+    }
+
+    impl<E> ApproximateEqualityEvaluator for SignedZeroAsApproxEvaluator<E>
+    where
+        E : ApproximateEqualityEvaluator,
+    {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            let (comparison_result, margin_factor, multiplier_factor) = self.inner.evaluate(expected, actual);
+
+            if ComparisonResult::ExactlyEqual == comparison_result
+                && 0.0 == expected
+                && 0.0 == actual
+                && expected.is_sign_negative() != actual.is_sign_negative()
+            {
+                (ComparisonResult::ApproximatelyEqual, margin_factor, multiplier_factor)
+            } else {
+                (comparison_result, margin_factor, multiplier_factor)
+            }
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            self.inner.describe(expected, actual)
+        }
+
+        fn passing_factor(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<f64> {
+            self.inner.passing_factor(expected, actual)
+        }
+    }
+
+    impl<E> ApproximateEqualityEvaluator for NoiseFloorEvaluator<E>
+    where
+        E : ApproximateEqualityEvaluator,
+    {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            if expected.abs() <= self.floor && actual.abs() <= self.floor {
+                if expected == actual {
+                    (ComparisonResult::ExactlyEqual, None, None)
+                } else {
+                    (ComparisonResult::ApproximatelyEqual, None, None)
+                }
+            } else {
+                self.inner.evaluate(expected, actual)
+            }
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            self.inner.describe(expected, actual)
+        }
+
+        fn passing_factor(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<f64> {
+            self.inner.passing_factor(expected, actual)
+        }
+    }
+
+    impl<E> ApproximateEqualityEvaluator for SaturatingEvaluator<E>
+    where
+        E : ApproximateEqualityEvaluator,
+    {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            let is_saturated = |value : f64| value.abs() > self.limit;
+
+            if is_saturated(expected)
+                && is_saturated(actual)
+                && expected.is_sign_negative() == actual.is_sign_negative()
+            {
+                if expected == actual {
+                    (ComparisonResult::ExactlyEqual, None, None)
+                } else {
+                    (ComparisonResult::ApproximatelyEqual, None, None)
+                }
+            } else {
+                self.inner.evaluate(expected, actual)
+            }
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            self.inner.describe(expected, actual)
+        }
+
+        fn passing_factor(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<f64> {
+            self.inner.passing_factor(expected, actual)
+        }
+    }
+
+    impl<E_positive, E_negative> ApproximateEqualityEvaluator for SignDependentEvaluator<E_positive, E_negative>
+    where
+        E_positive : ApproximateEqualityEvaluator,
+        E_negative : ApproximateEqualityEvaluator,
+    {
+        fn evaluate(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> (
+            ComparisonResult, // comparison_result
+            Option<f64>,      // margin_factor
+            Option<f64>,      // multiplier_factor
+        ) {
+            if self.dispatches_to_negative(expected) {
+                self.negative.evaluate(expected, actual)
+            } else {
+                self.positive.evaluate(expected, actual)
+            }
+        }
+
+        fn describe(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<String> {
+            if self.dispatches_to_negative(expected) {
+                self.negative.describe(expected, actual)
+            } else {
+                self.positive.describe(expected, actual)
+            }
+        }
+
+        fn passing_factor(
+            &self,
+            expected : f64,
+            actual : f64,
+        ) -> Option<f64> {
+            if self.dispatches_to_negative(expected) {
+                self.negative.passing_factor(expected, actual)
+            } else {
+                self.positive.passing_factor(expected, actual)
+            }
+        }
+    }
+}
+
+
+/// Ready-made evaluators for common numeric domains, for use when it is not
+/// obvious what tolerance to choose.
+pub mod presets {
+
+    use super::evaluators::{
+        MarginEvaluator,
+        MultiplierEvaluator,
+        ZeroMarginOrMultiplierEvaluator,
+    };
+
+
+    /// Evaluator suited to data of `f32` origin: a multiplier of
+    /// `4 * f32::EPSILON` (~4.77e-7), or a margin of `f32::EPSILON`
+    /// (~1.19e-7) when either comparand is zero.
+    pub fn single_precision() -> ZeroMarginOrMultiplierEvaluator {
+        crate::zero_margin_or_multiplier(4.0 * f32::EPSILON as f64, f32::EPSILON as f64)
+    }
+
+    /// Evaluator suited to data of `f16` (IEEE 754 half-precision) origin:
+    /// a multiplier of `4 * 2^-10` (~3.91e-3), or a margin of `2^-10`
+    /// (~9.77e-4) when either comparand is zero, `2^-10` being the machine
+    /// epsilon of `f16`.
+    pub fn half_precision() -> ZeroMarginOrMultiplierEvaluator {
+        const EPSILON : f64 = 0.0009765625;
+
+        crate::zero_margin_or_multiplier(4.0 * EPSILON, EPSILON)
+    }
+
+    /// Evaluator suited to data of `bf16` (bfloat16) origin: a multiplier
+    /// of `4 * 2^-7` (~3.13e-2), or a margin of `2^-7` (~7.81e-3) when
+    /// either comparand is zero, `2^-7` being the machine epsilon of
+    /// `bf16`.
+    pub fn bfloat16() -> ZeroMarginOrMultiplierEvaluator {
+        const EPSILON : f64 = 0.0078125;
+
+        crate::zero_margin_or_multiplier(4.0 * EPSILON, EPSILON)
+    }
+
+    /// Evaluator suited to data of `f64` origin: a multiplier of
+    /// `4 * f64::EPSILON` (~8.88e-16), or a margin of `f64::EPSILON`
+    /// (~2.22e-16) when either comparand is zero.
+    pub fn double_precision() -> ZeroMarginOrMultiplierEvaluator {
+        crate::zero_margin_or_multiplier(4.0 * f64::EPSILON, f64::EPSILON)
+    }
+
+    /// Evaluator suited to monetary amounts in major units: a margin of
+    /// `0.005`, i.e. half a cent.
+    pub fn currency() -> MarginEvaluator {
+        crate::margin(0.005)
+    }
+
+    /// Evaluator suited to physical quantities in SI units, whose
+    /// magnitudes vary over many orders: a multiplier of `1e-9` (one part
+    /// per billion), with no special treatment of zero.
+    pub fn physics_si() -> MultiplierEvaluator {
+        crate::multiplier(1e-9)
+    }
+}
+
+
+/// Utility types and functions, used in the implementation of the crate's
+/// functions and macros.
+///
+/// NOTE: the `compare_approximate_equality_by_*()` functions each treat
+/// same-signed infinities as exactly equal, whereupon any other infinite
+/// comparand is unequal, since it cannot be within a (finite) tolerance.
+#[doc(hidden)]
+pub mod utils {
+    use super::{
+        constants,
+        traits::{
+            ApproximateEqualityEvaluator,
+            CarriesTolerance,
+            DefaultTolerance,
+            ExtendedTestableAsF64,
+            IntoTestableAsF64,
+            MetricsSink,
+            TryTestableAsF64,
+        },
+        ComparisonResult,
+        MatrixComparisonResult,
+        RoundingMode,
+    };
+
+
+    /// T.B.C.
+    #[inline]
+    pub(crate) fn compare_approximate_equality_by_margin(
+        expected : f64,
+        actual : f64,
+        margin_factor : f64,
+    ) -> ComparisonResult {
+        debug_assert!(
+            margin_factor >= 0.0,
+            "`margin_factor` must not be negative, but {margin_factor} given"
+        );
+
+        if expected == actual {
+            return ComparisonResult::ExactlyEqual;
+        }
+
+        #[cfg(feature = "nan-equality")]
+        {
+            if expected.is_nan() && actual.is_nan() {
+                return ComparisonResult::BothNaN;
+            }
+        }
+
+        if expected.is_infinite() || actual.is_infinite() {
+            return ComparisonResult::Unequal;
+        }
+
+        // TODO: determine if can elide this explicit check
+        if 0.0 == margin_factor {
+            return ComparisonResult::Unequal;
+        }
+
+        let expected_lo = expected - margin_factor;
+        let expected_hi = expected + margin_factor;
+
+        result_from_range_(expected_lo, expected_hi, actual)
+    }
+
+    /// T.B.C.
+    pub(crate) fn compare_approximate_equality_by_asymmetric_margin(
+        expected : f64,
+        actual : f64,
+        lower_factor : f64,
+        upper_factor : f64,
+    ) -> ComparisonResult {
+        debug_assert!(
+            lower_factor >= 0.0,
+            "`lower_factor` must not be negative, but {lower_factor} given"
+        );
+        debug_assert!(
+            upper_factor >= 0.0,
+            "`upper_factor` must not be negative, but {upper_factor} given"
+        );
+
+        if expected == actual {
+            return ComparisonResult::ExactlyEqual;
+        }
+
+        #[cfg(feature = "nan-equality")]
+        {
+            if expected.is_nan() && actual.is_nan() {
+                return ComparisonResult::BothNaN;
+            }
+        }
+
+        if expected.is_infinite() || actual.is_infinite() {
+            return ComparisonResult::Unequal;
+        }
+
+        // TODO: determine if can elide this explicit check
+        if 0.0 == lower_factor && 0.0 == upper_factor {
+            return ComparisonResult::Unequal;
+        }
+
+        let expected_lo = expected - lower_factor;
+        let expected_hi = expected + upper_factor;
+
+        result_from_range_(expected_lo, expected_hi, actual)
+    }
+
+    /// T.B.C.
+    #[inline]
+    pub(crate) fn compare_approximate_equality_by_multiplier(
+        expected : f64,
+        actual : f64,
+        multiplier_factor : f64,
+    ) -> ComparisonResult {
+        debug_assert!(
+            multiplier_factor >= 0.0,
+            "`multiplier_factor` must not be negative, but {multiplier_factor} given"
+        );
+
+        if expected == actual {
+            return ComparisonResult::ExactlyEqual;
+        }
+
+        #[cfg(feature = "nan-equality")]
+        {
+            if expected.is_nan() && actual.is_nan() {
+                return ComparisonResult::BothNaN;
+            }
+        }
+
+        if expected.is_infinite() || actual.is_infinite() {
+            return ComparisonResult::Unequal;
+        }
+
+        // TODO: determine if can elide this explicit check
+        if 0.0 == multiplier_factor {
+            return ComparisonResult::Unequal;
+        }
+
+        let expected_lo = expected * (1.0 - multiplier_factor);
+        let expected_hi = expected * (1.0 + multiplier_factor);
+
+        result_from_range_(expected_lo, expected_hi, actual)
+    }
+
+    /// T.B.C.
+    pub(crate) fn compare_approximate_equality_by_zero_margin_or_multiplier(
+        expected : f64,
+        actual : f64,
+        multiplier_factor : f64,
+        margin_factor : f64,
+    ) -> ComparisonResult {
+        debug_assert!(
+            multiplier_factor >= 0.0,
+            "`multiplier_factor` must not be negative, but {multiplier_factor} given"
+        );
+        debug_assert!(
+            margin_factor >= 0.0,
+            "`margin_factor` must not be negative, but {margin_factor} given"
+        );
+
+        if expected == actual {
+            return ComparisonResult::ExactlyEqual;
+        }
+
+        #[cfg(feature = "nan-equality")]
+        {
+            if expected.is_nan() && actual.is_nan() {
+                return ComparisonResult::BothNaN;
+            }
+        }
+
+        if expected.is_infinite() || actual.is_infinite() {
+            return ComparisonResult::Unequal;
+        }
+
+        let (expected_lo, expected_hi) = if 0.0 == expected || 0.0 == actual {
+            // TODO: determine if can elide this explicit check
+            if 0.0 == margin_factor {
+                return ComparisonResult::Unequal;
+            }
+
+            let expected_lo = expected - margin_factor;
+            let expected_hi = expected + margin_factor;
+
+            (expected_lo, expected_hi)
+        } else {
+            // TODO: determine if can elide this explicit check
+            if 0.0 == multiplier_factor {
+                return ComparisonResult::Unequal;
+            }
+
+            let expected_lo = expected * (1.0 - multiplier_factor);
+            let expected_hi = expected * (1.0 + multiplier_factor);
+
+            (expected_lo, expected_hi)
+        };
+
+        result_from_range_(expected_lo, expected_hi, actual)
+    }
+
+    /// T.B.C.
+    pub(crate) fn compare_approximate_equality_by_relative_geomean(
+        expected : f64,
+        actual : f64,
+        multiplier_factor : f64,
+        zero_margin_factor : Option<f64>,
+    ) -> ComparisonResult {
+        debug_assert!(
+            multiplier_factor >= 0.0,
+            "`multiplier_factor` must not be negative, but {multiplier_factor} given"
+        );
+
+        if expected == actual {
+            return ComparisonResult::ExactlyEqual;
+        }
+
+        #[cfg(feature = "nan-equality")]
+        {
+            if expected.is_nan() && actual.is_nan() {
+                return ComparisonResult::BothNaN;
+            }
+        }
+
+        if expected.is_infinite() || actual.is_infinite() {
+            return ComparisonResult::Unequal;
+        }
+
+        let band = if 0.0 == expected || 0.0 == actual {
+            match zero_margin_factor {
+                Some(zero_margin_factor) => {
+                    debug_assert!(
+                        zero_margin_factor >= 0.0,
+                        "`zero_margin_factor` must not be negative, but {zero_margin_factor} given"
+                    );
+
+                    zero_margin_factor
+                },
+                None => 0.0,
+            }
+        } else {
+            multiplier_factor * (expected * actual).abs().sqrt()
+        };
+
+        // TODO: determine if can elide this explicit check
+        if 0.0 == band {
+            return ComparisonResult::Unequal;
+        }
+
+        result_from_range_(expected - band, expected + band, actual)
+    }
+
+    /// T.B.C.
+    pub(crate) fn compare_approximate_equality_by_log_scale(
+        expected : f64,
+        actual : f64,
+        base : f64,
+        margin_factor : f64,
+    ) -> ComparisonResult {
+        debug_assert!(
+            base > 0.0 && 1.0 != base,
+            "`base` must be positive and not 1, but {base} given"
+        );
+        debug_assert!(
+            margin_factor >= 0.0,
+            "`margin_factor` must not be negative, but {margin_factor} given"
+        );
+
+        if expected == actual {
+            return ComparisonResult::ExactlyEqual;
+        }
+
+        #[cfg(feature = "nan-equality")]
+        {
+            if expected.is_nan() && actual.is_nan() {
+                return ComparisonResult::BothNaN;
+            }
+        }
+
+        if expected.is_infinite() || actual.is_infinite() {
+            return ComparisonResult::Unequal;
+        }
+
+        // the logarithm of a non-positive value is undefined
+        if !(expected > 0.0 && actual > 0.0) {
+            return ComparisonResult::Unequal;
+        }
+
+        if (actual.log(base) - expected.log(base)).abs() <= margin_factor {
+            ComparisonResult::ApproximatelyEqual
+        } else {
+            ComparisonResult::Unequal
+        }
+    }
+
+    /// T.B.C.
+    pub(crate) fn compare_approximate_equality_by_numpy_close(
+        expected : f64,
+        actual : f64,
+        relative_tolerance : f64,
+        absolute_tolerance : f64,
+    ) -> ComparisonResult {
+        debug_assert!(
+            relative_tolerance >= 0.0,
+            "`relative_tolerance` must not be negative, but {relative_tolerance} given"
+        );
+        debug_assert!(
+            absolute_tolerance >= 0.0,
+            "`absolute_tolerance` must not be negative, but {absolute_tolerance} given"
+        );
+
+        if expected == actual {
+            return ComparisonResult::ExactlyEqual;
+        }
+
+        #[cfg(feature = "nan-equality")]
+        {
+            if expected.is_nan() && actual.is_nan() {
+                return ComparisonResult::BothNaN;
+            }
+        }
+
+        if expected.is_infinite() || actual.is_infinite() {
+            return ComparisonResult::Unequal;
+        }
+
+        // NOTE: as numpy, the relative tolerance is scaled by the actual
+        // (`b`) comparand only, so the test is not symmetric
+        if (actual - expected).abs() <= absolute_tolerance + relative_tolerance * actual.abs() {
+            ComparisonResult::ApproximatelyEqual
+        } else {
+            ComparisonResult::Unequal
+        }
+    }
+
+    /// T.B.C.
+    pub(crate) fn compare_approximate_equality_by_weighted_margin(
+        expected : f64,
+        actual : f64,
+        weight : f64,
+        margin_factor : f64,
+    ) -> ComparisonResult {
+        debug_assert!(weight >= 0.0, "`weight` must not be negative, but {weight} given");
+        debug_assert!(
+            margin_factor >= 0.0,
+            "`margin_factor` must not be negative, but {margin_factor} given"
+        );
+
+        if expected == actual {
+            return ComparisonResult::ExactlyEqual;
+        }
+
+        #[cfg(feature = "nan-equality")]
+        {
+            if expected.is_nan() && actual.is_nan() {
+                return ComparisonResult::BothNaN;
+            }
+        }
+
+        if expected.is_infinite() || actual.is_infinite() {
+            return ComparisonResult::Unequal;
+        }
+
+        if weight * (expected - actual).abs() <= margin_factor {
+            ComparisonResult::ApproximatelyEqual
+        } else {
+            ComparisonResult::Unequal
+        }
+    }
+
+    /// T.B.C.
+    pub(crate) fn compare_approximate_equality_by_significant_figures(
+        expected : f64,
+        actual : f64,
+        figures : u32,
+        rounding_mode : RoundingMode,
+    ) -> ComparisonResult {
+        debug_assert!(figures >= 1, "`figures` must be at least 1, but {figures} given");
+
+        if expected == actual {
+            return ComparisonResult::ExactlyEqual;
+        }
+
+        #[cfg(feature = "nan-equality")]
+        {
+            if expected.is_nan() && actual.is_nan() {
+                return ComparisonResult::BothNaN;
+            }
+        }
+
+        if expected.is_infinite() || actual.is_infinite() {
+            return ComparisonResult::Unequal;
+        }
+
+        if round_to_significant_figures(expected, figures, rounding_mode)
+            == round_to_significant_figures(actual, figures, rounding_mode)
+        {
+            ComparisonResult::ApproximatelyEqual
+        } else {
+            ComparisonResult::Unequal
+        }
+    }
+
+    /// Rounds `value` to the given number of significant (decimal) figures
+    /// in accordance with `rounding_mode`. The rounding is applied to the
+    /// shortest decimal representation that round-trips to `value` - i.e.
+    /// that with which it is displayed - rather than to its exact binary
+    /// expansion, so that, say, `1.35` is a tie, and `1.2` is not truncated
+    /// to `1.1`. Zeroes, infinities, and NaNs are returned unchanged.
+    pub fn round_to_significant_figures(
+        value : f64,
+        figures : u32,
+        rounding_mode : RoundingMode,
+    ) -> f64 {
+        if 0.0 == value || !value.is_finite() {
+            return value;
+        }
+
+        let figures = figures.max(1) as usize;
+
+        let expansion = format!("{:e}", value.abs());
+        let (mantissa, exponent) = expansion.split_once('e').unwrap();
+        let mut exponent : i32 = exponent.parse().unwrap();
+        let digits : Vec<u8> = mantissa.bytes().filter(u8::is_ascii_digit).map(|b| b - b'0').collect();
+
+        if figures >= digits.len() {
+            return value;
+        }
+
+        let mut kept = digits[..figures].to_vec();
+        let first_discarded = digits[figures];
+        let any_later_discarded = digits[figures + 1..].iter().any(|&d| 0 != d);
+
+        let round_up = match rounding_mode {
+            RoundingMode::TowardZero => false,
+            RoundingMode::HalfUp => first_discarded >= 5,
+            RoundingMode::HalfEven => {
+                first_discarded > 5 || (5 == first_discarded && (any_later_discarded || 1 == kept[figures - 1] % 2))
+            },
+        };
+
+        if round_up {
+            let mut ix = figures;
+
+            loop {
+                if 0 == ix {
+                    kept.insert(0, 1);
+                    kept.truncate(figures);
+                    exponent += 1;
+
+                    break;
+                }
+
+                ix -= 1;
+
+                if 9 == kept[ix] {
+                    kept[ix] = 0;
+                } else {
+                    kept[ix] += 1;
+
+                    break;
+                }
+            }
+        }
+
+        let mut rounded = String::with_capacity(figures + 8);
+
+        rounded.push(char::from(b'0' + kept[0]));
+        rounded.push('.');
+        rounded.extend(kept[1..].iter().map(|&d| char::from(b'0' + d)));
+        rounded += &format!("0e{exponent}");
+
+        let rounded : f64 = rounded.parse().unwrap();
+
+        if value < 0.0 {
+            -rounded
+        } else {
+            rounded
+        }
+    }
+
+    /// T.B.C.
+    pub(crate) fn compare_approximate_equality_by_ulps(
+        expected : f64,
+        actual : f64,
+        max_ulps : u64,
+    ) -> ComparisonResult {
+        if expected == actual {
+            return ComparisonResult::ExactlyEqual;
+        }
+
+        #[cfg(feature = "nan-equality")]
+        {
+            if expected.is_nan() && actual.is_nan() {
+                return ComparisonResult::BothNaN;
+            }
+        }
+
+        if expected.is_infinite() || actual.is_infinite() {
+            return ComparisonResult::Unequal;
+        }
+
+        match ulps_distance(expected, actual) {
+            Some(distance) if distance <= max_ulps => ComparisonResult::ApproximatelyEqual,
+            _ => ComparisonResult::Unequal,
+        }
+    }
+
+    /// Obtains the number of representable `f64` values - units in the last
+    /// place (ULPs) - between `expected` and `actual`, or `None` if either
+    /// is NaN. Positive and negative zero are 0 ULPs apart.
+    pub fn ulps_distance(
+        expected : f64,
+        actual : f64,
+    ) -> Option<u64> {
+        if expected.is_nan() || actual.is_nan() {
+            return None;
+        }
+
+        // maps the bit patterns onto a monotonic integer line, on which
+        // both zeroes are 0
+        let ordinal = |v : f64| -> i128 {
+            let bits = v.to_bits() as i64;
+
+            if bits < 0 {
+                i128::from(i64::MIN) - i128::from(bits)
+            } else {
+                i128::from(bits)
+            }
+        };
+
+        Some((ordinal(expected) - ordinal(actual)).unsigned_abs() as u64)
+    }
+
+    /// Simple, deterministic, pseudo-random number generator - SplitMix64 -
+    /// sufficient for generating test comparands without a dependency.
+    pub(crate) struct SplitMix64_ {
+        state : u64,
+    }
+
+    impl SplitMix64_ {
+        pub(crate) fn new(seed : u64) -> Self {
+            Self {
+                state : seed
+            }
+        }
+
+        pub(crate) fn next_u64(&mut self) -> u64 {
+            self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+            let mut z = self.state;
+
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+            z ^ (z >> 31)
+        }
+
+        /// Obtains a value in `[0, 1)`.
+        pub(crate) fn next_unit_f64(&mut self) -> f64 {
+            (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+        }
+    }
+
+    /// Describes the comparison of an infinite comparand with a finite one,
+    /// or of infinities of opposite sign, as is likely to result from
+    /// overflow, or `None` otherwise.
+    pub fn describe_infinity_mismatch(
+        expected : f64,
+        actual : f64,
+    ) -> Option<String> {
+        match (expected.is_infinite(), actual.is_infinite()) {
+            (true, true) => {
+                if expected != actual {
+                    Some(format!(
+                        "infinity mismatch: expected is {expected} but actual is {actual}"
+                    ))
+                } else {
+                    None
+                }
+            },
+            (true, false) => {
+                if actual.is_nan() {
+                    None
+                } else {
+                    Some(format!(
+                        "overflow/infinity mismatch: expected is {expected} but actual is finite"
+                    ))
+                }
+            },
+            (false, true) => {
+                if expected.is_nan() {
+                    None
+                } else {
+                    Some(format!(
+                        "overflow/infinity mismatch: actual is {actual} but expected is finite"
+                    ))
+                }
+            },
+            (false, false) => None,
+        }
+    }
+
+    /// Obtains the description of an evaluation for appending to an
+    /// assertion failure message, preferring that of any infinity mismatch,
+    /// as obtained by [`describe_infinity_mismatch()`], to that of the
+    /// evaluator, since any interval computed from an infinite comparand is
+    /// meaningless.
+    pub fn describe_evaluation(
+        evaluator : &dyn ApproximateEqualityEvaluator,
+        expected : f64,
+        actual : f64,
+    ) -> Option<String> {
+        describe_infinity_mismatch(expected, actual).or_else(|| evaluator.describe(expected, actual))
+    }
+
+    /// Obtains the given vector of 3-tuples as a slice, by which
+    /// [`assert_tuple3_eq_approx!()`] accepts only 3-tuples.
+    ///
+    /// [`assert_tuple3_eq_approx!()`]: crate::assert_tuple3_eq_approx
+    pub fn as_tuple3_slice<T, T0, T1, T2>(values : &T) -> &[(T0, T1, T2)]
+    where
+        T : std::convert::AsRef<[(T0, T1, T2)]> + ?Sized,
+    {
+        values.as_ref()
+    }
+
+    /// Formats the factors and the (evaluator-specific) description of an
+    /// evaluation for appending to an assertion failure message.
+    pub fn format_evaluation_suffix(
+        margin_factor : Option<f64>,
+        multiplier_factor : Option<f64>,
+        description : Option<String>,
+    ) -> String {
+        let mut suffix = String::new();
+
+        if let Some(margin_factor) = margin_factor {
+            suffix += &format!(", margin_factor={margin_factor}");
+        }
+        if let Some(multiplier_factor) = multiplier_factor {
+            suffix += &format!(", multiplier_factor={multiplier_factor}");
+        }
+        if let Some(description) = description {
+            suffix += &format!(": {description}");
+        }
+
+        suffix
+    }
+
+    /// Describes the failure recorded in a matrix comparison result, in the
+    /// terms used by `assert_matrix_eq_approx!()`, or obtains an empty
+    /// string for a passing result.
+    pub fn describe_matrix_mismatch(comparison_result : &MatrixComparisonResult) -> String {
+        match comparison_result {
+            MatrixComparisonResult::ExactlyEqual | MatrixComparisonResult::ApproximatelyEqual => String::new(),
+            MatrixComparisonResult::DifferentRowCounts {
+                expected_row_count,
+                actual_row_count,
+            } => format!("expected-row-count {expected_row_count} differs from actual-row-count {actual_row_count}"),
+            MatrixComparisonResult::DifferentRowLengths {
+                row_index,
+                expected_length,
+                actual_length,
+            } => {
+                format!(
+                    "at row {row_index} expected-length {expected_length} differs from actual-length {actual_length}"
+                )
+            },
+            MatrixComparisonResult::UnequalElements {
+                row_index,
+                column_index,
+                expected_value,
+                actual_value,
+            } => format!("at ({row_index}, {column_index}) expected={expected_value:?}, actual={actual_value:?}"),
+        }
+    }
+
+    /// Obtains `value`, or, if it is subnormal, zero of the same sign.
+    pub(crate) fn flush_subnormal_to_zero(value : f64) -> f64 {
+        if value.is_subnormal() {
+            0.0f64.copysign(value)
+        } else {
+            value
+        }
+    }
+
+    // 0 denotes full `Debug` formatting
+    static MESSAGE_PRECISION : std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+
+    pub(crate) fn set_message_precision_(digits : Option<u32>) -> Option<u32> {
+        let previous = MESSAGE_PRECISION.swap(digits.unwrap_or(0), std::sync::atomic::Ordering::Relaxed);
+
+        (0 != previous).then_some(previous)
+    }
+
+    /// Formats `value` for a failure message: in full, via `Debug`, unless
+    /// a precision has been set via
+    /// [`set_message_precision()`](crate::set_message_precision), in which
+    /// case it is first rounded to that number of significant figures.
+    pub fn format_message_value(value : f64) -> String {
+        match MESSAGE_PRECISION.load(std::sync::atomic::Ordering::Relaxed) {
+            0 => format!("{value:?}"),
+            digits => {
+                format!(
+                    "{:?}",
+                    round_to_significant_figures(value, digits, RoundingMode::HalfEven)
+                )
+            },
+        }
+    }
+
+    /// Formats a scalar comparand for a failure message: as given, via
+    /// `Debug`, unless a precision has been set, in which case its value
+    /// as `f64` is formatted as by [`format_message_value()`].
+    pub fn format_message_param(
+        param : &dyn std::fmt::Debug,
+        value : f64,
+    ) -> String {
+        match MESSAGE_PRECISION.load(std::sync::atomic::Ordering::Relaxed) {
+            0 => format!("{param:?}"),
+            _ => format_message_value(value),
+        }
+    }
+
+    static WARN_ON_SELF_COMPARISON : std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+    pub(crate) fn set_warn_on_self_comparison_(enabled : bool) -> bool {
+        WARN_ON_SELF_COMPARISON.swap(enabled, std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Writes a warning to the standard error stream if self-comparison
+    /// warnings have been enabled via
+    /// [`set_warn_on_self_comparison()`](crate::set_warn_on_self_comparison)
+    /// and `expected` and `actual` are the same (non-empty) slice, returning
+    /// whether the warning was written.
+    pub fn warn_if_self_comparison<T_expectedElement, T_actualElement>(
+        macro_name : &str,
+        expected : &[T_expectedElement],
+        actual : &[T_actualElement],
+    ) -> bool {
+        if !WARN_ON_SELF_COMPARISON.load(std::sync::atomic::Ordering::Relaxed) {
+            return false;
+        }
+
+        let is_aliased = !expected.is_empty()
+            && expected.len() == actual.len()
+            && std::mem::size_of::<T_expectedElement>() == std::mem::size_of::<T_actualElement>()
+            && std::ptr::eq(expected.as_ptr().cast::<u8>(), actual.as_ptr().cast::<u8>());
+
+        if is_aliased {
+            eprintln!(
+                "warning: {macro_name}: expected and actual are the same {}-element slice (at {:p}), so the comparison cannot fail",
+                expected.len(),
+                expected.as_ptr()
+            );
+        }
+
+        is_aliased
+    }
+
+    static METRICS_SINK_INSTALLED : std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+    static METRICS_SINK : std::sync::RwLock<Option<std::sync::Arc<dyn MetricsSink>>> = std::sync::RwLock::new(None);
+
+    pub(crate) fn set_metrics_sink_(
+        sink : Option<std::sync::Arc<dyn MetricsSink>>
+    ) -> Option<std::sync::Arc<dyn MetricsSink>> {
+        let mut guard = METRICS_SINK.write().unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        METRICS_SINK_INSTALLED.store(sink.is_some(), std::sync::atomic::Ordering::Relaxed);
+
+        std::mem::replace(&mut *guard, sink)
+    }
+
+    /// Obtains the (half-)width of the acceptance band of a comparison, as
+    /// approximated from the factors reported by the evaluator, or `None`
+    /// if no positive, finite band can be determined.
+    pub(crate) fn allowed_band_(
+        expected : f64,
+        actual : f64,
+        margin_factor : Option<f64>,
+        multiplier_factor : Option<f64>,
+    ) -> Option<f64> {
+        let allowed_band = match (margin_factor, multiplier_factor) {
+            (Some(margin_factor), Some(_)) if 0.0 == expected || 0.0 == actual => margin_factor,
+            (_, Some(multiplier_factor)) => multiplier_factor * expected.abs(),
+            (Some(margin_factor), None) => margin_factor,
+            (None, None) => return None,
+        };
+
+        if allowed_band.is_finite() && allowed_band > 0.0 {
+            Some(allowed_band)
+        } else {
+            None
+        }
+    }
+
+    /// Reports the metrics of a comparison to the sink installed via
+    /// [`set_metrics_sink()`](crate::set_metrics_sink), if any.
+    #[inline]
+    pub fn report_comparison_metrics(
+        expected : f64,
+        actual : f64,
+        margin_factor : Option<f64>,
+        multiplier_factor : Option<f64>,
+    ) {
+        if !METRICS_SINK_INSTALLED.load(std::sync::atomic::Ordering::Relaxed) {
+            return;
+        }
+
+        report_comparison_metrics_(expected, actual, margin_factor, multiplier_factor);
+    }
+
+    #[cold]
+    fn report_comparison_metrics_(
+        expected : f64,
+        actual : f64,
+        margin_factor : Option<f64>,
+        multiplier_factor : Option<f64>,
+    ) {
+        let delta = (actual - expected).abs();
+
+        if !delta.is_finite() {
+            return;
+        }
+
+        if let Some(allowed_band) = allowed_band_(expected, actual, margin_factor, multiplier_factor) {
+            let sink = METRICS_SINK
+                .read()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .clone();
+
+            if let Some(sink) = sink {
+                sink.record(delta, allowed_band);
+            }
+        }
+    }
+
+    /// Formats the expected and actual values of a failed comparison for
+    /// appending to an assertion failure message: when the `"pretty"`
+    /// feature is enabled, they are rendered aligned on their decimal
+    /// points, one above the other, with the first differing character
+    /// marked with a caret; otherwise, the empty string is returned, so
+    /// that the plain message is unaffected.
+    pub fn format_divergence(
+        expected : f64,
+        actual : f64,
+    ) -> String {
+        #[cfg(feature = "pretty")]
+        {
+            format_divergence_(expected, actual)
+        }
+
+        #[cfg(not(feature = "pretty"))]
+        {
+            let _ = (expected, actual);
+
+            String::new()
+        }
+    }
+
+    /// Obtains the number of leading significant (decimal) digits that the
+    /// given values share, as determined from their shortest round-trip
+    /// scientific representations, or `None` if either is not finite.
+    ///
+    /// NOTE: values of different sign, or of different decimal exponent,
+    /// share no digits, so that, e.g., `1.0` and `0.99999` share none.
+    pub fn significant_digits_in_common(
+        expected : f64,
+        actual : f64,
+    ) -> Option<u32> {
+        if !expected.is_finite() || !actual.is_finite() {
+            return None;
+        }
+
+        if expected.is_sign_negative() != actual.is_sign_negative() {
+            return Some(0);
+        }
+
+        let split = |v : f64| {
+            let s = format!("{:e}", v.abs());
+            let (mantissa, exponent) = s.split_once('e').unwrap();
+
+            (mantissa.replace('.', ""), exponent.to_string())
+        };
+
+        let (expected_digits, expected_exponent) = split(expected);
+        let (actual_digits, actual_exponent) = split(actual);
+
+        if expected_exponent != actual_exponent || (0.0 == expected) != (0.0 == actual) {
+            return Some(0);
+        }
+
+        let length = expected_digits.len().max(actual_digits.len());
+        let digit = |s : &str, i : usize| s.as_bytes().get(i).copied().unwrap_or(b'0');
+
+        Some(
+            (0..length)
+                .take_while(|&i| digit(&expected_digits, i) == digit(&actual_digits, i))
+                .count() as u32,
+        )
+    }
+
+    /// Formats, for appending to an assertion failure message, the number
+    /// of leading significant digits that the given values share, as in
+    /// `" (agree to 5 significant digits)"`, or the empty string if either
+    /// is not finite.
+    pub fn format_significant_digits_in_common(
+        expected : f64,
+        actual : f64,
+    ) -> String {
+        match significant_digits_in_common(expected, actual) {
+            Some(1) => " (agree to 1 significant digit)".into(),
+            Some(n) => format!(" (agree to {n} significant digits)"),
+            None => String::new(),
+        }
+    }
+
+    /// Formats, for appending to an assertion failure message, the factor
+    /// at which a failed comparison would have passed, and its ratio to the
+    /// current factor, as in `" (would pass at margin 1.0003e-6, 1.0003x
+    /// current)"`, so that a near-miss may be recognised as such. The factor
+    /// is selected as for the metrics reported to the sink installed via
+    /// [`set_metrics_sink()`](crate::set_metrics_sink): the margin if it is
+    /// the only factor, or if both are given and either comparand is zero;
+    /// otherwise the multiplier. The passing value is obtained from the
+    /// evaluator's
+    /// [`passing_factor()`](ApproximateEqualityEvaluator::passing_factor).
+    ///
+    /// NOTE: yields the empty string if there is no factor, if the evaluator
+    /// does not provide a passing factor, or if no finite factor would make
+    /// the comparison pass, as when either comparand is NaN or infinite.
+    pub fn format_headroom(
+        evaluator : &dyn ApproximateEqualityEvaluator,
+        expected : f64,
+        actual : f64,
+        margin_factor : Option<f64>,
+        multiplier_factor : Option<f64>,
+    ) -> String {
+        let (name, current) = match (margin_factor, multiplier_factor) {
+            (Some(margin_factor), Some(_)) if 0.0 == expected || 0.0 == actual => ("margin", margin_factor),
+            (_, Some(multiplier_factor)) => ("multiplier", multiplier_factor),
+            (Some(margin_factor), None) => ("margin", margin_factor),
+            (None, None) => return String::new(),
+        };
+        let needed = match evaluator.passing_factor(expected, actual) {
+            Some(needed) => needed,
+            None => return String::new(),
+        };
+
+        if !needed.is_finite() {
+            String::new()
+        } else if current > 0.0 {
+            format!(" (would pass at {name} {needed:.4e}, {:.4}x current)", needed / current)
+        } else {
+            format!(" (would pass at {name} {needed:.4e})")
+        }
+    }
+
+    /// Evaluates already-converted comparands, reporting metrics, and
+    /// obtains the diagnostic that [`assert_scalar_eq_approx!()`] produces
+    /// on failure - in which the comparands are formatted from the given
+    /// `expected_param` and `actual_param` - or the empty string on success.
+    ///
+    /// This is the common implementation of
+    /// [`evaluate_and_describe_scalar()`](crate::evaluate_and_describe_scalar)
+    /// and of [`assert_scalar_eq_approx!()`], which must first convert
+    /// comparands of types that are not `TestableAsF64`.
+    ///
+    /// [`assert_scalar_eq_approx!()`]: crate::assert_scalar_eq_approx
+    pub fn evaluate_and_describe_f64(
+        expected_param : &dyn std::fmt::Debug,
+        actual_param : &dyn std::fmt::Debug,
+        expected : f64,
+        actual : f64,
+        evaluator : &dyn ApproximateEqualityEvaluator,
+    ) -> (
+        ComparisonResult, // comparison_result
+        String,           // diagnostic
+    ) {
+        let (comparison_result, margin_factor, multiplier_factor) =
+            super::evaluate_f64_eq_approx_(expected, actual, evaluator);
+
+        if super::result_passes(&comparison_result, true) {
+            (comparison_result, String::new())
+        } else {
+            let suffix = format_evaluation_suffix(
+                margin_factor,
+                multiplier_factor,
+                describe_evaluation(evaluator, expected, actual),
+            );
+
+            let expected_param = format_message_param(expected_param, expected);
+            let actual_param = format_message_param(actual_param, actual);
+            let agreement = format_significant_digits_in_common(expected, actual);
+            let headroom = format_headroom(evaluator, expected, actual, margin_factor, multiplier_factor);
+            let divergence = format_divergence(expected, actual);
+
+            (
+                comparison_result,
+                format!("assertion failed: failed to verify approximate equality: expected={expected_param}, actual={actual_param}{suffix}{agreement}{headroom}{divergence}"),
+            )
+        }
+    }
+
+    /// Evaluates, by Horner's method, the polynomial with the given
+    /// `coefficients`, in ascending order of power - i.e. `[c0, c1, c2]`
+    /// denotes `c0 + c1*x + c2*x^2` - at `x`, yielding 0.0 if there are no
+    /// coefficients.
+    pub fn evaluate_polynomial(
+        coefficients : &[f64],
+        x : f64,
+    ) -> f64 {
+        coefficients.iter().rev().fold(0.0, |acc, &c| acc * x + c)
+    }
+
+    #[cfg(feature = "pretty")]
+    fn format_divergence_(
+        expected : f64,
+        actual : f64,
+    ) -> String {
+        let expected = format!("{expected:?}");
+        let actual = format!("{actual:?}");
+
+        let integral_width = |s : &str| s.find(['.', 'e']).unwrap_or(s.len());
+        let width = integral_width(&expected).max(integral_width(&actual));
+
+        let expected = format!("{}{expected}", " ".repeat(width - integral_width(&expected)));
+        let actual = format!("{}{actual}", " ".repeat(width - integral_width(&actual)));
+
+        let position = expected
+            .chars()
+            .zip(actual.chars())
+            .position(|(e, a)| e != a)
+            .unwrap_or_else(|| expected.chars().count().min(actual.chars().count()));
+
+        format!(
+            "\n  expected: {expected}\n  actual:   {actual}\n            {}^",
+            " ".repeat(position)
+        )
+    }
+
+    /// Obtains the elements of a vector comparand as a slice, which, when
+    /// invoked with method-call syntax, auto-derefs through smart pointers,
+    /// so that the vector assertion macros accept comparands, such as
+    /// `Arc<Vec<f64>>`, that implement `AsRef<[T]>` only via `Deref`.
+    pub trait AsElementSlice<T> {
+        fn as_element_slice(&self) -> &[T];
+    }
+
+    impl<T, C> AsElementSlice<T> for C
+    where
+        C : std::convert::AsRef<[T]> + ?Sized,
+    {
+        fn as_element_slice(&self) -> &[T] {
+            self.as_ref()
+        }
+    }
+
+    /// Probe by which the two-parameter forms of the assertion macros
+    /// select, at compile time, the default tolerance of the type `T` if it
+    /// implements [`DefaultTolerance`], or else the default evaluator, via
+    /// auto-ref method resolution: [`DefaultToleranceViaType`] is
+    /// implemented for `DefaultToleranceProbe<T>`, and so its `&self`
+    /// method is preferred when applicable over that of
+    /// [`DefaultToleranceViaFallback`], which is implemented for
+    /// `&DefaultToleranceProbe<T>` and so requires a further auto-ref.
+    ///
+    /// NOTE: the method must be invoked, with both traits in scope, as
+    /// `(&probe).default_tolerance_evaluator()`, where the type `T` is
+    /// known.
+    pub struct DefaultToleranceProbe<T : ?Sized>(std::marker::PhantomData<T>);
+
+    impl<T : ?Sized> DefaultToleranceProbe<T> {
+        /// Creates a probe for the type of the given comparand.
+        pub fn of(_comparand : &T) -> Self {
+            Self(std::marker::PhantomData)
+        }
+    }
+
+    impl<T> DefaultToleranceProbe<T> {
+        /// Creates a probe for the element type of the given vector
+        /// comparand.
+        pub fn of_elements<C>(_comparand : &C) -> Self
+        where
+            C : std::convert::AsRef<[T]> + ?Sized,
+        {
+            Self(std::marker::PhantomData)
+        }
+    }
+
+    pub trait DefaultToleranceViaType {
+        fn default_tolerance_evaluator(&self) -> Box<dyn ApproximateEqualityEvaluator>;
+    }
+
+    impl<T> DefaultToleranceViaType for DefaultToleranceProbe<T>
+    where
+        T : DefaultTolerance + ?Sized,
+    {
+        fn default_tolerance_evaluator(&self) -> Box<dyn ApproximateEqualityEvaluator> {
+            T::default_tolerance()
+        }
+    }
+
+    pub trait DefaultToleranceViaFallback {
+        fn default_tolerance_evaluator(&self) -> Box<dyn ApproximateEqualityEvaluator>;
+    }
+
+    impl<T : ?Sized> DefaultToleranceViaFallback for &DefaultToleranceProbe<T> {
+        fn default_tolerance_evaluator(&self) -> Box<dyn ApproximateEqualityEvaluator> {
+            crate::default_evaluator().boxed()
+        }
+    }
+
+    /// Probe that is used by the two-parameter forms of the scalar
+    /// assertion macros to obtain the tolerance carried by the expected
+    /// comparand, if its type implements [`CarriesTolerance`], in the same
+    /// auto-ref manner as [`DefaultToleranceProbe`]: [`ToleranceViaValue`]
+    /// is implemented for `ToleranceProbe<T>`, and [`ToleranceViaNone`] for
+    /// `&ToleranceProbe<T>`.
+    ///
+    /// NOTE: the method must be invoked, with both traits in scope, as
+    /// `(&probe).carried_tolerance()`.
+    pub struct ToleranceProbe<'a, T : ?Sized>(&'a T);
+
+    impl<'a, T : ?Sized> ToleranceProbe<'a, T> {
+        /// Creates a probe for the given comparand.
+        pub fn of(comparand : &'a T) -> Self {
+            Self(comparand)
+        }
+    }
+
+    pub trait ToleranceViaValue {
+        fn carried_tolerance(&self) -> Option<Box<dyn ApproximateEqualityEvaluator>>;
+    }
+
+    impl<T> ToleranceViaValue for ToleranceProbe<'_, T>
+    where
+        T : CarriesTolerance + ?Sized,
+    {
+        fn carried_tolerance(&self) -> Option<Box<dyn ApproximateEqualityEvaluator>> {
+            Some(self.0.tolerance())
+        }
+    }
+
+    pub trait ToleranceViaNone {
+        fn carried_tolerance(&self) -> Option<Box<dyn ApproximateEqualityEvaluator>>;
+    }
+
+    impl<T : ?Sized> ToleranceViaNone for &ToleranceProbe<'_, T> {
+        fn carried_tolerance(&self) -> Option<Box<dyn ApproximateEqualityEvaluator>> {
+            None
+        }
+    }
+
+    /// Probe by which the scalar assertion macros convert a comparand of
+    /// type `T` to `f64`, selecting, at compile time and in order of
+    /// preference, the first applicable of:
+    /// 0. for an expected comparand only (i.e. one probed via
+    ///    [`TestableProbe::of_expected()`]), [`CarriesTolerance`], via
+    ///    [`TestableViaCarriedTolerance`];
+    /// 1. [`TryTestableAsF64`](crate::traits::TryTestableAsF64), via [`TestableViaTry`];
+    /// 2. [`ExtendedTestableAsF64`](crate::traits::ExtendedTestableAsF64), via [`TestableViaExtended`];
+    /// 3. [`IntoTestableAsF64`](crate::traits::IntoTestableAsF64), via [`TestableViaInto`];
+    /// 4. `TryTestableAsF64` of the target of `T`, via [`TestableViaDeref`];
+    /// 5. `TryTestableAsF64` of the target of the target of `T`, via
+    ///    [`TestableViaDerefDeref`].
+    ///
+    /// Each is implemented for a reference one level shallower than the
+    /// last, and calls the comparand's trait method by full path, so that
+    /// the selection is made only by auto-deref method resolution of the
+    /// probe, whatever other traits are in scope.
+    ///
+    /// NOTE: the method must be invoked, with all six traits in scope, as
+    /// `(&&&&&&probe).probe_as_f64()`, which
+    /// [`try_testable_as_f64_!`](crate::try_testable_as_f64_) does.
+    pub struct TestableProbe<'a, T : ?Sized, P = AnyComparand>(&'a T, std::marker::PhantomData<P>);
+
+    /// Marks a [`TestableProbe`] of a comparand in any position.
+    pub struct AnyComparand;
+
+    /// Marks a [`TestableProbe`] of an expected comparand.
+    pub struct ExpectedComparand;
+
+    impl<'a, T : ?Sized> TestableProbe<'a, T> {
+        /// Creates a probe for the given comparand.
+        pub fn of(comparand : &'a T) -> Self {
+            Self(comparand, std::marker::PhantomData)
+        }
+    }
+
+    impl<'a, T : ?Sized> TestableProbe<'a, T, ExpectedComparand> {
+        /// Creates a probe for the given expected comparand.
+        pub fn of_expected(comparand : &'a T) -> Self {
+            Self(comparand, std::marker::PhantomData)
+        }
+    }
+
+    pub trait TestableViaCarriedTolerance {
+        fn probe_as_f64(&self) -> Result<f64, String>;
+    }
+
+    impl<T> TestableViaCarriedTolerance for &&&&&TestableProbe<'_, T, ExpectedComparand>
+    where
+        T : CarriesTolerance + ?Sized,
+    {
+        fn probe_as_f64(&self) -> Result<f64, String> {
+            Ok(<T as CarriesTolerance>::carried_value(self.0))
+        }
+    }
+
+    pub trait TestableViaTry {
+        fn probe_as_f64(&self) -> Result<f64, String>;
+    }
+
+    impl<T, P> TestableViaTry for &&&&TestableProbe<'_, T, P>
+    where
+        T : TryTestableAsF64 + ?Sized,
+    {
+        fn probe_as_f64(&self) -> Result<f64, String> {
+            <T as TryTestableAsF64>::try_testable_as_f64(self.0)
+        }
+    }
+
+    pub trait TestableViaExtended {
+        fn probe_as_f64(&self) -> Result<f64, String>;
+    }
+
+    impl<T, P> TestableViaExtended for &&&TestableProbe<'_, T, P>
+    where
+        T : ExtendedTestableAsF64 + ?Sized,
+    {
+        fn probe_as_f64(&self) -> Result<f64, String> {
+            <T as ExtendedTestableAsF64>::extended_testable_as_f64(self.0)
+        }
+    }
+
+    pub trait TestableViaInto {
+        fn probe_as_f64(&self) -> Result<f64, String>;
+    }
+
+    impl<T, P> TestableViaInto for &&TestableProbe<'_, T, P>
+    where
+        T : IntoTestableAsF64,
+    {
+        fn probe_as_f64(&self) -> Result<f64, String> {
+            <T as IntoTestableAsF64>::converted_testable_as_f64(self.0)
+        }
+    }
+
+    pub trait TestableViaDeref {
+        fn probe_as_f64(&self) -> Result<f64, String>;
+    }
+
+    impl<T, P> TestableViaDeref for &TestableProbe<'_, T, P>
+    where
+        T : std::ops::Deref + ?Sized,
+        T::Target : TryTestableAsF64,
+    {
+        fn probe_as_f64(&self) -> Result<f64, String> {
+            <T::Target as TryTestableAsF64>::try_testable_as_f64(&**self.0)
+        }
+    }
+
+    pub trait TestableViaDerefDeref {
+        fn probe_as_f64(&self) -> Result<f64, String>;
+    }
+
+    impl<T, P> TestableViaDerefDeref for TestableProbe<'_, T, P>
+    where
+        T : std::ops::Deref + ?Sized,
+        T::Target : std::ops::Deref,
+        <T::Target as std::ops::Deref>::Target : TryTestableAsF64,
+    {
+        fn probe_as_f64(&self) -> Result<f64, String> {
+            <<T::Target as std::ops::Deref>::Target as TryTestableAsF64>::try_testable_as_f64(&***self.0)
+        }
+    }
+
+    /// Obtains the overrides of the default `(margin_factor,
+    /// multiplier_factor)` via `lookup`, which is given the name of the
+    /// environment variable. Values that are absent, unparsable, negative,
+    /// or non-finite are ignored, and so yield `None`.
+    pub(crate) fn default_factor_overrides_from_<F>(lookup : F) -> (Option<f64>, Option<f64>)
+    where
+        F : Fn(&str) -> Option<String>,
+    {
+        let parse = |name : &str| -> Option<f64> {
+            lookup(name)
+                .and_then(|s| s.trim().parse::<f64>().ok())
+                .filter(|v| v.is_finite() && *v >= 0.0)
+        };
+
+        (
+            parse(constants::DEFAULT_MARGIN_ENVIRONMENT_VARIABLE),
+            parse(constants::DEFAULT_MULTIPLIER_ENVIRONMENT_VARIABLE),
+        )
+    }
+
+    /// Obtains the given `(margin_factor, multiplier_factor)` defaults with
+    /// any overrides applied, consulting the environment only once per
+    /// process.
+    pub(crate) fn default_factors(defaults : (f64, f64)) -> (f64, f64) {
+        static OVERRIDES : std::sync::OnceLock<(Option<f64>, Option<f64>)> = std::sync::OnceLock::new();
+
+        let (margin_factor, multiplier_factor) =
+            *OVERRIDES.get_or_init(|| default_factor_overrides_from_(|name| std::env::var(name).ok()));
+
+        (
+            margin_factor.unwrap_or(defaults.0),
+            multiplier_factor.unwrap_or(defaults.1),
+        )
+    }
+
+    fn result_from_range_(
+        lo : f64,
+        hi : f64,
+        actual : f64,
+    ) -> ComparisonResult {
+        let r = if lo <= hi { lo..=hi } else { hi..=lo };
+
+        if r.contains(&actual) {
+            ComparisonResult::ApproximatelyEqual
+        } else {
+            ComparisonResult::Unequal
+        }
+    }
+
+
+    #[cfg(test)]
+    #[rustfmt::skip]
+    mod tests {
+        #![allow(non_snake_case)]
+
+
+        use super::{
+            allowed_band_,
+            compare_approximate_equality_by_asymmetric_margin,
+            compare_approximate_equality_by_margin,
+            compare_approximate_equality_by_multiplier,
+            compare_approximate_equality_by_relative_geomean,
+            compare_approximate_equality_by_zero_margin_or_multiplier,
+            default_factor_overrides_from_,
+            evaluate_polynomial,
+            format_divergence,
+            format_evaluation_suffix,
+            format_headroom,
+            format_significant_digits_in_common,
+            significant_digits_in_common,
+        };
+
+        use super::super::{
+            constants::*,
+            ComparisonResult,
+        };
+
+
+        #[test]
+        fn TEST_compare_approximate_equality_by_margin_1() {
+
+            // expected == actual == 0.0
+            {
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.0));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.0000001));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.000001));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.00001));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.0001));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.001));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.01));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.1));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.5));
+            }
+
+            // expected == 0.0, actual == 0.1, f == *
+            {
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.0, 0.1, 0.0));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.0, 0.1, 0.0000001));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.0, 0.1, 0.000001));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.0, 0.1, 0.00001));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.0, 0.1, 0.0001));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.0, 0.1, 0.001));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.0, 0.1, 0.01));
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_margin(0.0, 0.1, 0.1));
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_margin(0.0, 0.1, 0.5));
+            }
+
+            // expected == 0.099, actual == 0.1, f == *
+            {
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.099, 0.1, 0.0));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.099, 0.1, 0.0000001));        // expected [ 0.0989999-0.0990001 ]
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.099, 0.1, 0.000001));         // expected [  0.098999-0.099001  ]
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.099, 0.1, 0.00001));          // expected [   0.09899-0.09901   ]
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.099, 0.1, 0.0001));           // expected [    0.0989-0.0991    ]
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_margin(0.099, 0.1, 0.001)); // expected [     0.098-0.1       ]
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_margin(0.099, 0.1, 0.01));  // expected [     0.089-0.109     ]
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_margin(0.099, 0.1, 0.02));  // expected [     0.089-0.119     ]
+            }
+        }
+
+        #[test]
+        fn TEST_compare_approximate_equality_by_asymmetric_margin_1() {
+
+            // expected == actual
+            {
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_asymmetric_margin(0.0, 0.0, 0.0, 0.0));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_asymmetric_margin(10.0, 10.0, 0.1, 0.5));
+            }
+
+            // expected == 10.0, lower == 0.1, upper == 0.5
+            {
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_asymmetric_margin(10.0, 9.8, 0.1, 0.5));
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_asymmetric_margin(10.0, 9.9, 0.1, 0.5));
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_asymmetric_margin(10.0, 10.4, 0.1, 0.5));
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_asymmetric_margin(10.0, 10.5, 0.1, 0.5));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_asymmetric_margin(10.0, 10.6, 0.1, 0.5));
+            }
+
+            // only one side
+            {
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_asymmetric_margin(10.0, 9.99, 0.0, 0.5));
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_asymmetric_margin(10.0, 10.01, 0.0, 0.5));
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_asymmetric_margin(10.0, 9.99, 0.5, 0.0));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_asymmetric_margin(10.0, 10.01, 0.5, 0.0));
+            }
+        }
+
+        #[test]
+        fn TEST_compare_approximate_equality_by_multiplier_1() {
+
+            // expected == actual == 0.0
+            {
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.0));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.0000001));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.000001));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.00001));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.0001));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.001));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.01));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.1));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.5));
+            }
+
+            // expected == 0.0, actual == 0.1, f == *
+            {
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.0));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.0000001));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.000001));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.00001));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.0001));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.001));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.01));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.1));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.5));
+            }
+
+            // expected == 0.099, actual == 0.1, f == *
+            {
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.0));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.0000001)); // expected [ 0.0989999901-0.0990000099 ]
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.000001));   // expected [  0.098999901-0.099000099  ]
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.00001));     // expected [   0.09899901-0.09900099   ]
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.0001));       // expected [    0.0989901-0.0990099    ]
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.001));         // expected [     0.098901-0.099099     ]
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.01));           // expected [      0.09801-0.09999      ]
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.1));  // expected [       0.0891-0.1089       ]
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.5));  // expected [       0.0495-0.1485       ]
+            }
+        }
+
+        #[test]
+        fn TEST_compare_approximate_equality_by_relative_geomean_1() {
+
+            // expected == actual
+            {
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_relative_geomean(0.0, 0.0, 0.0, None));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_relative_geomean(2.0, 2.0, 0.1, None));
+            }
+
+            // away from zero: band is f * sqrt(|e*a|)
+            {
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_relative_geomean(1.0, 4.0, 0.0, None));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_relative_geomean(1.0, 4.0, 1.49, None));     // band 2.98
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_relative_geomean(1.0, 4.0, 1.5, None)); // band 3.0
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_relative_geomean(4.0, 1.0, 1.5, None)); // symmetric
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_relative_geomean(-1.0, -4.0, 1.5, None));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_relative_geomean(-1.0, 1.0, 1.99, None));   // band 1.99
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_relative_geomean(-1.0, 1.0, 2.0, None));
+            }
+
+            // near zero
+            {
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_relative_geomean(0.0, 0.001, 0.5, None));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_relative_geomean(0.0, 0.001, 0.5, Some(0.0001)));
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_relative_geomean(0.0, 0.001, 0.5, Some(0.001)));
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_relative_geomean(0.001, 0.0, 0.5, Some(0.001)));
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_relative_geomean(0.001, 0.0011, 0.1, Some(0.0)));
+            }
+        }
+
+        #[test]
+        fn TEST_compare_approximate_equality_by_zero_margin_or_multiplier_1() {
+
+            // expected == actual == 0.0
+            {
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.0, 0.0));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.0000001, 0.0000001));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.000001, 0.000001));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.00001, 0.00001));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.0001, 0.0001));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.001, 0.001));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.01, 0.01));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.1, 0.1));
+                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.5, 0.5));
+            }
+
+            // expected == 0.0, actual == 0.1, f == *
+            {
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.0, 0.0));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.0000001, 0.0000001));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.000001, 0.000001));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.00001, 0.00001));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.0001, 0.0001));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.001, 0.001));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.01, 0.01));
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.1, 0.1));
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.5, 0.5));
+            }
+
+            // expected == 0.099, actual == 0.1, f == *
+            {
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.0, 0.0));
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.0000001, 0.0000001)); // expected [ 0.0989999901-0.0990000099 ]
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.000001, 0.000001));     // expected [  0.098999901-0.099000099  ]
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.00001, 0.00001));         // expected [   0.09899901-0.09900099   ]
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.0001, 0.0001));             // expected [    0.0989901-0.0990099    ]
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.001, 0.001));                 // expected [     0.098901-0.099099     ]
+                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.01, 0.01));                     // expected [      0.09801-0.09999      ]
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.1, 0.1));              // expected [       0.0891-0.1089       ]
+                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.5, 0.5));              // expected [       0.0495-0.1485       ]
+            }
+        }
+
+        #[test]
+        fn TEST_format_evaluation_suffix_1() {
+            assert_eq!("", format_evaluation_suffix(None, None, None));
+            assert_eq!(", margin_factor=0.1", format_evaluation_suffix(Some(0.1), None, None));
+            assert_eq!(", multiplier_factor=0.2", format_evaluation_suffix(None, Some(0.2), None));
+            assert_eq!(", margin_factor=0.1, multiplier_factor=0.2", format_evaluation_suffix(Some(0.1), Some(0.2), None));
+            assert_eq!(", margin_factor=0.1: abc", format_evaluation_suffix(Some(0.1), None, Some("abc".into())));
+            assert_eq!(": abc", format_evaluation_suffix(None, None, Some("abc".into())));
+        }
+
+        #[test]
+        fn TEST_default_factor_overrides_from__1() {
+
+            // no overrides
+            {
+                assert_eq!((None, None), default_factor_overrides_from_(|_| None));
+            }
+
+            // valid overrides
+            {
+                assert_eq!((Some(0.01), None), default_factor_overrides_from_(|name| (DEFAULT_MARGIN_ENVIRONMENT_VARIABLE == name).then(|| "0.01".into())));
+                assert_eq!((None, Some(0.5)), default_factor_overrides_from_(|name| (DEFAULT_MULTIPLIER_ENVIRONMENT_VARIABLE == name).then(|| " 0.5 ".into())));
+                assert_eq!((Some(0.0), Some(0.0)), default_factor_overrides_from_(|_| Some("0".into())));
+            }
+
+            // invalid overrides
+            {
+                assert_eq!((None, None), default_factor_overrides_from_(|_| Some("".into())));
+                assert_eq!((None, None), default_factor_overrides_from_(|_| Some("abc".into())));
+                assert_eq!((None, None), default_factor_overrides_from_(|_| Some("-0.1".into())));
+                assert_eq!((None, None), default_factor_overrides_from_(|_| Some("inf".into())));
+                assert_eq!((None, None), default_factor_overrides_from_(|_| Some("NaN".into())));
+            }
+        }
+
+        #[test]
+        fn TEST_evaluate_polynomial() {
+            assert_eq!(0.0, evaluate_polynomial(&[], 3.0));
+            assert_eq!(5.0, evaluate_polynomial(&[5.0], 3.0));
+            assert_eq!(22.0, evaluate_polynomial(&[1.0, -2.0, 3.0], 3.0));
+            assert_eq!(-7.0, evaluate_polynomial(&[1.0, 0.0, 0.0, 1.0], -2.0));
+        }
+
+        #[cfg(not(feature = "pretty"))]
+        #[test]
+        fn TEST_format_divergence_PLAIN() {
+            assert_eq!("", format_divergence(1.2345, 1.2355));
+        }
+
+        #[cfg(feature = "pretty")]
+        #[test]
+        fn TEST_format_divergence_PRETTY() {
+            assert_eq!("\n  expected: 1.2345\n  actual:   1.2355\n                ^", format_divergence(1.2345, 1.2355));
+            assert_eq!("\n  expected:  1.5\n  actual:   10.5\n            ^", format_divergence(1.5, 10.5));
+            assert_eq!("\n  expected: -2.0\n  actual:    2.0\n            ^", format_divergence(-2.0, 2.0));
+            assert_eq!("\n  expected: 1.0\n  actual:   1.05\n               ^", format_divergence(1.0, 1.05));
+        }
+
+        #[test]
+        fn TEST_significant_digits_in_common() {
+            assert_eq!(Some(7), significant_digits_in_common(0.12345678, 0.12345679));
+            assert_eq!(Some(5), significant_digits_in_common(1.23456, 1.23457));
+            assert_eq!(Some(6), significant_digits_in_common(1.0, 1.000002));
+            assert_eq!(Some(6), significant_digits_in_common(1.000002, 1.0));
+            assert_eq!(Some(1), significant_digits_in_common(-1500.0, -1600.0));
+            assert_eq!(Some(0), significant_digits_in_common(1.0, 0.99999));
+            assert_eq!(Some(0), significant_digits_in_common(1.0, -1.0));
+            assert_eq!(Some(0), significant_digits_in_common(0.0, 1.0));
+            assert_eq!(None, significant_digits_in_common(f64::NAN, 1.0));
+            assert_eq!(None, significant_digits_in_common(1.0, f64::INFINITY));
+        }
+
+        #[test]
+        fn TEST_format_significant_digits_in_common() {
+            assert_eq!(" (agree to 5 significant digits)", format_significant_digits_in_common(1.23456, 1.23457));
+            assert_eq!(" (agree to 1 significant digit)", format_significant_digits_in_common(1500.0, 1600.0));
+            assert_eq!("", format_significant_digits_in_common(f64::NAN, 1.0));
+        }
+
+        #[test]
+        fn TEST_format_headroom() {
+            // near-misses
+            assert_eq!(" (would pass at margin 1.0003e-6, 1.0003x current)", format_headroom(&crate::margin(1e-6), 1.0, 1.0 + 1.0003e-6, Some(1e-6), None));
+            assert_eq!(" (would pass at multiplier 1.0003e-6, 1.0003x current)", format_headroom(&crate::multiplier(1e-6), 1000.0, 1000.0 + 1.0003e-3, None, Some(1e-6)));
+
+            // both factors: margin applies at zero, otherwise multiplier
+            let e = crate::zero_margin_or_multiplier(1e-6, 1e-4);
+
+            assert_eq!(" (would pass at margin 2.0000e-4, 2.0000x current)", format_headroom(&e, 0.0, 2e-4, Some(1e-4), Some(1e-6)));
+            assert_eq!(" (would pass at multiplier 2.0000e-6, 2.0000x current)", format_headroom(&e, 1.0, 1.000002, Some(1e-4), Some(1e-6)));
+
+            // relative to the geometric mean
+            assert_eq!(" (would pass at multiplier 1.5000e0, 3.0000x current)", format_headroom(&crate::relative_geomean(0.5), 1.0, 4.0, None, Some(0.5)));
+
+            // no current tolerance
+            assert_eq!(" (would pass at margin 1.0000e-1)", format_headroom(&crate::margin(0.0), 1.0, 1.1, Some(0.0), None));
+
+            // no passing factor provided by the evaluator
+            assert_eq!("", format_headroom(&crate::asymmetric_margin(0.1, 0.001), 1.0, 1.01, Some(0.1), None));
+
+            assert_eq!("", format_headroom(&crate::ulps(4), 1.0, 2.0, None, None));
+            assert_eq!("", format_headroom(&crate::margin(0.1), 1.0, f64::NAN, Some(0.1), None));
+            assert_eq!("", format_headroom(&crate::multiplier(0.1), 0.0, 1.0, None, Some(0.1)));
+        }
+
+        #[test]
+        fn TEST_allowed_band__1() {
+            assert_eq!(None, allowed_band_(1.0, 2.0, None, None));
+            assert_eq!(Some(0.1), allowed_band_(1.0, 2.0, Some(0.1), None));
+            assert_eq!(Some(0.2), allowed_band_(-2.0, 2.0, None, Some(0.1)));
+            assert_eq!(Some(0.1), allowed_band_(0.0, 2.0, Some(0.1), Some(0.5)));
+            assert_eq!(Some(0.5), allowed_band_(1.0, 2.0, Some(0.1), Some(0.5)));
+            assert_eq!(None, allowed_band_(0.0, 2.0, None, Some(0.5)));
+            assert_eq!(None, allowed_band_(1.0, 2.0, Some(0.0), None));
+            assert_eq!(None, allowed_band_(f64::INFINITY, 2.0, None, Some(0.5)));
+        }
+    }
+}
+
+
+// /////////////////////////////////////////////////////////
+// API functions
+
+pub fn evaluate_scalar_eq_approx<T_expected, T_actual>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    ComparisonResult, // comparison_result
+    Option<f64>,      // margin_factor
+    Option<f64>,      // multiplier_factor
+)
+where
+    T_expected : traits::TestableAsF64 + std_fmt::Debug,
+    T_actual : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let (expected, actual) = {
+        let expected : &dyn traits::TestableAsF64 = expected;
+        let actual : &dyn traits::TestableAsF64 = actual;
+
+        let expected = expected.testable_as_f64();
+        let actual = actual.testable_as_f64();
+
+        (expected, actual)
+    };
+
+    evaluate_f64_eq_approx_(expected, actual, evaluator)
+}
+
+/// Evaluates the given comparands as does [`evaluate_scalar_eq_approx()`],
+/// and obtains, along with the result, the diagnostic that
+/// [`assert_scalar_eq_approx!`] would produce on failure, or the empty
+/// string on success. This allows custom test frameworks to decide whether
+/// to panic, log, or collect failures.
+pub fn evaluate_and_describe_scalar<T_expected, T_actual>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    ComparisonResult, // comparison_result
+    String,           // diagnostic
+)
+where
+    T_expected : traits::TestableAsF64 + std_fmt::Debug,
+    T_actual : traits::TestableAsF64 + std_fmt::Debug,
+{
+    utils::evaluate_and_describe_f64(
+        expected,
+        actual,
+        expected.testable_as_f64(),
+        actual.testable_as_f64(),
+        evaluator,
+    )
+}
+
+/// Evaluates already-converted comparands, reporting metrics, as the
+/// common implementation of [`evaluate_scalar_eq_approx()`] and of the
+/// element-wise loops that must retain the converted values.
+#[inline]
+fn evaluate_f64_eq_approx_<E>(
+    expected : f64,
+    actual : f64,
+    evaluator : &E,
+) -> (
+    ComparisonResult, // comparison_result
+    Option<f64>,      // margin_factor
+    Option<f64>,      // multiplier_factor
+)
+where
+    E : traits::ApproximateEqualityEvaluator + ?Sized,
+{
+    let (comparison_result, margin_factor, multiplier_factor) = evaluator.evaluate(expected, actual);
+
+    utils::report_comparison_metrics(expected, actual, margin_factor, multiplier_factor);
+
+    (comparison_result, margin_factor, multiplier_factor)
+}
+
+/// Folds the given comparison results into their worst case, as per
+/// [`ComparisonResult::combine()`], yielding `ComparisonResult::ExactlyEqual`
+/// if there are none.
+pub fn fold_comparison_results<I>(results : I) -> ComparisonResult
+where
+    I : IntoIterator<Item = ComparisonResult>,
+{
+    results
+        .into_iter()
+        .fold(ComparisonResult::ExactlyEqual, ComparisonResult::combine)
+}
+
+/// Suggests, as a tuning aid, the smallest margin factor that would have
+/// made all of the given `(expected, actual)` pairs pass, i.e. one that
+/// [`margin()`] would accept for every pair, being (subject to rounding)
+/// the maximum absolute difference between them.
+///
+/// NOTE: yields `0.0` if there are no pairs (or all are exactly equal), and
+/// `f64::INFINITY` if any pair cannot be accepted by a finite margin, such
+/// as one involving a NaN or an infinity.
+pub fn suggest_margin(pairs : &[(f64, f64)]) -> f64 {
+    pairs.iter().fold(0.0, |suggestion : f64, &(expected, actual)| {
+        if ComparisonResult::Unequal != utils::compare_approximate_equality_by_margin(expected, actual, suggestion) {
+            return suggestion;
+        }
+
+        let mut margin_factor = (actual - expected).abs();
+
+        if !margin_factor.is_finite() {
+            return f64::INFINITY;
+        }
+
+        // allow for rounding in the computation of the acceptance interval
+        while ComparisonResult::Unequal
+            == utils::compare_approximate_equality_by_margin(expected, actual, margin_factor)
+        {
+            margin_factor = margin_factor.next_up();
+        }
+
+        margin_factor
+    })
+}
+
+/// Suggests, as a tuning aid, the smallest multiplier factor that would
+/// have made all of the given `(expected, actual)` pairs pass, i.e. one
+/// that [`multiplier()`] would accept for every pair, being (subject to
+/// rounding) the maximum absolute difference between them relative to the
+/// magnitude of the expected value.
+///
+/// NOTE: yields `0.0` if there are no pairs (or all are exactly equal), and
+/// `f64::INFINITY` if any pair cannot be accepted by a finite multiplier,
+/// such as one whose expected value is zero (but whose actual value is
+/// not) or one involving a NaN or an infinity.
+pub fn suggest_multiplier(pairs : &[(f64, f64)]) -> f64 {
+    pairs.iter().fold(0.0, |suggestion : f64, &(expected, actual)| {
+        if ComparisonResult::Unequal != utils::compare_approximate_equality_by_multiplier(expected, actual, suggestion)
+        {
+            return suggestion;
+        }
+
+        let mut multiplier_factor = (actual - expected).abs() / expected.abs();
+
+        if !multiplier_factor.is_finite() {
+            return f64::INFINITY;
+        }
+
+        // allow for rounding in the computation of the acceptance interval
+        while ComparisonResult::Unequal
+            == utils::compare_approximate_equality_by_multiplier(expected, actual, multiplier_factor)
+        {
+            multiplier_factor = multiplier_factor.next_up();
+        }
+
+        multiplier_factor
+    })
+}
+
+pub fn evaluate_vector_eq_approx<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    VectorComparisonResult, // comparison_result
+    Option<f64>,            // margin_factor
+    Option<f64>,            // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    evaluate_vector_eq_approx_(expected.as_ref(), actual.as_ref(), evaluator, None)
+}
+
+/// Equivalent to [`evaluate_vector_eq_approx()`], except that the elements
+/// are required to implement `Into<f64>` (and `Copy`), rather than
+/// `TestableAsF64`, so that vectors of third-party numeric types that do
+/// not implement `base_traits::ToF64` may be compared.
+///
+/// NOTE: the elements are converted up front, and so the vectors are
+/// copied.
+pub fn evaluate_vector_eq_approx_into<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    VectorComparisonResult, // comparison_result
+    Option<f64>,            // margin_factor
+    Option<f64>,            // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : Into<f64> + Copy,
+    T_actualElement : Into<f64> + Copy,
+{
+    let expected : Vec<f64> = expected.as_ref().iter().map(|&v| v.into()).collect();
+    let actual : Vec<f64> = actual.as_ref().iter().map(|&v| v.into()).collect();
+
+    evaluate_vector_eq_approx_(&expected, &actual, evaluator, None)
+}
+
+/// Equivalent to [`evaluate_vector_eq_approx()`], except that the evaluator
+/// is taken as a generic parameter, rather than as a trait object, so that
+/// the comparison is monomorphized for the concrete evaluator type and its
+/// per-element `evaluate()` may be inlined. This suits very large vectors
+/// compared with the stock evaluators, such as those obtained from
+/// [`margin()`] and [`multiplier()`], whereupon the cost of the dynamic
+/// dispatch can dominate.
+pub fn evaluate_vector_eq_approx_with<T_expected, T_actual, T_expectedElement, T_actualElement, E>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &E,
+) -> (
+    VectorComparisonResult, // comparison_result
+    Option<f64>,            // margin_factor
+    Option<f64>,            // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+    E : traits::ApproximateEqualityEvaluator,
+{
+    evaluate_vector_eq_approx_(expected.as_ref(), actual.as_ref(), evaluator, None)
+}
+
+/// Equivalent to [`evaluate_vector_eq_approx()`], except that only the
+/// common prefix of the vectors - i.e. their first `min(expected.len(),
+/// actual.len())` elements - is compared, so that a difference in length
+/// is never reported. This suits comparisons of sequences that are
+/// intentionally of different lengths, such as a growing log.
+///
+/// NOTE: any trailing elements of the longer vector are ignored entirely.
+pub fn evaluate_vector_eq_approx_prefix<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    VectorComparisonResult, // comparison_result
+    Option<f64>,            // margin_factor
+    Option<f64>,            // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let expected = expected.as_ref();
+    let actual = actual.as_ref();
+
+    let prefix_length = expected.len().min(actual.len());
+
+    evaluate_vector_eq_approx_(&expected[..prefix_length], &actual[..prefix_length], evaluator, None)
+}
+
+/// Equivalent to [`evaluate_vector_eq_approx()`], except that the elements
+/// at the indices listed in `ignore` - such as known-nondeterministic
+/// timestamps embedded in a numeric buffer - are skipped, while the
+/// vectors are still required to be of equal length. Any mismatch is
+/// reported at its index in the original vectors.
+///
+/// NOTE: indices in `ignore` that are out of range are disregarded.
+pub fn evaluate_vector_eq_approx_ignoring<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+    ignore : &[usize],
+) -> (
+    VectorComparisonResult, // comparison_result
+    Option<f64>,            // margin_factor
+    Option<f64>,            // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let expected = expected.as_ref();
+    let actual = actual.as_ref();
+
+    if expected.len() != actual.len() {
+        return evaluate_vector_eq_approx_(expected, actual, evaluator, None);
+    }
+
+    let mut ignored = vec![false; expected.len()];
+
+    for &ix in ignore {
+        if let Some(ignored) = ignored.get_mut(ix) {
+            *ignored = true;
+        }
+    }
+
+    // ignored elements are neither converted nor evaluated, so that they
+    // are not reported to any metrics sink
+    let triples = expected
+        .iter()
+        .zip(actual)
+        .enumerate()
+        .filter(|&(ix, _)| !ignored[ix])
+        .map(|(ix, (expected_element, actual_element))| {
+            (ix, expected_element.testable_as_f64(), actual_element.testable_as_f64())
+        });
+
+    evaluate_f64_sequence_eq_approx_(triples, evaluator, None).finish_vector()
+}
+
+/// Equivalent to [`evaluate_vector_eq_approx()`], except that only the
+/// comparison result is returned, for cleaner call sites in programmatic
+/// (non-macro) usage that have no need of the factors.
+pub fn vector_comparison<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> VectorComparisonResult
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    evaluate_vector_eq_approx(expected, actual, evaluator).0
+}
+
+/// Equivalent to [`evaluate_vector_eq_approx()`], except that `progress` is
+/// invoked, with the number of elements processed thus far, after every
+/// `interval` elements have been compared. This may be used to provide
+/// feedback when comparing very large vectors.
+///
+/// NOTE: if `interval` is 0 then `progress` is never invoked.
+pub fn evaluate_vector_eq_approx_with_progress<T_expected, T_actual, T_expectedElement, T_actualElement, F>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+    interval : usize,
+    mut progress : F,
+) -> (
+    VectorComparisonResult, // comparison_result
+    Option<f64>,            // margin_factor
+    Option<f64>,            // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+    F : FnMut(usize),
+{
+    evaluate_vector_eq_approx_(
+        expected.as_ref(),
+        actual.as_ref(),
+        evaluator,
+        Some((interval, &mut progress)),
+    )
+}
+
+/// Accumulates the element-wise results of a sequence comparison, as the
+/// common implementation of the element-wise evaluations, retaining the
+/// first unequal element, identified by an index of type `X`, and the
+/// factors of that element or, failing that, of the first inexact one.
+#[derive(Debug)]
+struct SequenceAccumulator_<X> {
+    any_inexact :   bool,
+    first_unequal : Option<(X, f64, f64)>,
+    factors :       (Option<f64>, Option<f64>),
+}
+
+impl<X> SequenceAccumulator_<X> {
+    fn new() -> Self {
+        Self {
+            any_inexact :   false,
+            first_unequal : None,
+            factors :       (None, None),
+        }
+    }
+
+    /// Records an inexact comparison.
+    fn record_inexact(
+        &mut self,
+        margin_factor : Option<f64>,
+        multiplier_factor : Option<f64>,
+    ) {
+        if !self.any_inexact && self.first_unequal.is_none() {
+            self.factors = (margin_factor, multiplier_factor);
+        }
+
+        self.any_inexact = true;
+    }
+
+    /// Records an unequal comparison of the element at `index`.
+    fn record_unequal(
+        &mut self,
+        index : X,
+        expected_value : f64,
+        actual_value : f64,
+        margin_factor : Option<f64>,
+        multiplier_factor : Option<f64>,
+    ) {
+        if self.first_unequal.is_none() {
+            self.first_unequal = Some((index, expected_value, actual_value));
+            self.factors = (margin_factor, multiplier_factor);
+        }
+    }
+
+    /// Records the given evaluation of the element at `index`, returning
+    /// its comparison result.
+    fn record(
+        &mut self,
+        index : X,
+        expected_value : f64,
+        actual_value : f64,
+        (comparison_result, margin_factor, multiplier_factor) : (ComparisonResult, Option<f64>, Option<f64>),
+    ) -> ComparisonResult {
+        match comparison_result {
+            ComparisonResult::ExactlyEqual | ComparisonResult::BothNaN => (),
+            ComparisonResult::ApproximatelyEqual => self.record_inexact(margin_factor, multiplier_factor),
+            ComparisonResult::Unequal => {
+                self.record_unequal(index, expected_value, actual_value, margin_factor, multiplier_factor)
+            },
+        };
+
+        comparison_result
+    }
+
+    /// Obtains the overall result, one of `exactly_equal` and
+    /// `approximately_equal` or, if an unequal element was recorded, that
+    /// obtained from it by `unequal`, along with the factors.
+    fn finish<R, F>(
+        self,
+        exactly_equal : R,
+        approximately_equal : R,
+        unequal : F,
+    ) -> (
+        R,           // comparison_result
+        Option<f64>, // margin_factor
+        Option<f64>, // multiplier_factor
+    )
+    where
+        F : FnOnce(X, f64, f64) -> R,
+    {
+        let (margin_factor, multiplier_factor) = self.factors;
+
+        let comparison_result = match self.first_unequal {
+            Some((index, expected_value, actual_value)) => unequal(index, expected_value, actual_value),
+            None if self.any_inexact => approximately_equal,
+            None => exactly_equal,
+        };
+
+        (comparison_result, margin_factor, multiplier_factor)
+    }
+}
+
+impl SequenceAccumulator_<usize> {
+    fn finish_vector(
+        self
+    ) -> (
+        VectorComparisonResult, // comparison_result
+        Option<f64>,            // margin_factor
+        Option<f64>,            // multiplier_factor
+    ) {
+        self.finish(
+            VectorComparisonResult::ExactlyEqual,
+            VectorComparisonResult::ApproximatelyEqual,
+            |index, expected_value, actual_value| {
+                VectorComparisonResult::UnequalElements {
+                    index_of_first_unequal_element :          index,
+                    expected_value_of_first_unequal_element : expected_value,
+                    actual_value_of_first_unequal_element :   actual_value,
+                }
+            },
+        )
+    }
+}
+
+/// Evaluates, in turn, the given `(index, expected_value, actual_value)`
+/// triples, ceasing at the first that is unequal, and invoking `progress`,
+/// if given, after every `interval` triples.
+fn evaluate_f64_sequence_eq_approx_<X, I, E>(
+    triples : I,
+    evaluator : &E,
+    mut progress : Option<(usize, &mut dyn FnMut(usize))>,
+) -> SequenceAccumulator_<X>
+where
+    I : IntoIterator<Item = (X, f64, f64)>,
+    E : traits::ApproximateEqualityEvaluator + ?Sized,
+{
+    let mut accumulator = SequenceAccumulator_::new();
+
+    for (num_evaluated, (index, expected_value, actual_value)) in (1..).zip(triples) {
+        if ComparisonResult::Unequal
+            == accumulator.record(
+                index,
+                expected_value,
+                actual_value,
+                evaluate_f64_eq_approx_(expected_value, actual_value, evaluator),
+            )
+        {
+            break;
+        }
+
+        if let Some((interval, ref mut progress)) = progress {
+            if 0 != interval && 0 == num_evaluated % interval {
+                progress(num_evaluated);
+            }
+        }
+    }
+
+    accumulator
+}
+
+fn evaluate_vector_eq_approx_<T_expectedElement, T_actualElement, E>(
+    expected : &[T_expectedElement],
+    actual : &[T_actualElement],
+    evaluator : &E,
+    progress : Option<(usize, &mut dyn FnMut(usize))>,
+) -> (
+    VectorComparisonResult, // comparison_result
+    Option<f64>,            // margin_factor
+    Option<f64>,            // multiplier_factor
+)
+where
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+    E : traits::ApproximateEqualityEvaluator + ?Sized,
+{
+    let expected_length = expected.len();
+    let actual_length = actual.len();
+
+    if expected_length != actual_length {
+        (
+            VectorComparisonResult::DifferentLengths {
+                expected_length,
+                actual_length,
+            },
+            None,
+            None,
+        )
+    } else {
+        // each element is converted exactly once, and the converted values
+        // retained for reporting any mismatch
+        let triples = expected
+            .iter()
+            .zip(actual)
+            .enumerate()
+            .map(|(ix, (expected_element, actual_element))| {
+                (ix, expected_element.testable_as_f64(), actual_element.testable_as_f64())
+            });
+
+        evaluate_f64_sequence_eq_approx_(triples, evaluator, progress).finish_vector()
+    }
+}
+
+/// Evaluates the approximate equality of two byte buffers that encode
+/// sequences of `f64` values in the given byte order, decoding each
+/// 8-byte chunk and comparing the values in turn, and identifying any
+/// unequal value by its byte offset.
+pub fn evaluate_bytes_as_f64_eq_approx(
+    expected_bytes : &[u8],
+    actual_bytes : &[u8],
+    endianness : Endianness,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    BytesComparisonResult, // comparison_result
+    Option<f64>,           // margin_factor
+    Option<f64>,           // multiplier_factor
+) {
+    evaluate_bytes_as_floats_eq_approx_(expected_bytes, actual_bytes, evaluator, |chunk : [u8; 8]| {
+        match endianness {
+            Endianness::Little => f64::from_le_bytes(chunk),
+            Endianness::Big => f64::from_be_bytes(chunk),
+        }
+    })
+}
+
+/// Equivalent to [`evaluate_bytes_as_f64_eq_approx()`], except that the
+/// buffers encode `f32` values, and so are decoded in 4-byte chunks.
+pub fn evaluate_bytes_as_f32_eq_approx(
+    expected_bytes : &[u8],
+    actual_bytes : &[u8],
+    endianness : Endianness,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    BytesComparisonResult, // comparison_result
+    Option<f64>,           // margin_factor
+    Option<f64>,           // multiplier_factor
+) {
+    evaluate_bytes_as_floats_eq_approx_(expected_bytes, actual_bytes, evaluator, |chunk : [u8; 4]| {
+        match endianness {
+            Endianness::Little => f32::from_le_bytes(chunk),
+            Endianness::Big => f32::from_be_bytes(chunk),
+        }
+    })
+}
+
+fn evaluate_bytes_as_floats_eq_approx_<const N: usize, T, F>(
+    expected_bytes : &[u8],
+    actual_bytes : &[u8],
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+    decode : F,
+) -> (
+    BytesComparisonResult, // comparison_result
+    Option<f64>,           // margin_factor
+    Option<f64>,           // multiplier_factor
+)
+where
+    T : Into<f64>,
+    F : Fn([u8; N]) -> T,
+{
+    if expected_bytes.len() != actual_bytes.len() {
+        return (
+            BytesComparisonResult::DifferentByteLengths {
+                expected_byte_length : expected_bytes.len(),
+                actual_byte_length :   actual_bytes.len(),
+            },
+            None,
+            None,
+        );
+    }
+
+    if !expected_bytes.len().is_multiple_of(N) {
+        return (
+            BytesComparisonResult::IncompleteChunk {
+                byte_length : expected_bytes.len(),
+                chunk_size :  N,
+            },
+            None,
+            None,
+        );
+    }
+
+    let triples = expected_bytes
+        .chunks_exact(N)
+        .zip(actual_bytes.chunks_exact(N))
+        .enumerate()
+        .map(|(ix, (expected_chunk, actual_chunk))| {
+            let expected : f64 = decode(expected_chunk.try_into().unwrap()).into();
+            let actual : f64 = decode(actual_chunk.try_into().unwrap()).into();
+
+            (ix * N, expected, actual)
+        });
+
+    evaluate_f64_sequence_eq_approx_(triples, evaluator, None).finish(
+        BytesComparisonResult::ExactlyEqual,
+        BytesComparisonResult::ApproximatelyEqual,
+        |byte_offset, expected, actual| {
+            BytesComparisonResult::UnequalElements {
+                byte_offset_of_first_unequal_element :    byte_offset,
+                expected_value_of_first_unequal_element : expected,
+                actual_value_of_first_unequal_element :   actual,
+            }
+        },
+    )
+}
+
+/// Evaluates the approximate equality of two `rust_decimal::Decimal`
+/// values natively, i.e. without conversion to `f64`, deeming them
+/// approximately equal if they differ by no more than the (decimal)
+/// `margin`, thereby avoiding binary floating-point rounding entirely.
+///
+/// NOTE: requires the `"rust_decimal"` feature.
+#[cfg(feature = "rust_decimal")]
+pub fn evaluate_decimal_eq_approx(
+    expected : &rust_decimal::Decimal,
+    actual : &rust_decimal::Decimal,
+    margin : &rust_decimal::Decimal,
+) -> ComparisonResult {
+    debug_assert!(
+        !margin.is_sign_negative(),
+        "`margin` must not be negative, but {margin} given"
+    );
+
+    if expected == actual {
+        ComparisonResult::ExactlyEqual
+    } else {
+        match actual.checked_sub(*expected) {
+            Some(difference) if difference.abs() <= *margin => ComparisonResult::ApproximatelyEqual,
+            _ => ComparisonResult::Unequal,
+        }
+    }
+}
+
+/// Evaluates whether the elements of a vector are approximately constant,
+/// i.e. whether each is approximately equal, according to `evaluator`, to
+/// the reference value identified by `reference` - the first element, the
+/// mean of the elements, or a given target - identifying the first element
+/// that strays.
+pub fn evaluate_vector_constant_approx<T_values, T_element>(
+    values : &T_values,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+    reference : ConstantReference,
+) -> (
+    ConstantComparisonResult, // comparison_result
+    Option<f64>,              // margin_factor
+    Option<f64>,              // multiplier_factor
+)
+where
+    T_values : std_convert::AsRef<[T_element]>,
+    T_element : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let values = values.as_ref();
+
+    if values.is_empty() {
+        return (ConstantComparisonResult::ExactlyEqual, None, None);
+    }
+
+    let reference_value = match reference {
+        ConstantReference::First => values[0].testable_as_f64(),
+        ConstantReference::Mean => values.iter().map(|v| v.testable_as_f64()).sum::<f64>() / values.len() as f64,
+        ConstantReference::Target(target) => target,
+    };
+
+    let triples = values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| (index, reference_value, value.testable_as_f64()));
+
+    evaluate_f64_sequence_eq_approx_(triples, evaluator, None).finish(
+        ConstantComparisonResult::ExactlyEqual,
+        ConstantComparisonResult::ApproximatelyEqual,
+        |index, reference_value, value| {
+            ConstantComparisonResult::StrayElement {
+                index,
+                reference_value,
+                value,
+                deviation : value - reference_value,
+            }
+        },
+    )
+}
+
+/// Equivalent to [`evaluate_vector_eq_approx()`], except that it is the
+/// cumulative (prefix) sums of the vectors that are compared, element-wise,
+/// which is more sensitive to accumulation drift, as in running totals. The
+/// values of any unequal element are the cumulative totals at that index.
+pub fn evaluate_cumsum_eq_approx<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    VectorComparisonResult, // comparison_result
+    Option<f64>,            // margin_factor
+    Option<f64>,            // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    fn cumsum_<T : traits::TestableAsF64>(values : &[T]) -> Vec<f64> {
+        values
+            .iter()
+            .scan(0.0, |total, v| {
+                *total += v.testable_as_f64();
+
+                Some(*total)
+            })
+            .collect()
+    }
+
+    evaluate_vector_eq_approx_(&cumsum_(expected.as_ref()), &cumsum_(actual.as_ref()), evaluator, None)
+}
+
+/// Equivalent to [`evaluate_vector_eq_approx()`], except that each actual
+/// element is compared with the negation of the corresponding expected
+/// element, i.e. verifying that `actual ≈ -expected` element-wise, as for
+/// antisymmetric operators. The expected value of any unequal element is
+/// the negated expected value.
+pub fn evaluate_vector_eq_neg_approx<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    VectorComparisonResult, // comparison_result
+    Option<f64>,            // margin_factor
+    Option<f64>,            // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let negated_expected : Vec<f64> = expected.as_ref().iter().map(|v| -v.testable_as_f64()).collect();
+
+    evaluate_vector_eq_approx_(&negated_expected, actual.as_ref(), evaluator, None)
+}
+
+/// Equivalent to [`evaluate_vector_eq_approx()`], except that each element
+/// is named by the corresponding entry in `labels` - as when the vectors
+/// represent named state variables - and any unequal element is
+/// identified by its label as well as its index.
+///
+/// Yields [`LabeledVectorComparisonResult::DifferentLabelCount`] if the
+/// number of labels differs from the (common) length of the vectors.
+pub fn evaluate_vector_eq_approx_labeled<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    labels : &[&str],
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    LabeledVectorComparisonResult, // comparison_result
+    Option<f64>,                   // margin_factor
+    Option<f64>,                   // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let expected = expected.as_ref();
+    let actual = actual.as_ref();
+
+    if expected.len() == actual.len() && labels.len() != expected.len() {
+        return (
+            LabeledVectorComparisonResult::DifferentLabelCount {
+                length :      expected.len(),
+                label_count : labels.len(),
+            },
+            None,
+            None,
+        );
+    }
+
+    let (comparison_result, margin_factor, multiplier_factor) =
+        evaluate_vector_eq_approx_(expected, actual, evaluator, None);
+
+    let comparison_result = match comparison_result {
+        VectorComparisonResult::ExactlyEqual => LabeledVectorComparisonResult::ExactlyEqual,
+        VectorComparisonResult::ApproximatelyEqual => LabeledVectorComparisonResult::ApproximatelyEqual,
+        VectorComparisonResult::DifferentLengths {
+            expected_length,
+            actual_length,
+        } => {
+            LabeledVectorComparisonResult::DifferentLengths {
+                expected_length,
+                actual_length,
+            }
+        },
+        VectorComparisonResult::UnequalElements {
+            index_of_first_unequal_element,
+            expected_value_of_first_unequal_element,
+            actual_value_of_first_unequal_element,
+        } => {
+            LabeledVectorComparisonResult::UnequalElements {
+                label_of_first_unequal_element : labels[index_of_first_unequal_element].into(),
+                index_of_first_unequal_element,
+                expected_value_of_first_unequal_element,
+                actual_value_of_first_unequal_element,
+            }
+        },
+    };
+
+    (comparison_result, margin_factor, multiplier_factor)
+}
+
+/// Equivalent to [`evaluate_vector_eq_approx()`], except that `expected`
+/// is compared with `k * actual`, where the scale `k` is that which best
+/// fits - in the least-squares sense - the one to the other, i.e.
+/// `sum(expected[i] * actual[i]) / sum(actual[i]^2)`. This suits results
+/// that may legitimately differ by an unknown constant gain, such as
+/// unnormalised eigenvectors.
+///
+/// The estimated scale is returned alongside the result, and the values
+/// of any unequal element are those after scaling.
+///
+/// NOTE: if the lengths differ, or `actual` is entirely zero (so that no
+/// scale is determinable), the scale is taken to be `1.0`.
+pub fn evaluate_vector_eq_approx_up_to_scale<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    VectorComparisonResult, // comparison_result
+    f64,                    // scale
+    Option<f64>,            // margin_factor
+    Option<f64>,            // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let expected = expected
+        .as_ref()
+        .iter()
+        .map(|v| v.testable_as_f64())
+        .collect::<Vec<_>>();
+    let actual = actual.as_ref().iter().map(|v| v.testable_as_f64()).collect::<Vec<_>>();
+
+    let scale = if expected.len() == actual.len() {
+        let numerator = expected.iter().zip(actual.iter()).map(|(e, a)| e * a).sum::<f64>();
+        let denominator = actual.iter().map(|a| a * a).sum::<f64>();
+
+        if 0.0 == denominator {
+            1.0
+        } else {
+            numerator / denominator
+        }
+    } else {
+        1.0
+    };
+
+    let scaled_actual = actual.iter().map(|a| scale * a).collect::<Vec<_>>();
+
+    let (comparison_result, margin_factor, multiplier_factor) =
+        evaluate_vector_eq_approx_(&expected, &scaled_actual, evaluator, None);
+
+    (comparison_result, scale, margin_factor, multiplier_factor)
+}
+
+/// Equivalent to [`evaluate_vector_eq_approx()`], except that the elements
+/// are tuples - such as `(f64, f64, f64)` RGB or XYZ triples - each of whose
+/// components are compared, in order, by the evaluator, reporting the index
+/// and the component of the first that diverges.
+///
+/// NOTE: the expected and actual tuples must be of the same arity.
+pub fn evaluate_tuple_vector_eq_approx<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    TupleVectorComparisonResult, // comparison_result
+    Option<f64>,                 // margin_factor
+    Option<f64>,                 // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableTupleAsF64,
+    T_actualElement : traits::TestableTupleAsF64,
+{
+    debug_assert_eq!(
+        T_expectedElement::ARITY,
+        T_actualElement::ARITY,
+        "the expected and actual tuples must be of the same arity, but {} and {} given",
+        T_expectedElement::ARITY,
+        T_actualElement::ARITY
+    );
+
+    let expected = expected.as_ref();
+    let actual = actual.as_ref();
+
+    if expected.len() != actual.len() {
+        return (
+            TupleVectorComparisonResult::DifferentLengths {
+                expected_length : expected.len(),
+                actual_length :   actual.len(),
+            },
+            None,
+            None,
+        );
+    }
+
+    let triples =
+        expected
+            .iter()
+            .zip(actual.iter())
+            .enumerate()
+            .flat_map(|(index, (expected_element, actual_element))| {
+                (0..T_expectedElement::ARITY).map(move |component| {
+                    (
+                        (index, component),
+                        expected_element.testable_component_as_f64(component),
+                        actual_element.testable_component_as_f64(component),
+                    )
+                })
+            });
+
+    evaluate_f64_sequence_eq_approx_(triples, evaluator, None).finish(
+        TupleVectorComparisonResult::ExactlyEqual,
+        TupleVectorComparisonResult::ApproximatelyEqual,
+        |(index, component), expected_value, actual_value| {
+            TupleVectorComparisonResult::UnequalComponents {
+                index,
+                component,
+                expected_value,
+                actual_value,
+            }
+        },
+    )
+}
+
+/// Equivalent to [`evaluate_vector_eq_approx()`], except that the elements
+/// are optional, as in sensor data in which gaps are encoded as `None`: a
+/// `None` matches a `None`, a `Some` is compared with a `Some` by the
+/// evaluator, and a `Some`/`None` mismatch is reported, by index, as
+/// [`OptionalVectorComparisonResult::MismatchedPresence`].
+pub fn evaluate_optional_vector_eq_approx<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    OptionalVectorComparisonResult, // comparison_result
+    Option<f64>,                    // margin_factor
+    Option<f64>,                    // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[Option<T_expectedElement>]>,
+    T_actual : std_convert::AsRef<[Option<T_actualElement>]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let expected = expected.as_ref();
+    let actual = actual.as_ref();
+
+    if expected.len() != actual.len() {
+        return (
+            OptionalVectorComparisonResult::DifferentLengths {
+                expected_length : expected.len(),
+                actual_length :   actual.len(),
+            },
+            None,
+            None,
+        );
+    }
+
+    // the evaluation ceases at the first mismatched presence, which is
+    // retained here
+    let mut mismatched_presence = None;
+
+    let triples = expected
+        .iter()
+        .zip(actual.iter())
+        .enumerate()
+        .filter(|(_, elements)| !matches!(elements, (None, None)))
+        .map_while(|(index, (expected_element, actual_element))| {
+            match (expected_element, actual_element) {
+                (Some(expected_element), Some(actual_element)) => {
+                    Some((
+                        index,
+                        expected_element.testable_as_f64(),
+                        actual_element.testable_as_f64(),
+                    ))
+                },
+                _ => {
+                    mismatched_presence = Some(OptionalVectorComparisonResult::MismatchedPresence {
+                        index,
+                        expected_value : expected_element.as_ref().map(|v| v.testable_as_f64()),
+                        actual_value : actual_element.as_ref().map(|v| v.testable_as_f64()),
+                    });
+
+                    None
+                },
+            }
+        });
+
+    let accumulator = evaluate_f64_sequence_eq_approx_(triples, evaluator, None);
+
+    if let Some(mismatched_presence) = mismatched_presence {
+        return (mismatched_presence, None, None);
+    }
+
+    accumulator.finish(
+        OptionalVectorComparisonResult::ExactlyEqual,
+        OptionalVectorComparisonResult::ApproximatelyEqual,
+        |index, expected_value, actual_value| {
+            OptionalVectorComparisonResult::UnequalElements {
+                index_of_first_unequal_element :          index,
+                expected_value_of_first_unequal_element : expected_value,
+                actual_value_of_first_unequal_element :   actual_value,
+            }
+        },
+    )
+}
+
+/// Evaluates the approximate equality of two fallible scalar values: two
+/// `Ok` values are compared by the evaluator, as by
+/// [`evaluate_scalar_eq_approx()`]; two `Err` values are compared in
+/// accordance with `both_err`; and an `Ok`/`Err` mismatch is reported as
+/// [`ResultComparisonResult::MismatchedErr`].
+pub fn evaluate_result_eq_approx<T_expected, E_expected, T_actual, E_actual>(
+    expected : &Result<T_expected, E_expected>,
+    actual : &Result<T_actual, E_actual>,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+    both_err : BothErrDisposition,
+) -> (
+    ResultComparisonResult, // comparison_result
+    Option<f64>,            // margin_factor
+    Option<f64>,            // multiplier_factor
+)
+where
+    T_expected : traits::TestableAsF64 + std_fmt::Debug,
+    E_expected : std_fmt::Debug,
+    T_actual : traits::TestableAsF64 + std_fmt::Debug,
+    E_actual : std_fmt::Debug,
+{
+    match (expected, actual) {
+        (Ok(expected), Ok(actual)) => {
+            let (comparison_result, margin_factor, multiplier_factor) =
+                evaluate_scalar_eq_approx(expected, actual, evaluator);
+
+            (
+                ResultComparisonResult::Ok(comparison_result),
+                margin_factor,
+                multiplier_factor,
+            )
+        },
+        (Err(expected_error), Err(actual_error)) => {
+            let expected_error = format!("{expected_error:?}");
+            let actual_error = format!("{actual_error:?}");
+
+            let are_equal = match both_err {
+                BothErrDisposition::CompareDebug => expected_error == actual_error,
+                BothErrDisposition::Equal => true,
+                BothErrDisposition::Unequal => false,
+            };
+
+            if are_equal {
+                (ResultComparisonResult::BothErr, None, None)
+            } else {
+                (
+                    ResultComparisonResult::UnequalErrs {
+                        expected_error,
+                        actual_error,
+                    },
+                    None,
+                    None,
+                )
+            }
+        },
+        _ => {
+            (
+                ResultComparisonResult::MismatchedErr {
+                    expected : expected
+                        .as_ref()
+                        .map(|v| v.testable_as_f64())
+                        .map_err(|e| format!("{e:?}")),
+                    actual :   actual
+                        .as_ref()
+                        .map(|v| v.testable_as_f64())
+                        .map_err(|e| format!("{e:?}")),
+                },
+                None,
+                None,
+            )
+        },
+    }
+}
+
+/// Equivalent to [`evaluate_vector_eq_approx()`], except that, in addition,
+/// up to `k` unequal elements are collected, in index order, ceasing
+/// evaluation once `k` have been found. This gives a feel for the pattern
+/// of a failure without reporting every unequal element.
+///
+/// NOTE: a `k` of 0 is treated as 1. When the lengths differ no elements are
+/// evaluated, and the returned mismatches are empty.
+pub fn evaluate_vector_eq_approx_first_k<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+    k : usize,
+) -> (
+    VectorComparisonResult,     // comparison_result
+    Vec<VectorElementMismatch>, // mismatches
+    Option<f64>,                // margin_factor
+    Option<f64>,                // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let expected = expected.as_ref();
+    let actual = actual.as_ref();
+    let k = k.max(1);
+
+    if expected.len() != actual.len() {
+        return (
+            VectorComparisonResult::DifferentLengths {
+                expected_length : expected.len(),
+                actual_length :   actual.len(),
+            },
+            Vec::new(),
+            None,
+            None,
+        );
+    }
+
+    let mut accumulator = SequenceAccumulator_::new();
+    let mut mismatches = Vec::new();
+
+    for (index, (expected_element, actual_element)) in expected.iter().zip(actual.iter()).enumerate() {
+        let expected_value = expected_element.testable_as_f64();
+        let actual_value = actual_element.testable_as_f64();
+
+        if ComparisonResult::Unequal
+            == accumulator.record(
+                index,
+                expected_value,
+                actual_value,
+                evaluate_f64_eq_approx_(expected_value, actual_value, evaluator),
+            )
+        {
+            mismatches.push(VectorElementMismatch {
+                index,
+                expected_value,
+                actual_value,
+            });
+
+            if k == mismatches.len() {
+                break;
+            }
+        }
+    }
+
+    let (comparison_result, margin_factor, multiplier_factor) = accumulator.finish_vector();
+
+    (comparison_result, mismatches, margin_factor, multiplier_factor)
+}
+
+/// Equivalent to [`evaluate_vector_eq_approx_first_k()`], except that all
+/// elements are evaluated, and the (up to) `k` unequal elements returned
+/// are those of the greatest absolute error, `|actual - expected|`, in
+/// descending order thereof, so as to surface the worst offenders. Unequal
+/// elements of equal error are in index order, and those whose error is
+/// NaN are deemed the worst. The comparison result and the factors are
+/// still those of the first unequal element.
+///
+/// NOTE: a `k` of 0 is treated as 1. When the lengths differ no elements are
+/// evaluated, and the returned mismatches are empty.
+pub fn evaluate_vector_eq_approx_worst_first<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+    k : usize,
+) -> (
+    VectorComparisonResult,     // comparison_result
+    Vec<VectorElementMismatch>, // mismatches
+    Option<f64>,                // margin_factor
+    Option<f64>,                // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let expected = expected.as_ref();
+    let (comparison_result, mut mismatches, margin_factor, multiplier_factor) =
+        evaluate_vector_eq_approx_first_k(&expected, actual, evaluator, expected.len());
+
+    // stable, so that those of equal error remain in index order
+    mismatches.sort_by(|lhs, rhs| {
+        let lhs_error = (lhs.actual_value - lhs.expected_value).abs();
+        let rhs_error = (rhs.actual_value - rhs.expected_value).abs();
+
+        rhs_error.total_cmp(&lhs_error)
+    });
+    mismatches.truncate(k.max(1));
+
+    (comparison_result, mismatches, margin_factor, multiplier_factor)
+}
+
+/// Evaluates the approximate equality of two vectors irrespective of the
+/// order of their elements, i.e. as multisets, by matching each expected
+/// element, in turn, to the nearest unused actual element that is
+/// (approximately) equal to it, and reporting any expected and actual
+/// elements that remain unmatched.
+///
+/// NOTE: the matching is greedy, so when the tolerance is large relative to
+/// the spacing of the elements it may fail to find a complete matching even
+/// though one exists, e.g. with `margin(0.1)`, expected `[1.0, 1.1]` and
+/// actual `[1.05, 0.95]`, `1.0` is matched to `1.05` (rather than `0.95`),
+/// leaving `1.1` unmatched.
+pub fn evaluate_vector_eq_approx_unordered<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    UnorderedComparisonResult, // comparison_result
+    Option<f64>,               // margin_factor
+    Option<f64>,               // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    evaluate_vector_eq_approx_unordered_with_nan_ordering(expected, actual, evaluator, NanOrdering::Last)
+}
+
+/// Equivalent to [`evaluate_vector_eq_approx_unordered()`], except that NaN
+/// elements are matched in accordance with `nan_ordering`.
+pub fn evaluate_vector_eq_approx_unordered_with_nan_ordering<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+    nan_ordering : NanOrdering,
+) -> (
+    UnorderedComparisonResult, // comparison_result
+    Option<f64>,               // margin_factor
+    Option<f64>,               // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let total_cmp_evaluator = evaluators::TotalCmpNanEvaluator {
+        inner : evaluator
+    };
+    let evaluator : &dyn traits::ApproximateEqualityEvaluator = match nan_ordering {
+        NanOrdering::Last => evaluator,
+        NanOrdering::TotalCmp => &total_cmp_evaluator,
+    };
+
+    let expected : Vec<f64> = expected.as_ref().iter().map(|v| v.testable_as_f64()).collect();
+    let actual : Vec<f64> = actual.as_ref().iter().map(|v| v.testable_as_f64()).collect();
+
+    let mut used = vec![false; actual.len()];
+    let mut accumulator = SequenceAccumulator_::<usize>::new();
+    let mut unmatched_expected_values = Vec::new();
+
+    struct Candidate_ {
+        index :             usize,
+        distance :          f64,
+        is_exact :          bool,
+        margin_factor :     Option<f64>,
+        multiplier_factor : Option<f64>,
+    }
+
+    for &expected_value in &expected {
+        let mut best : Option<Candidate_> = None;
+
+        for (ix, &actual_value) in actual.iter().enumerate() {
+            if used[ix] {
+                continue;
+            }
+
+            let (comparison_result, candidate_margin_factor, candidate_multiplier_factor) =
+                evaluator.evaluate(expected_value, actual_value);
+
+            let is_exact = match comparison_result {
+                ComparisonResult::ExactlyEqual | ComparisonResult::BothNaN => true,
+                ComparisonResult::ApproximatelyEqual => false,
+                ComparisonResult::Unequal => continue,
+            };
+            let distance = if is_exact {
+                0.0
+            } else {
+                (expected_value - actual_value).abs()
+            };
+
+            if best.as_ref().is_none_or(|best| distance < best.distance) {
+                best = Some(Candidate_ {
+                    index : ix,
+                    distance,
+                    is_exact,
+                    margin_factor : candidate_margin_factor,
+                    multiplier_factor : candidate_multiplier_factor,
+                });
+            }
+        }
+
+        match best {
+            Some(candidate) => {
+                used[candidate.index] = true;
+
+                if !candidate.is_exact {
+                    accumulator.record_inexact(candidate.margin_factor, candidate.multiplier_factor);
+                }
+            },
+            None => unmatched_expected_values.push(expected_value),
+        };
+    }
+
+    let unmatched_actual_values : Vec<f64> = actual
+        .iter()
+        .zip(used.iter())
+        .filter(|(_, &used)| !used)
+        .map(|(&v, _)| v)
+        .collect();
+
+    let (comparison_result, margin_factor, multiplier_factor) = accumulator.finish(
+        UnorderedComparisonResult::ExactlyEqual,
+        UnorderedComparisonResult::ApproximatelyEqual,
+        |_, _, _| unreachable!("no unequal element is recorded"),
+    );
+
+    if !unmatched_expected_values.is_empty() || !unmatched_actual_values.is_empty() {
+        return (
+            UnorderedComparisonResult::Unmatched {
+                unmatched_expected_values,
+                unmatched_actual_values,
+            },
+            margin_factor,
+            multiplier_factor,
+        );
+    }
+
+    (comparison_result, margin_factor, multiplier_factor)
+}
+
+/// Evaluates the approximate equality of two vectors irrespective of the
+/// order of their elements, by sorting each - ascending, with any NaNs
+/// placed last - and comparing them element-wise as does
+/// [`evaluate_vector_eq_approx()`], so that the index of any unequal element
+/// is that in sorted space.
+///
+/// Unlike [`evaluate_vector_eq_approx_unordered()`], which matches greedily
+/// in `O(n^2)`, this is deterministic and `O(n log n)`, though a tolerance
+/// large relative to the spacing of the elements may pair them differently
+/// than would a matching.
+pub fn evaluate_vector_eq_approx_sorted<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    VectorComparisonResult, // comparison_result
+    Option<f64>,            // margin_factor
+    Option<f64>,            // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    evaluate_vector_eq_approx_sorted_with_nan_ordering(expected, actual, evaluator, NanOrdering::Last)
+}
+
+/// Equivalent to [`evaluate_vector_eq_approx_sorted()`], except that NaN
+/// elements are ordered and compared in accordance with `nan_ordering`.
+pub fn evaluate_vector_eq_approx_sorted_with_nan_ordering<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+    nan_ordering : NanOrdering,
+) -> (
+    VectorComparisonResult, // comparison_result
+    Option<f64>,            // margin_factor
+    Option<f64>,            // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    fn sorted_<T : traits::TestableAsF64>(
+        values : &[T],
+        nan_ordering : NanOrdering,
+    ) -> Vec<f64> {
+        let mut values : Vec<f64> = values.iter().map(|v| v.testable_as_f64()).collect();
+
+        match nan_ordering {
+            // NaNs last, irrespective of their sign
+            NanOrdering::Last => values.sort_by(|lhs, rhs| lhs.is_nan().cmp(&rhs.is_nan()).then(lhs.total_cmp(rhs))),
+            NanOrdering::TotalCmp => values.sort_by(f64::total_cmp),
+        };
+
+        values
+    }
+
+    let expected = sorted_(expected.as_ref(), nan_ordering);
+    let actual = sorted_(actual.as_ref(), nan_ordering);
+
+    match nan_ordering {
+        NanOrdering::Last => evaluate_vector_eq_approx_(&expected, &actual, evaluator, None),
+        NanOrdering::TotalCmp => {
+            let evaluator = evaluators::TotalCmpNanEvaluator {
+                inner : evaluator
+            };
+
+            evaluate_vector_eq_approx_(&expected, &actual, &evaluator, None)
+        },
+    }
+}
+
+/// Evaluates the approximate equality of the sequences yielded by two
+/// boxed iterators, as for plugin-style data sources that are not slices,
+/// without buffering them. Evaluation ceases at the first unequal element,
+/// but both iterators are consumed in full, so that, as by
+/// [`evaluate_vector_eq_approx()`], a difference in length is reported in
+/// preference to it.
+pub fn evaluate_boxed_iter_eq_approx(
+    mut expected : Box<dyn Iterator<Item = f64> + '_>,
+    mut actual : Box<dyn Iterator<Item = f64> + '_>,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    VectorComparisonResult, // comparison_result
+    Option<f64>,            // margin_factor
+    Option<f64>,            // multiplier_factor
+) {
+    let mut length = 0;
+    let mut num_excess = (0, 0);
+
+    let triples = std::iter::from_fn(|| {
+        match (expected.next(), actual.next()) {
+            (Some(expected_value), Some(actual_value)) => {
+                length += 1;
+
+                Some((length - 1, expected_value, actual_value))
+            },
+            (expected_value, actual_value) => {
+                num_excess = (expected_value.is_some() as usize, actual_value.is_some() as usize);
+
+                None
+            },
+        }
+    });
+
+    let accumulator = evaluate_f64_sequence_eq_approx_(triples, evaluator, None);
+
+    // once evaluation ceases, whether at the end of either sequence or at
+    // the first unequal element, the remaining items are only counted
+    let expected_length = length + num_excess.0 + expected.count();
+    let actual_length = length + num_excess.1 + actual.count();
+
+    if expected_length != actual_length {
+        return (
+            VectorComparisonResult::DifferentLengths {
+                expected_length,
+                actual_length,
+            },
+            None,
+            None,
+        );
+    }
+
+    accumulator.finish_vector()
+}
+
+/// Evaluates the approximate equality of the total energies - the sums of
+/// the squared magnitudes of their bins - of two complex spectra, as for
+/// verifying that an FFT round trip preserves energy (Parseval's theorem)
+/// even when individual bins differ slightly. The spectra need not be of
+/// the same length.
+///
+/// NOTE: requires the `"num-complex"` feature.
+#[cfg(feature = "num-complex")]
+pub fn evaluate_energy_eq_approx<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    ComparisonResult, // comparison_result
+    f64,              // expected_energy
+    f64,              // actual_energy
+    Option<f64>,      // margin_factor
+    Option<f64>,      // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[num_complex::Complex<T_expectedElement>]> + ?Sized,
+    T_actual : std_convert::AsRef<[num_complex::Complex<T_actualElement>]> + ?Sized,
+    T_expectedElement : Copy + Into<f64>,
+    T_actualElement : Copy + Into<f64>,
+{
+    fn energy_<T : Copy + Into<f64>>(spectrum : &[num_complex::Complex<T>]) -> f64 {
+        spectrum
+            .iter()
+            .map(|bin| num_complex::Complex::<f64>::new(bin.re.into(), bin.im.into()).norm_sqr())
+            .sum()
+    }
+
+    let expected_energy = energy_(expected.as_ref());
+    let actual_energy = energy_(actual.as_ref());
+
+    let (comparison_result, margin_factor, multiplier_factor) =
+        evaluate_f64_eq_approx_(expected_energy, actual_energy, evaluator);
+
+    (
+        comparison_result,
+        expected_energy,
+        actual_energy,
+        margin_factor,
+        multiplier_factor,
+    )
+}
+
+/// Evaluates the approximate equality of two sets of values, given as
+/// slices - as may be obtained from a `HashSet` of a hashable wrapper of
+/// `f64` - from each of which any (exact) duplicates are first removed:
+/// the sets are deemed equal if every expected value has a distinct
+/// approximately-equal partner in the actual set, and vice versa. If not,
+/// the first expected value without a partner is reported, or, if there is
+/// none, the first such actual value.
+///
+/// NOTE: the partners are found as by
+/// [`evaluate_vector_eq_approx_unordered()`], i.e. greedily, so when the
+/// tolerance is large enough that a value is approximately equal to more
+/// than one candidate, the choice of partner is ambiguous, and a matching
+/// may be missed even though one exists. Where this is a concern, use a
+/// tolerance smaller than half the spacing of the values.
+pub fn evaluate_set_eq_approx<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    SetComparisonResult, // comparison_result
+    Option<f64>,         // margin_factor
+    Option<f64>,         // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    fn distinct_<T : traits::TestableAsF64>(values : &[T]) -> Vec<f64> {
+        let mut distinct : Vec<f64> = Vec::with_capacity(values.len());
+
+        for value in values.iter().map(|v| v.testable_as_f64()) {
+            if !distinct.iter().any(|&d| d == value || d.total_cmp(&value).is_eq()) {
+                distinct.push(value);
+            }
+        }
+
+        distinct
+    }
+
+    let (comparison_result, margin_factor, multiplier_factor) =
+        evaluate_vector_eq_approx_unordered(&distinct_(expected.as_ref()), &distinct_(actual.as_ref()), evaluator);
+
+    let comparison_result = match comparison_result {
+        UnorderedComparisonResult::ExactlyEqual => SetComparisonResult::ExactlyEqual,
+        UnorderedComparisonResult::ApproximatelyEqual => SetComparisonResult::ApproximatelyEqual,
+        UnorderedComparisonResult::Unmatched {
+            unmatched_expected_values,
+            unmatched_actual_values,
+        } => {
+            match unmatched_expected_values.first() {
+                Some(&expected_value) => {
+                    SetComparisonResult::UnmatchedExpectedValue {
+                        expected_value,
+                    }
+                },
+                None => {
+                    SetComparisonResult::UnmatchedActualValue {
+                        actual_value : unmatched_actual_values[0],
+                    }
+                },
+            }
+        },
+    };
+
+    (comparison_result, margin_factor, multiplier_factor)
+}
+
+/// Evaluates the approximate equality of two complex numbers in polar form,
+/// applying `magnitude_evaluator` to their magnitudes and `phase_evaluator`
+/// to their phases. The phases are compared on the circle, i.e. the actual
+/// phase is taken to be the expected phase plus their (wrapped) difference,
+/// in `(-π, π]`, so that, say, `π - 0.01` and `-π + 0.01` are `0.02` apart.
+///
+/// NOTE: when either magnitude is zero the phase is undefined, and so only
+/// the magnitudes are compared.
+#[cfg(feature = "num-complex")]
+pub fn evaluate_complex_polar_eq_approx<T_expected, T_actual>(
+    expected : &num_complex::Complex<T_expected>,
+    actual : &num_complex::Complex<T_actual>,
+    magnitude_evaluator : &dyn traits::ApproximateEqualityEvaluator,
+    phase_evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    ComplexPolarComparisonResult, // comparison_result
+    Option<f64>,                  // margin_factor
+    Option<f64>,                  // multiplier_factor
+)
+where
+    T_expected : Copy + Into<f64>,
+    T_actual : Copy + Into<f64>,
+{
+    let expected = num_complex::Complex::<f64>::new(expected.re.into(), expected.im.into());
+    let actual = num_complex::Complex::<f64>::new(actual.re.into(), actual.im.into());
+
+    let (expected_magnitude, expected_phase) = expected.to_polar();
+    let (actual_magnitude, actual_phase) = actual.to_polar();
+
+    let (magnitude_comparison_result, magnitude_margin_factor, magnitude_multiplier_factor) =
+        magnitude_evaluator.evaluate(expected_magnitude, actual_magnitude);
+
+    if ComparisonResult::Unequal == magnitude_comparison_result {
+        return (
+            ComplexPolarComparisonResult::UnequalMagnitudes {
+                expected_magnitude,
+                actual_magnitude,
+            },
+            magnitude_margin_factor,
+            magnitude_multiplier_factor,
+        );
+    }
+
+    let phase_comparison_result = if 0.0 == expected_magnitude || 0.0 == actual_magnitude {
+        (ComparisonResult::ExactlyEqual, None, None)
+    } else {
+        let tau = 2.0 * std::f64::consts::PI;
+        let mut phase_difference = (actual_phase - expected_phase) % tau;
+
+        if phase_difference > std::f64::consts::PI {
+            phase_difference -= tau;
+        } else if phase_difference <= -std::f64::consts::PI {
+            phase_difference += tau;
+        }
+
+        let (comparison_result, margin_factor, multiplier_factor) =
+            phase_evaluator.evaluate(expected_phase, expected_phase + phase_difference);
+
+        if ComparisonResult::Unequal == comparison_result {
+            return (
+                ComplexPolarComparisonResult::UnequalPhases {
+                    expected_phase,
+                    actual_phase,
+                    phase_difference,
+                },
+                margin_factor,
+                multiplier_factor,
+            );
+        }
+
+        (comparison_result, margin_factor, multiplier_factor)
+    };
+
+    match (magnitude_comparison_result, phase_comparison_result) {
+        (ComparisonResult::ExactlyEqual, (ComparisonResult::ExactlyEqual, ..)) => {
+            (ComplexPolarComparisonResult::ExactlyEqual, None, None)
+        },
+        (ComparisonResult::ApproximatelyEqual, _) => {
+            (
+                ComplexPolarComparisonResult::ApproximatelyEqual,
+                magnitude_margin_factor,
+                magnitude_multiplier_factor,
+            )
+        },
+        (_, (_, phase_margin_factor, phase_multiplier_factor)) => {
+            (
+                ComplexPolarComparisonResult::ApproximatelyEqual,
+                phase_margin_factor,
+                phase_multiplier_factor,
+            )
+        },
+    }
+}
+
+/// Evaluates the approximate equality of two `ndarray` arrays of the same
+/// shape, comparing them element-wise, in logical order, with
+/// [`evaluate_scalar_eq_approx()`].
+#[cfg(feature = "ndarray")]
+pub fn evaluate_ndarray_eq_approx<S_expected, S_actual, D_expected, D_actual, T_expectedElement, T_actualElement>(
+    expected : &ndarray::ArrayBase<S_expected, D_expected>,
+    actual : &ndarray::ArrayBase<S_actual, D_actual>,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    NdarrayComparisonResult, // comparison_result
+    Option<f64>,             // margin_factor
+    Option<f64>,             // multiplier_factor
+)
+where
+    S_expected : ndarray::Data<Elem = T_expectedElement>,
+    S_actual : ndarray::Data<Elem = T_actualElement>,
+    D_expected : ndarray::Dimension,
+    D_actual : ndarray::Dimension,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    use ndarray::IntoDimension as _;
+
+    if expected.shape() != actual.shape() {
+        return (
+            NdarrayComparisonResult::DifferentShapes {
+                expected_shape : expected.shape().to_vec(),
+                actual_shape :   actual.shape().to_vec(),
+            },
+            None,
+            None,
+        );
+    }
+
+    let triples = expected
+        .indexed_iter()
+        .zip(actual.iter())
+        .map(|((index, expected_element), actual_element)| {
+            (
+                index,
+                expected_element.testable_as_f64(),
+                actual_element.testable_as_f64(),
+            )
+        });
+
+    evaluate_f64_sequence_eq_approx_(triples, evaluator, None).finish(
+        NdarrayComparisonResult::ExactlyEqual,
+        NdarrayComparisonResult::ApproximatelyEqual,
+        |index, expected_value, actual_value| {
+            NdarrayComparisonResult::UnequalElements {
+                index : index.into_dimension().slice().to_vec(),
+                expected_value,
+                actual_value,
+            }
+        },
+    )
+}
+
+/// Evaluates the approximate equality of two `serde_json::Value`s that are
+/// expected to be arrays - which may be nested - of numbers, as read from
+/// JSON fixtures, comparing the numbers, in document order, as `f64` with
+/// [`evaluate_scalar_eq_approx()`], and identifying any mismatch by its
+/// JSON-pointer-like path.
+///
+/// NOTE: two (top-level) numbers are also compared.
+#[cfg(feature = "serde_json")]
+pub fn evaluate_json_eq_approx(
+    expected : &serde_json::Value,
+    actual : &serde_json::Value,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    JsonComparisonResult, // comparison_result
+    Option<f64>,          // margin_factor
+    Option<f64>,          // multiplier_factor
+) {
+    let mut inexact_factors = None;
+
+    match evaluate_json_eq_approx_(expected, actual, "", evaluator, &mut inexact_factors) {
+        Some(failure) => failure,
+        None => {
+            match inexact_factors {
+                Some((margin_factor, multiplier_factor)) => {
+                    (
+                        JsonComparisonResult::ApproximatelyEqual,
+                        margin_factor,
+                        multiplier_factor,
+                    )
+                },
+                None => (JsonComparisonResult::ExactlyEqual, None, None),
+            }
+        },
+    }
+}
+
+/// Recursive implementation of [`evaluate_json_eq_approx()`], which
+/// obtains the first failure, if any, and records the factors of the first
+/// inexact comparison in `inexact_factors`.
+#[cfg(feature = "serde_json")]
+fn evaluate_json_eq_approx_(
+    expected : &serde_json::Value,
+    actual : &serde_json::Value,
+    path : &str,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+    inexact_factors : &mut Option<(Option<f64>, Option<f64>)>,
+) -> Option<(
+    JsonComparisonResult, // comparison_result
+    Option<f64>,          // margin_factor
+    Option<f64>,          // multiplier_factor
+)> {
+    use serde_json::Value;
+
+    match (expected, actual) {
+        (Value::Array(expected_elements), Value::Array(actual_elements)) => {
+            if expected_elements.len() != actual_elements.len() {
+                return Some((
+                    JsonComparisonResult::DifferentLengths {
+                        path :            path.into(),
+                        expected_length : expected_elements.len(),
+                        actual_length :   actual_elements.len(),
+                    },
+                    None,
+                    None,
+                ));
+            }
+
+            expected_elements.iter().zip(actual_elements).enumerate().find_map(
+                |(index, (expected_element, actual_element))| {
+                    evaluate_json_eq_approx_(
+                        expected_element,
+                        actual_element,
+                        &format!("{path}/{index}"),
+                        evaluator,
+                        inexact_factors,
+                    )
+                },
+            )
+        },
+        (Value::Number(expected_number), Value::Number(actual_number)) => {
+            let (Some(expected_value), Some(actual_value)) = (expected_number.as_f64(), actual_number.as_f64()) else {
+                return Some((
+                    JsonComparisonResult::NonNumericElement {
+                        path :           path.into(),
+                        expected_value : expected.clone(),
+                        actual_value :   actual.clone(),
+                    },
+                    None,
+                    None,
+                ));
+            };
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                evaluate_f64_eq_approx_(expected_value, actual_value, evaluator);
+
+            match comparison_result {
+                ComparisonResult::ExactlyEqual | ComparisonResult::BothNaN => None,
+                ComparisonResult::ApproximatelyEqual => {
+                    inexact_factors.get_or_insert((margin_factor, multiplier_factor));
+
+                    None
+                },
+                ComparisonResult::Unequal => {
+                    Some((
+                        JsonComparisonResult::UnequalElements {
+                            path : path.into(),
+                            expected_value,
+                            actual_value,
+                        },
+                        margin_factor,
+                        multiplier_factor,
+                    ))
+                },
+            }
+        },
+        (Value::Array(_), Value::Number(_)) | (Value::Number(_), Value::Array(_)) => {
+            Some((
+                JsonComparisonResult::MismatchedStructure {
+                    path :           path.into(),
+                    expected_value : expected.clone(),
+                    actual_value :   actual.clone(),
+                },
+                None,
+                None,
+            ))
+        },
+        _ => {
+            Some((
+                JsonComparisonResult::NonNumericElement {
+                    path :           path.into(),
+                    expected_value : expected.clone(),
+                    actual_value :   actual.clone(),
+                },
+                None,
+                None,
+            ))
+        },
+    }
+}
+
+/// Evaluates the approximate equality of two matrices, given as vectors of
+/// rows, comparing them row-by-row with [`evaluate_vector_eq_approx()`].
+pub fn evaluate_matrix_eq_approx<T_expected, T_actual, T_expectedRow, T_actualRow, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    MatrixComparisonResult, // comparison_result
+    Option<f64>,            // margin_factor
+    Option<f64>,            // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedRow]>,
+    T_actual : std_convert::AsRef<[T_actualRow]>,
+    T_expectedRow : std_convert::AsRef<[T_expectedElement]>,
+    T_actualRow : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let expected = expected.as_ref();
+    let actual = actual.as_ref();
+
+    if expected.len() != actual.len() {
+        return (
+            MatrixComparisonResult::DifferentRowCounts {
+                expected_row_count : expected.len(),
+                actual_row_count :   actual.len(),
+            },
+            None,
+            None,
+        );
+    }
+
+    let mut accumulator = SequenceAccumulator_::new();
+
+    for (row_index, (expected_row, actual_row)) in expected.iter().zip(actual.iter()).enumerate() {
+        let (row_comparison_result, row_margin_factor, row_multiplier_factor) =
+            evaluate_vector_eq_approx(expected_row, actual_row, evaluator);
+
+        match row_comparison_result {
+            VectorComparisonResult::ExactlyEqual => (),
+            VectorComparisonResult::ApproximatelyEqual => {
+                accumulator.record_inexact(row_margin_factor, row_multiplier_factor);
+            },
+            VectorComparisonResult::DifferentLengths {
+                expected_length,
+                actual_length,
+            } => {
+                return (
+                    MatrixComparisonResult::DifferentRowLengths {
+                        row_index,
+                        expected_length,
+                        actual_length,
+                    },
+                    None,
+                    None,
+                );
+            },
+            VectorComparisonResult::UnequalElements {
+                index_of_first_unequal_element,
+                expected_value_of_first_unequal_element,
+                actual_value_of_first_unequal_element,
+            } => {
+                accumulator.record_unequal(
+                    (row_index, index_of_first_unequal_element),
+                    expected_value_of_first_unequal_element,
+                    actual_value_of_first_unequal_element,
+                    row_margin_factor,
+                    row_multiplier_factor,
+                );
+
+                break;
+            },
+        };
+    }
+
+    accumulator.finish(
+        MatrixComparisonResult::ExactlyEqual,
+        MatrixComparisonResult::ApproximatelyEqual,
+        |(row_index, column_index), expected_value, actual_value| {
+            MatrixComparisonResult::UnequalElements {
+                row_index,
+                column_index,
+                expected_value,
+                actual_value,
+            }
+        },
+    )
+}
+
+/// Evaluates the approximate equality of two matrices, given as vectors of
+/// rows, as does [`evaluate_matrix_eq_approx()`], except that if `actual`
+/// does not match `expected` then its transpose is tried also, so as to
+/// accommodate results that may legitimately be returned transposed.
+///
+/// The factors returned are those of the passing comparison, or, when both
+/// fail, of the direct comparison.
+pub fn evaluate_matrix_eq_approx_or_transpose<
+    T_expected,
+    T_actual,
+    T_expectedRow,
+    T_actualRow,
+    T_expectedElement,
+    T_actualElement,
+>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    TransposableMatrixComparisonResult, // comparison_result
+    Option<f64>,                        // margin_factor
+    Option<f64>,                        // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedRow]>,
+    T_actual : std_convert::AsRef<[T_actualRow]>,
+    T_expectedRow : std_convert::AsRef<[T_expectedElement]>,
+    T_actualRow : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let (direct_comparison_result, margin_factor, multiplier_factor) =
+        evaluate_matrix_eq_approx(expected, actual, evaluator);
+
+    match direct_comparison_result {
+        MatrixComparisonResult::ExactlyEqual => {
+            return (
+                TransposableMatrixComparisonResult::ExactlyEqual {
+                    transposed : false
+                },
+                margin_factor,
+                multiplier_factor,
+            );
+        },
+        MatrixComparisonResult::ApproximatelyEqual => {
+            return (
+                TransposableMatrixComparisonResult::ApproximatelyEqual {
+                    transposed : false
+                },
+                margin_factor,
+                multiplier_factor,
+            );
+        },
+        _ => (),
+    };
+
+    let actual_rows : Vec<Vec<f64>> = actual
+        .as_ref()
+        .iter()
+        .map(|row| row.as_ref().iter().map(|v| v.testable_as_f64()).collect())
+        .collect();
+    let column_count = actual_rows.first().map_or(0, |row| row.len());
+
+    if actual_rows.iter().any(|row| row.len() != column_count) {
+        return (
+            TransposableMatrixComparisonResult::Unequal {
+                direct :     direct_comparison_result,
+                transposed : None,
+            },
+            margin_factor,
+            multiplier_factor,
+        );
+    }
+
+    let actual_transposed : Vec<Vec<f64>> = (0..column_count)
+        .map(|column_index| actual_rows.iter().map(|row| row[column_index]).collect())
+        .collect();
+
+    let (transposed_comparison_result, transposed_margin_factor, transposed_multiplier_factor) =
+        evaluate_matrix_eq_approx(expected, &actual_transposed, evaluator);
+
+    match transposed_comparison_result {
+        MatrixComparisonResult::ExactlyEqual => {
+            (
+                TransposableMatrixComparisonResult::ExactlyEqual {
+                    transposed : true
+                },
+                transposed_margin_factor,
+                transposed_multiplier_factor,
+            )
+        },
+        MatrixComparisonResult::ApproximatelyEqual => {
+            (
+                TransposableMatrixComparisonResult::ApproximatelyEqual {
+                    transposed : true
+                },
+                transposed_margin_factor,
+                transposed_multiplier_factor,
+            )
+        },
+        _ => {
+            (
+                TransposableMatrixComparisonResult::Unequal {
+                    direct :     direct_comparison_result,
+                    transposed : Some(transposed_comparison_result),
+                },
+                margin_factor,
+                multiplier_factor,
+            )
+        },
+    }
+}
+
+/// Evaluates the approximate equality of two rank-3 tensors, given as
+/// nested three-level vectors, comparing them matrix-by-matrix with
+/// [`evaluate_matrix_eq_approx()`].
+pub fn evaluate_tensor3_eq_approx<
+    T_expected,
+    T_actual,
+    T_expectedMatrix,
+    T_actualMatrix,
+    T_expectedRow,
+    T_actualRow,
+    T_expectedElement,
+    T_actualElement,
+>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    Tensor3ComparisonResult, // comparison_result
+    Option<f64>,             // margin_factor
+    Option<f64>,             // multiplier_factor
+)
+where
+    T_expected : std_convert::AsRef<[T_expectedMatrix]>,
+    T_actual : std_convert::AsRef<[T_actualMatrix]>,
+    T_expectedMatrix : std_convert::AsRef<[T_expectedRow]>,
+    T_actualMatrix : std_convert::AsRef<[T_actualRow]>,
+    T_expectedRow : std_convert::AsRef<[T_expectedElement]>,
+    T_actualRow : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let expected = expected.as_ref();
+    let actual = actual.as_ref();
+
+    if expected.len() != actual.len() {
+        return (
+            Tensor3ComparisonResult::DifferentOuterLengths {
+                expected_length : expected.len(),
+                actual_length :   actual.len(),
+            },
+            None,
+            None,
+        );
+    }
+
+    let mut accumulator = SequenceAccumulator_::new();
+
+    for (i, (expected_matrix, actual_matrix)) in expected.iter().zip(actual.iter()).enumerate() {
+        let (matrix_comparison_result, matrix_margin_factor, matrix_multiplier_factor) =
+            evaluate_matrix_eq_approx(expected_matrix, actual_matrix, evaluator);
+
+        match matrix_comparison_result {
+            MatrixComparisonResult::ExactlyEqual => (),
+            MatrixComparisonResult::ApproximatelyEqual => {
+                accumulator.record_inexact(matrix_margin_factor, matrix_multiplier_factor);
+            },
+            MatrixComparisonResult::DifferentRowCounts {
+                expected_row_count,
+                actual_row_count,
+            } => {
+                return (
+                    Tensor3ComparisonResult::DifferentMiddleLengths {
+                        i,
+                        expected_length : expected_row_count,
+                        actual_length : actual_row_count,
+                    },
+                    None,
+                    None,
+                );
+            },
+            MatrixComparisonResult::DifferentRowLengths {
+                row_index,
+                expected_length,
+                actual_length,
+            } => {
+                return (
+                    Tensor3ComparisonResult::DifferentInnerLengths {
+                        i,
+                        j : row_index,
+                        expected_length,
+                        actual_length,
+                    },
+                    None,
+                    None,
+                );
+            },
+            MatrixComparisonResult::UnequalElements {
+                row_index,
+                column_index,
+                expected_value,
+                actual_value,
+            } => {
+                accumulator.record_unequal(
+                    (i, row_index, column_index),
+                    expected_value,
+                    actual_value,
+                    matrix_margin_factor,
+                    matrix_multiplier_factor,
+                );
+
+                break;
+            },
+        };
+    }
+
+    accumulator.finish(
+        Tensor3ComparisonResult::ExactlyEqual,
+        Tensor3ComparisonResult::ApproximatelyEqual,
+        |(i, j, k), expected_value, actual_value| {
+            Tensor3ComparisonResult::UnequalElements {
+                i,
+                j,
+                k,
+                expected_value,
+                actual_value,
+            }
+        },
+    )
+}
+
+/// Evaluates whether a function, `f`, matches a reference polynomial, given
+/// by its `coefficients` in ascending order of power, as does
+/// [`evaluate_fn_eq_approx()`] with the (Horner-evaluated) polynomial as
+/// the reference, so that the expected value of any divergence is that of
+/// the polynomial.
+pub fn evaluate_fn_matches_poly_approx<F, T_actual>(
+    f : F,
+    coefficients : &[f64],
+    domain_start : f64,
+    domain_end : f64,
+    steps : usize,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    FunctionComparisonResult, // comparison_result
+    Option<f64>,              // margin_factor
+    Option<f64>,              // multiplier_factor
+)
+where
+    F : FnMut(f64) -> T_actual,
+    T_actual : traits::TestableAsF64 + std_fmt::Debug,
+{
+    evaluate_fn_eq_approx(
+        |x| utils::evaluate_polynomial(coefficients, x),
+        f,
+        domain_start,
+        domain_end,
+        steps,
+        evaluator,
+    )
+}
+
+/// Evaluates the approximate equality of two functions - typically a
+/// reference, `f`, and an approximation of it, `g` - by sampling each at
+/// `steps` evenly spaced points across the closed interval
+/// `[domain_start, domain_end]`, stopping at the first point at which they
+/// diverge.
+///
+/// NOTE: when `steps` is 1 only `domain_start` is sampled; when it is 0 no
+/// point is sampled, and the functions are deemed exactly equal.
+pub fn evaluate_fn_eq_approx<F, G, T_expected, T_actual>(
+    mut f : F,
+    mut g : G,
+    domain_start : f64,
+    domain_end : f64,
+    steps : usize,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    FunctionComparisonResult, // comparison_result
+    Option<f64>,              // margin_factor
+    Option<f64>,              // multiplier_factor
+)
+where
+    F : FnMut(f64) -> T_expected,
+    G : FnMut(f64) -> T_actual,
+    T_expected : traits::TestableAsF64 + std_fmt::Debug,
+    T_actual : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let triples = (0..steps).map(|step| {
+        let x = if 0 == step {
+            domain_start
+        } else if steps - 1 == step {
+            domain_end
+        } else {
+            domain_start + (domain_end - domain_start) * (step as f64) / ((steps - 1) as f64)
+        };
+
+        (x, f(x).testable_as_f64(), g(x).testable_as_f64())
+    });
+
+    evaluate_f64_sequence_eq_approx_(triples, evaluator, None).finish(
+        FunctionComparisonResult::ExactlyEqual,
+        FunctionComparisonResult::ApproximatelyEqual,
+        |x, expected_value, actual_value| {
+            FunctionComparisonResult::UnequalAtPoint {
+                x,
+                expected_value,
+                actual_value,
+            }
+        },
+    )
+}
+
+/// Evaluates the approximate equality of two distributions, given as
+/// vectors of bin counts (or weights), by normalising each to sum to 1.0
+/// and then comparing them bin-by-bin.
+///
+/// In the case of unequal elements the values reported are the normalised
+/// masses of the first divergent bin.
+///
+/// # Errors
+/// Returns [`DistributionError`] if either set of bins sums to zero.
+pub fn evaluate_distribution_eq_approx<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> Result<
+    (
+        VectorComparisonResult, // comparison_result
+        Option<f64>,            // margin_factor
+        Option<f64>,            // multiplier_factor
+    ),
+    DistributionError,
+>
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    fn normalise_<T>(bins : &[T]) -> Option<Vec<f64>>
+    where
+        T : traits::TestableAsF64,
+    {
+        let bins : Vec<f64> = bins.iter().map(|bin| bin.testable_as_f64()).collect();
+        let sum : f64 = bins.iter().sum();
+
+        if 0.0 == sum {
+            None
+        } else {
+            Some(bins.into_iter().map(|bin| bin / sum).collect())
+        }
+    }
+
+    let expected = normalise_(expected.as_ref()).ok_or(DistributionError::ExpectedSumIsZero)?;
+    let actual = normalise_(actual.as_ref()).ok_or(DistributionError::ActualSumIsZero)?;
+
+    Ok(evaluate_vector_eq_approx(&expected, &actual, evaluator))
+}
+
+/// Evaluates the approximate equality of two vectors element-by-element,
+/// deeming each pair of elements approximately equal if the weighted error,
+/// `weights[i] * |expected[i] - actual[i]|`, does not exceed
+/// `margin_factor`, and reporting the first element for which it does.
+///
+/// # Errors
+///
+/// Fails with [`WeightsError::DifferentLengths`] if the number of weights
+/// differs from the number of expected elements.
+pub fn evaluate_vector_eq_approx_weighted<T_expected, T_actual, T_weights, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+    weights : &T_weights,
+    margin_factor : f64,
+) -> Result<
+    (
+        VectorComparisonResult, // comparison_result
+        Option<f64>,            // margin_factor
+        Option<f64>,            // multiplier_factor
+    ),
+    WeightsError,
+>
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_weights : std_convert::AsRef<[f64]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let expected = expected.as_ref();
+    let actual = actual.as_ref();
+    let weights = weights.as_ref();
+
+    if weights.len() != expected.len() {
+        return Err(WeightsError::DifferentLengths {
+            expected_length : expected.len(),
+            weights_length :  weights.len(),
+        });
+    }
+
+    if expected.len() != actual.len() {
+        return Ok((
+            VectorComparisonResult::DifferentLengths {
+                expected_length : expected.len(),
+                actual_length :   actual.len(),
+            },
+            None,
+            None,
+        ));
+    }
+
+    let mut any_inexact = false;
+
+    for (ix, ((expected_element, actual_element), &weight)) in
+        expected.iter().zip(actual.iter()).zip(weights.iter()).enumerate()
+    {
+        let expected_value = expected_element.testable_as_f64();
+        let actual_value = actual_element.testable_as_f64();
+
+        match utils::compare_approximate_equality_by_weighted_margin(
+            expected_value,
+            actual_value,
+            weight,
+            margin_factor,
+        ) {
+            ComparisonResult::ExactlyEqual | ComparisonResult::BothNaN => (),
+            ComparisonResult::ApproximatelyEqual => any_inexact = true,
+            ComparisonResult::Unequal => {
+                return Ok((
+                    VectorComparisonResult::UnequalElements {
+                        index_of_first_unequal_element :          ix,
+                        expected_value_of_first_unequal_element : expected_value,
+                        actual_value_of_first_unequal_element :   actual_value,
+                    },
+                    Some(margin_factor),
+                    None,
+                ));
+            },
+        };
+    }
+
+    Ok((
+        if any_inexact {
+            VectorComparisonResult::ApproximatelyEqual
+        } else {
+            VectorComparisonResult::ExactlyEqual
+        },
+        Some(margin_factor),
+        None,
+    ))
+}
+
+/// Checks basic invariants of the given evaluator over a deterministic set
+/// of pseudo-random comparand pairs generated from `seed`, returning any
+/// violations found, so that authors of custom evaluators have a ready-made
+/// sanity harness. The invariants are:
+/// - `ComparisonResult::ExactlyEqual` implies `expected == actual`, so that
+///   two NaNs may be equal only as `ComparisonResult::BothNaN`;
+/// - `evaluate(x, x)` is never `ComparisonResult::Unequal` for finite `x`;
+///
+/// The comparands range over many orders of magnitude, in both signs, and
+/// include zeroes, NaNs, and pairs that are close to one another.
+pub fn check_evaluator_invariants(
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+    seed : u64,
+) -> Vec<EvaluatorInvariantViolation> {
+    const NUM_SAMPLES : usize = 1000;
+
+    let mut rng = utils::SplitMix64_::new(seed);
+    let mut violations = Vec::new();
+
+    let next_value = |rng : &mut utils::SplitMix64_| -> f64 {
+        match rng.next_u64() % 16 {
+            0 => 0.0,
+            1 => -0.0,
+            2 => f64::NAN,
+            _ => {
+                let sign = if 0 == rng.next_u64() & 1 { 1.0 } else { -1.0 };
+                let exponent = (rng.next_u64() % 41) as i32 - 20;
+
+                sign * (1.0 + rng.next_unit_f64()) * 10f64.powi(exponent)
+            },
+        }
+    };
+
+    for _ in 0..NUM_SAMPLES {
+        let expected = next_value(&mut rng);
+        let actual = match rng.next_u64() % 4 {
+            // close to expected
+            0 => expected * (1.0 + (rng.next_unit_f64() - 0.5) * 1e-6),
+            // identical to expected
+            1 => expected,
+            _ => next_value(&mut rng),
+        };
+
+        if expected.is_finite() && ComparisonResult::Unequal == evaluator.evaluate(expected, expected).0 {
+            violations.push(EvaluatorInvariantViolation::NotReflexive {
+                value : expected
+            });
+        }
+
+        // NOTE: two NaNs compare unequal here, since any evaluator that
+        // treats them as equal must report `ComparisonResult::BothNaN`
+        if ComparisonResult::ExactlyEqual == evaluator.evaluate(expected, actual).0 && expected != actual {
+            violations.push(EvaluatorInvariantViolation::ExactlyEqualForUnequalComparands {
+                expected,
+                actual,
+            });
+        }
+    }
+
+    violations
+}
+
+/// Evaluates whether `value` lies within the closed interval `[lo, hi]`,
+/// or is approximately equal (as determined by `evaluator`) to the nearer
+/// bound.
+pub fn evaluate_scalar_in_range_approx<T_value, T_lo, T_hi>(
+    value : &T_value,
+    lo : &T_lo,
+    hi : &T_hi,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    RangeComparisonResult, // comparison_result
+    Option<f64>,           // margin_factor
+    Option<f64>,           // multiplier_factor
+)
+where
+    T_value : traits::TestableAsF64 + std_fmt::Debug,
+    T_lo : traits::TestableAsF64 + std_fmt::Debug,
+    T_hi : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let value = value.testable_as_f64();
+    let lo = lo.testable_as_f64();
+    let hi = hi.testable_as_f64();
+
+    if value >= lo && value <= hi {
+        return (RangeComparisonResult::WithinRange, None, None);
+    }
+
+    let (bound, is_above) = if value > hi { (hi, true) } else { (lo, false) };
+
+    let (comparison_result, margin_factor, multiplier_factor) = evaluator.evaluate(bound, value);
+
+    let range_comparison_result = match comparison_result {
+        ComparisonResult::ExactlyEqual | ComparisonResult::BothNaN | ComparisonResult::ApproximatelyEqual => {
+            RangeComparisonResult::ApproximatelyWithinRange
+        },
+        ComparisonResult::Unequal => {
+            if is_above {
+                RangeComparisonResult::AboveUpperBound {
+                    upper_bound : hi,
+                    excess :      value - hi,
+                }
+            } else {
+                RangeComparisonResult::BelowLowerBound {
+                    lower_bound : lo,
+                    shortfall :   lo - value,
+                }
+            }
+        },
+    };
+
+    (range_comparison_result, margin_factor, multiplier_factor)
+}
+
+/// Evaluates whether `actual` lies within the closed band `[lo, hi]`,
+/// which is given directly rather than as a centre and a tolerance. Any
+/// value within the band, including either bound, is deemed
+/// `ComparisonResult::ApproximatelyEqual`, and any value outside it (or
+/// NaN) `ComparisonResult::Unequal`; `ComparisonResult::ExactlyEqual` is
+/// never obtained, since there is no single expected value.
+///
+/// NOTE: if `lo` is greater than `hi` the band is empty, and so every value
+/// is unequal.
+pub fn evaluate_scalar_in_band<T_actual, T_lo, T_hi>(
+    actual : &T_actual,
+    lo : &T_lo,
+    hi : &T_hi,
+) -> ComparisonResult
+where
+    T_actual : traits::TestableAsF64 + std_fmt::Debug,
+    T_lo : traits::TestableAsF64 + std_fmt::Debug,
+    T_hi : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let actual = actual.testable_as_f64();
+    let lo = lo.testable_as_f64();
+    let hi = hi.testable_as_f64();
+
+    if actual >= lo && actual <= hi {
+        ComparisonResult::ApproximatelyEqual
+    } else {
+        ComparisonResult::Unequal
+    }
+}
+
+/// Evaluates whether two values with measurement uncertainties - "value ±
+/// uncertainty" - are equal, in accordance with the given `criterion`,
+/// returning also their separation in units of their combined uncertainty,
+/// `|expected - actual| / sqrt(expected_uncertainty^2 +
+/// actual_uncertainty^2)`, as in "1.7σ".
+///
+/// NOTE: uncertainties are taken as magnitudes. If both uncertainties are
+/// zero then only identical values are equal, and the separation of
+/// different values is infinite.
+pub fn evaluate_scalar_eq_approx_with_uncertainty<T_expected, T_actual>(
+    expected : &T_expected,
+    expected_uncertainty : f64,
+    actual : &T_actual,
+    actual_uncertainty : f64,
+    criterion : UncertaintyCriterion,
+) -> (
+    ComparisonResult, // comparison_result
+    f64,              // separation_in_sigmas
+)
+where
+    T_expected : traits::TestableAsF64 + std_fmt::Debug,
+    T_actual : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let expected = expected.testable_as_f64();
+    let actual = actual.testable_as_f64();
+    let expected_uncertainty = expected_uncertainty.abs();
+    let actual_uncertainty = actual_uncertainty.abs();
+
+    if expected == actual {
+        return (ComparisonResult::ExactlyEqual, 0.0);
+    }
+
+    let separation = (expected - actual).abs();
+    let combined_uncertainty = expected_uncertainty.hypot(actual_uncertainty);
+
+    let separation_in_sigmas = if 0.0 == combined_uncertainty {
+        f64::INFINITY
+    } else {
+        separation / combined_uncertainty
+    };
+
+    let limit = match criterion {
+        UncertaintyCriterion::IntervalsOverlap => expected_uncertainty + actual_uncertainty,
+        UncertaintyCriterion::CombinedUncertainty => combined_uncertainty,
+    };
+
+    let comparison_result = if separation <= limit {
+        ComparisonResult::ApproximatelyEqual
+    } else {
+        ComparisonResult::Unequal
+    };
+
+    (comparison_result, separation_in_sigmas)
+}
+
+/// Evaluates whether `value` is approximately an integer, i.e. whether it
+/// is approximately equal, as determined by the given evaluator, to its
+/// nearest integer, which is returned alongside the result.
+///
+/// NOTE: a non-finite value is never an integer, and so is deemed
+/// unequal.
+pub fn evaluate_approx_integer<T_value>(
+    value : &T_value,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+) -> (
+    ComparisonResult, // comparison_result
+    f64,              // nearest_integer
+    Option<f64>,      // margin_factor
+    Option<f64>,      // multiplier_factor
+)
+where
+    T_value : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let value = value.testable_as_f64();
+    let nearest_integer = value.round();
+
+    if !value.is_finite() {
+        return (ComparisonResult::Unequal, nearest_integer, None, None);
+    }
+
+    let (comparison_result, margin_factor, multiplier_factor) =
+        evaluate_f64_eq_approx_(nearest_integer, value, evaluator);
+
+    (comparison_result, nearest_integer, margin_factor, multiplier_factor)
+}
+
+/// Computes the relative error of `actual` with respect to `expected`,
+/// i.e. `|actual - expected| / |expected|`, or, when `expected` is zero,
+/// the absolute error `|actual - expected|`.
+pub fn evaluate_relative_error<T_expected, T_actual>(
+    expected : &T_expected,
+    actual : &T_actual,
+) -> (
+    f64,  // error
+    bool, // is_absolute
+)
+where
+    T_expected : traits::TestableAsF64 + std_fmt::Debug,
+    T_actual : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let expected = expected.testable_as_f64();
+    let actual = actual.testable_as_f64();
+
+    let absolute_error = (actual - expected).abs();
+
+    if 0.0 == expected {
+        (absolute_error, true)
+    } else {
+        (absolute_error / expected.abs(), false)
+    }
+}
+
+/// Computes the root-mean-square error (RMSE) of `actual` with respect to
+/// `expected`, i.e. `sqrt(sum((actual[i] - expected[i])^2) / n)`, as an
+/// aggregate measure of fit quality rather than per-element pass/fail.
+///
+/// Fails with [`RmseError::DifferentLengths`] if the vectors differ in
+/// length. The RMSE of two empty vectors is 0.0.
+pub fn evaluate_rmse<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+) -> Result<f64, RmseError>
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let expected = expected.as_ref();
+    let actual = actual.as_ref();
+
+    if expected.len() != actual.len() {
+        return Err(RmseError::DifferentLengths {
+            expected_length : expected.len(),
+            actual_length :   actual.len(),
+        });
+    }
+
+    if expected.is_empty() {
+        return Ok(0.0);
+    }
+
+    let sum_of_squares = expected
+        .iter()
+        .zip(actual.iter())
+        .map(|(e, a)| {
+            let d = a.testable_as_f64() - e.testable_as_f64();
+
+            d * d
+        })
+        .sum::<f64>();
+
+    Ok((sum_of_squares / expected.len() as f64).sqrt())
+}
+
+/// Computes the Pearson correlation coefficient of `expected` and
+/// `actual`, in the range [-1.0, 1.0], as a measure of whether two signals
+/// are shaped alike, irrespective of their offset and scale.
+///
+/// Fails with [`CorrelationError`] if the vectors differ in length, have
+/// fewer than two elements, or either of them is constant.
+pub fn evaluate_correlation<T_expected, T_actual, T_expectedElement, T_actualElement>(
+    expected : &T_expected,
+    actual : &T_actual,
+) -> Result<f64, CorrelationError>
+where
+    T_expected : std_convert::AsRef<[T_expectedElement]>,
+    T_actual : std_convert::AsRef<[T_actualElement]>,
+    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
+    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
+{
+    let expected = expected.as_ref();
+    let actual = actual.as_ref();
+
+    if expected.len() != actual.len() {
+        return Err(CorrelationError::DifferentLengths {
+            expected_length : expected.len(),
+            actual_length :   actual.len(),
+        });
+    }
+
+    let n = expected.len();
+
+    if n < 2 {
+        return Err(CorrelationError::InsufficientElements {
+            length : n
+        });
+    }
+
+    let mean_e = expected.iter().map(|v| v.testable_as_f64()).sum::<f64>() / n as f64;
+    let mean_a = actual.iter().map(|v| v.testable_as_f64()).sum::<f64>() / n as f64;
+
+    let mut covariance = 0.0;
+    let mut variance_e = 0.0;
+    let mut variance_a = 0.0;
+
+    for (e, a) in expected.iter().zip(actual.iter()) {
+        let d_e = e.testable_as_f64() - mean_e;
+        let d_a = a.testable_as_f64() - mean_a;
+
+        covariance += d_e * d_a;
+        variance_e += d_e * d_e;
+        variance_a += d_a * d_a;
+    }
+
+    if 0.0 == variance_e || 0.0 == variance_a {
+        return Err(CorrelationError::ZeroVariance {
+            expected_is_constant : 0.0 == variance_e,
+            actual_is_constant :   0.0 == variance_a,
+        });
+    }
+
+    // clamp, since rounding may take a perfect correlation just beyond 1
+    Ok((covariance / (variance_e * variance_a).sqrt()).clamp(-1.0, 1.0))
+}
+
+/// Evaluates whether a sampled time-series is approximately periodic with
+/// the given `period_in_samples`, by comparing, according to `evaluator`,
+/// each sample `samples[i]` with the sample one period later,
+/// `samples[i + period_in_samples]`, for as long as the latter lies within
+/// the series, identifying the first sample that diverges.
+///
+/// A period that is not an integral number of samples is supported, the
+/// sample one period later being obtained in accordance with
+/// `interpolation`.
+pub fn evaluate_periodic_approx<T_samples, T_element>(
+    samples : &T_samples,
+    period_in_samples : f64,
+    evaluator : &dyn traits::ApproximateEqualityEvaluator,
+    interpolation : PeriodInterpolation,
+) -> (
+    PeriodicComparisonResult, // comparison_result
+    Option<f64>,              // margin_factor
+    Option<f64>,              // multiplier_factor
+)
+where
+    T_samples : std_convert::AsRef<[T_element]>,
+    T_element : traits::TestableAsF64 + std_fmt::Debug,
+{
+    debug_assert!(
+        period_in_samples > 0.0 && period_in_samples.is_finite(),
+        "`period_in_samples` must be positive and finite, but {period_in_samples} given"
+    );
+
+    let samples = samples.as_ref();
+    let length = samples.len();
+
+    let sample_at = |position : f64| -> Option<f64> {
+        let lower = position.floor();
+        let fraction = position - lower;
+        let lower = lower as usize;
+
+        if 0.0 == fraction {
+            return samples.get(lower).map(|v| v.testable_as_f64());
+        }
+
+        match interpolation {
+            PeriodInterpolation::Linear => {
+                let lower_value = samples.get(lower)?.testable_as_f64();
+                let upper_value = samples.get(lower + 1)?.testable_as_f64();
+
+                Some(lower_value + (upper_value - lower_value) * fraction)
+            },
+            PeriodInterpolation::Nearest => samples.get(position.round() as usize).map(|v| v.testable_as_f64()),
+        }
+    };
+
+    // the samples are compared for as long as one lies a period later, so
+    // none is compared if the first does not
+    if 0 == length || sample_at(period_in_samples).is_none() {
+        return (
+            PeriodicComparisonResult::InsufficientSamples {
+                length,
+                period : period_in_samples,
+            },
+            None,
+            None,
+        );
+    }
+
+    let triples = samples.iter().enumerate().map_while(|(index, sample)| {
+        sample_at(index as f64 + period_in_samples).map(|period_value| (index, sample.testable_as_f64(), period_value))
+    });
+
+    evaluate_f64_sequence_eq_approx_(triples, evaluator, None).finish(
+        PeriodicComparisonResult::ExactlyEqual,
+        PeriodicComparisonResult::ApproximatelyEqual,
+        |index, value, period_value| {
+            PeriodicComparisonResult::UnequalSamples {
+                index,
+                value,
+                period_value,
+            }
+        },
+    )
+}
+
+/// Enables (or disables) a warning, written to the standard error stream,
+/// when [`assert_vector_eq_approx!()`] is given the same slice - i.e. the
+/// same memory - as both expected and actual, as when a test accidentally
+/// compares a variable with itself, returning the previous setting. Such a
+/// comparison cannot fail, and so may hide a real defect.
+///
+/// NOTE: the setting applies to the whole process, and is disabled by
+/// default.
+pub fn set_warn_on_self_comparison(enabled : bool) -> bool {
+    utils::set_warn_on_self_comparison_(enabled)
+}
+
+/// Indicates whether `result` is a pass for an assertion of equality, if
+/// `expect_equal` is `true` - i.e. the comparands are exactly or
+/// approximately equal, or both NaN (when that is deemed equal) - or of
+/// inequality, if `expect_equal` is `false` - i.e. they are unequal. This is
+/// the decision made by both [`assert_scalar_eq_approx!()`] and
+/// [`assert_scalar_ne_approx!()`], and may be used to compose custom
+/// assertions.
+pub fn result_passes(
+    result : &ComparisonResult,
+    expect_equal : bool,
+) -> bool {
+    if expect_equal {
+        result.passed()
+    } else {
+        result.is_unequal()
+    }
+}
+
+/// Installs the given metrics sink, to which every subsequent comparison
+/// performed by the scalar assertion macros, by
+/// [`evaluate_scalar_eq_approx()`] (and, thereby, by the vector, matrix,
+/// and array functions and assertion macros), and by
+/// [`StreamingVectorComparator`] reports its `(delta, allowed_band)`,
+/// returning the previously installed sink, if any. Passing `None`
+/// uninstalls the current sink.
+///
+/// The allowed band is approximated from the factors reported by the
+/// evaluator: the margin factor when either comparand is zero or there is
+/// no multiplier factor; otherwise the multiplier factor scaled by the
+/// magnitude of the expected value. Comparisons for which no (positive,
+/// finite) band can be determined, such as those of ULP-based evaluators,
+/// or that involve a NaN or an infinity, are not reported.
+///
+/// NOTE: when no sink is installed the only cost is a single relaxed
+/// atomic load per comparison, so that the performance of suites that do
+/// not use metrics is, in practice, unaffected.
+pub fn set_metrics_sink(
+    sink : Option<std::sync::Arc<dyn traits::MetricsSink>>
+) -> Option<std::sync::Arc<dyn traits::MetricsSink>> {
+    utils::set_metrics_sink_(sink)
+}
+
+/// Sets the number of significant figures to which values are rounded in
+/// the failure messages of [`assert_scalar_eq_approx!()`],
+/// [`assert_scalar_ne_approx!()`], and [`assert_vector_eq_approx!()`],
+/// returning the previous setting. Passing `None` (the default) restores
+/// full `Debug` formatting, in which scalar comparands are shown as given.
+///
+/// This is useful when values such as `0.30000000000000004` make messages
+/// noisy; the factors, agreement, and headroom are unaffected.
+///
+/// NOTE: the setting applies to the whole process.
+pub fn set_message_precision(digits : Option<u32>) -> Option<u32> {
+    debug_assert!(digits != Some(0), "`digits` must be at least 1");
+
+    utils::set_message_precision_(digits)
+}
+
+/// Creates the [`ApproximateEqualityEvaluator`] used by the two-parameter
+/// forms of the assertion macros, which is equivalent to
+/// `zero_margin_or_multiplier(DEFAULT_MULTIPLIER, DEFAULT_MARGIN)`.
+///
+/// Either factor may be overridden by setting the environment variables
+/// named by [`constants::DEFAULT_MARGIN_ENVIRONMENT_VARIABLE`] and
+/// [`constants::DEFAULT_MULTIPLIER_ENVIRONMENT_VARIABLE`] to a non-negative
+/// value; unset or unparsable values are ignored. The environment is read
+/// once, at first use, and the result cached for the life of the process.
+///
+/// NOTE: evaluators passed explicitly to the three-parameter forms of the
+/// assertion macros are never overridden.
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn default_evaluator() -> evaluators::ZeroMarginOrMultiplierEvaluator {
+    let (zero_margin_factor, multiplier_factor) =
+        utils::default_factors((constants::DEFAULT_MARGIN, constants::DEFAULT_MULTIPLIER));
+
+    evaluators::ZeroMarginOrMultiplierEvaluator {
+        multiplier_factor,
+        zero_margin_factor,
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that operates by applying
+/// the given `margin_factor` as a margin to the logarithms, to the given
+/// `base`, of the comparands, which suits quantities that are naturally
+/// compared in log space, such as frequencies and magnitudes.
+///
+/// NOTE: since the logarithm of a non-positive value is undefined, any
+/// non-positive comparand (that is not exactly equal to the other) is
+/// deemed unequal, and so described in the failure message.
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn log_scale(
+    base : f64,
+    margin_factor : f64,
+) -> evaluators::LogScaleEvaluator {
+    evaluators::LogScaleEvaluator {
+        base,
+        margin_factor,
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that operates by applying
+/// the given `factor` as a margin to determine approximate equality.
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn margin(factor : f64) -> evaluators::MarginEvaluator {
+    evaluators::MarginEvaluator {
+        factor,
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that applies a margin
+/// derived from the condition number of a computation, in accordance with
+/// the standard numerical-analysis heuristic that the error of, say, a
+/// linear solve is bounded by the working precision scaled by the condition
+/// number of the problem, i.e. the margin is:
+///
+/// ```text
+/// base_eps * condition_number
+/// ```
+///
+/// as in `margin_from_condition(f64::EPSILON, 1e6)` for "accurate to
+/// machine epsilon times the matrix condition number".
+///
+/// NOTE: the heuristic bounds the *relative* error, so the margin is
+/// appropriate for results of order unity; for others,
+/// `multiplier(base_eps * condition_number)` may be preferred.
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn margin_from_condition(
+    base_eps : f64,
+    condition_number : f64,
+) -> evaluators::MarginEvaluator {
+    debug_assert!(base_eps >= 0.0, "`base_eps` must not be negative, but {base_eps} given");
+    debug_assert!(
+        condition_number >= 1.0,
+        "`condition_number` must not be less than 1, but {condition_number} given"
+    );
+
+    margin(base_eps * condition_number)
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that operates by applying
+/// the given `factor` as a multiplier to determine approximate equality.
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn multiplier(factor : f64) -> evaluators::MultiplierEvaluator {
+    evaluators::MultiplierEvaluator {
+        factor,
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that operates by applying
+/// the given `multiplier_factor` as a multiplier to determine approximate
+/// equality in all cases except when or both comparands is zero, in which
+/// case it applies the `zero_margin_factor` as a margin to determine
+/// approximate equality.
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn zero_margin_or_multiplier(
+    multiplier_factor : f64,
+    zero_margin_factor : f64,
+) -> evaluators::ZeroMarginOrMultiplierEvaluator {
+    evaluators::ZeroMarginOrMultiplierEvaluator {
+        multiplier_factor,
+        zero_margin_factor,
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that operates by accepting
+/// actual values in the closed interval `[expected - lower_factor, expected
+/// + upper_factor]`.
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn asymmetric_margin(
+    lower_factor : f64,
+    upper_factor : f64,
+) -> evaluators::AsymmetricMarginEvaluator {
+    evaluators::AsymmetricMarginEvaluator {
+        lower_factor,
+        upper_factor,
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that operates by applying
+/// the given `multiplier_factor` as a multiplier of the geometric mean of
+/// the comparands' magnitudes - `sqrt(|expected * actual|)` - to determine
+/// approximate equality. This is symmetric in its comparands, which makes
+/// it suitable for ratio-like quantities.
+///
+/// NOTE: when either (but not both) comparand is zero the comparands are
+/// deemed unequal; see [`zero_margin_or_relative_geomean()`].
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn relative_geomean(multiplier_factor : f64) -> evaluators::RelativeGeomeanEvaluator {
+    evaluators::RelativeGeomeanEvaluator {
+        multiplier_factor,
+        zero_margin_factor : None,
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that behaves as that
+/// created by [`relative_geomean()`] except when either comparand is zero,
+/// in which case it applies the `zero_margin_factor` as a margin to
+/// determine approximate equality.
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn zero_margin_or_relative_geomean(
+    multiplier_factor : f64,
+    zero_margin_factor : f64,
+) -> evaluators::RelativeGeomeanEvaluator {
+    evaluators::RelativeGeomeanEvaluator {
+        multiplier_factor,
+        zero_margin_factor : Some(zero_margin_factor),
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that operates by applying
+/// the given `fraction` of the full-scale `range`, i.e. `range * fraction`,
+/// as a margin to determine approximate equality, irrespective of the
+/// magnitude of the comparands. This suits instrumentation tolerances such
+/// as "0.1% of full scale".
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn full_scale(
+    range : f64,
+    fraction : f64,
+) -> evaluators::FullScaleEvaluator {
+    evaluators::FullScaleEvaluator {
+        range,
+        fraction,
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that operates by applying
+/// the given relative tolerance, expressed in parts per million, as a
+/// multiplier, i.e. it is equivalent to `multiplier(parts_per_million / 1e6)`
+/// except that failure messages state the measured error and the tolerance
+/// in ppm, as in "error 3.200 ppm exceeds 1.000 ppm" (or "is within", for
+/// a failed inequality). This suits specifications such as oscillator and
+/// clock accuracies.
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn ppm(parts_per_million : f64) -> evaluators::PpmEvaluator {
+    evaluators::PpmEvaluator {
+        parts_per_million,
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that reproduces exactly the
+/// test of numpy's `isclose(a, b, rtol, atol)`, namely
+/// `|a - b| <= atol + rtol * |b|`, where `a` is the expected and `b` the
+/// actual comparand. This eases porting of tests from Python.
+///
+/// Note that, unlike [`zero_margin_or_multiplier()`] - which applies either
+/// its margin (when a comparand is zero) or its multiplier (relative to the
+/// expected value), but never both - the two tolerances are summed, and the
+/// relative tolerance is scaled by the actual comparand only, so that the
+/// test is not symmetric. numpy's defaults are `rtol=1e-5` and `atol=1e-8`.
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn numpy_close(
+    rtol : f64,
+    atol : f64,
+) -> evaluators::NumpyCloseEvaluator {
+    evaluators::NumpyCloseEvaluator {
+        relative_tolerance : rtol,
+        absolute_tolerance : atol,
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that deems comparands
+/// approximately equal if they are the same when rounded to `figures`
+/// significant (decimal) figures, using [`RoundingMode::HalfEven`].
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn significant_figures(figures : u32) -> evaluators::SignificantFiguresEvaluator {
+    significant_figures_with_rounding_mode(figures, RoundingMode::default())
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that behaves as that
+/// created by [`significant_figures()`] except that it rounds in
+/// accordance with the given `rounding_mode`, so that the comparison may
+/// be made consistent with the system under test.
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn significant_figures_with_rounding_mode(
+    figures : u32,
+    rounding_mode : RoundingMode,
+) -> evaluators::SignificantFiguresEvaluator {
+    evaluators::SignificantFiguresEvaluator {
+        figures,
+        rounding_mode,
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that operates by accepting
+/// comparands that are at most `max_ulps` units in the last place (ULPs)
+/// apart (0 meaning bit-identical, ±0 aside).
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn ulps(max_ulps : u64) -> evaluators::UlpsEvaluator {
+    evaluators::UlpsEvaluator {
+        max_ulps,
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that delegates to the given
+/// `inner` evaluator except when either comparand is NaN, in which case the
+/// comparison is deemed unequal (and described as "comparand is NaN"),
+/// irrespective of the `"nan-equality"` feature. This is useful for
+/// detecting accidental production of NaN values.
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn nan_strict<E>(inner : E) -> evaluators::NanStrictEvaluator<E>
+where
+    E : traits::ApproximateEqualityEvaluator,
+{
+    evaluators::NanStrictEvaluator {
+        inner,
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that delegates to the given
+/// `inner` evaluator except that a comparison of `+0.0` with `-0.0` (or
+/// vice versa), which would otherwise be deemed exactly equal, is
+/// downgraded to `ComparisonResult::ApproximatelyEqual`. The assertions
+/// still pass, but programmatic callers can detect the sign change.
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn signed_zero_as_approx<E>(inner : E) -> evaluators::SignedZeroAsApproxEvaluator<E>
+where
+    E : traits::ApproximateEqualityEvaluator,
+{
+    evaluators::SignedZeroAsApproxEvaluator {
+        inner,
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that flushes any subnormal
+/// (denormal) comparand to zero (of the same sign) before delegating to the
+/// given `inner` evaluator, matching the behaviour of flush-to-zero (FTZ)
+/// hardware modes. This avoids the surprising results that relative
+/// tolerances can give for subnormal comparands, which are then compared
+/// as zero (and so, e.g., subject to the zero-margin of a
+/// zero-margin-or-multiplier evaluator).
+///
+/// NOTE: only the comparands are flushed; the factors of `inner` are
+/// unaffected.
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn flush_subnormals_to_zero<E>(inner : E) -> evaluators::FlushSubnormalsEvaluator<E>
+where
+    E : traits::ApproximateEqualityEvaluator,
+{
+    evaluators::FlushSubnormalsEvaluator {
+        inner,
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that deems any two
+/// comparands whose magnitudes are both no greater than `floor` to be
+/// approximately equal, regardless of their relative difference, and
+/// otherwise delegates to the given `inner` evaluator. Unlike a margin, it
+/// only takes effect when *both* comparands are within the noise band.
+///
+/// NOTE: a NaN comparand is never within the noise band.
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn noise_floor<E>(
+    floor : f64,
+    inner : E,
+) -> evaluators::NoiseFloorEvaluator<E>
+where
+    E : traits::ApproximateEqualityEvaluator,
+{
+    debug_assert!(floor >= 0.0, "`floor` must not be negative, but {floor} given");
+
+    evaluators::NoiseFloorEvaluator {
+        floor,
+        inner,
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that deems any two
+/// comparands of the same sign whose magnitudes both exceed `limit` to be
+/// approximately equal, regardless of their difference, and otherwise
+/// delegates to the given `inner` evaluator. This suits clamped or
+/// saturating hardware, whose out-of-range readings are not meaningfully
+/// comparable.
+///
+/// NOTE: a NaN comparand is never saturated.
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn saturating<E>(
+    limit : f64,
+    inner : E,
+) -> evaluators::SaturatingEvaluator<E>
+where
+    E : traits::ApproximateEqualityEvaluator,
+{
+    debug_assert!(limit >= 0.0, "`limit` must not be negative, but {limit} given");
+
+    evaluators::SaturatingEvaluator {
+        limit,
+        inner,
+    }
+}
+
+/// Creates an [`ApproximateEqualityEvaluator`] that dispatches to the
+/// `positive` evaluator when the expected value is positive and to the
+/// `negative` evaluator when it is negative, as for instruments with
+/// asymmetric error characteristics. An expected value of zero is
+/// dispatched to the `positive` evaluator.
+///
+/// NOTE: a NaN expected value is dispatched to the `positive` evaluator.
+///
+/// [`ApproximateEqualityEvaluator`]: crate::traits::ApproximateEqualityEvaluator
+pub fn sign_dependent<E_positive, E_negative>(
+    positive : E_positive,
+    negative : E_negative,
+) -> evaluators::SignDependentEvaluator<E_positive, E_negative>
+where
+    E_positive : traits::ApproximateEqualityEvaluator,
+    E_negative : traits::ApproximateEqualityEvaluator,
+{
+    sign_dependent_with_zero_dispatch(positive, negative, ZeroDispatch::Positive)
+}
+
+/// As [`sign_dependent()`], but dispatching an expected value of zero in
+/// accordance with the given [`ZeroDispatch`].
+pub fn sign_dependent_with_zero_dispatch<E_positive, E_negative>(
+    positive : E_positive,
+    negative : E_negative,
+    zero_dispatch : ZeroDispatch,
+) -> evaluators::SignDependentEvaluator<E_positive, E_negative>
+where
+    E_positive : traits::ApproximateEqualityEvaluator,
+    E_negative : traits::ApproximateEqualityEvaluator,
+{
+    evaluators::SignDependentEvaluator {
+        positive,
+        negative,
+        zero_dispatch,
+    }
+}
+
+
+/// The modes recognised by [`parse_evaluator()`].
+const PARSE_EVALUATOR_MODES_ : &[&str] = &[
+    "default",
+    "asymmetric_margin",
+    "full_scale",
+    "log_scale",
+    "margin",
+    "margin_from_condition",
+    "multiplier",
+    "numpy_close",
+    "ppm",
+    "relative_geomean",
+    "significant_figures",
+    "significant_figures_with_rounding_mode",
+    "ulps",
+    "zero_margin_or_multiplier",
+    "zero_margin_or_relative_geomean",
+];
+
+/// Creates an evaluator from the given textual `spec`, as may be read from
+/// the configuration of a test rig, in accordance with the grammar:
+///
+/// ```text
+/// spec      := mode [ ":" arguments ]
+/// arguments := argument { "," argument }
+/// ```
+///
+/// where whitespace around each `mode` and `argument` is ignored, and
+/// `mode`, and the arguments it requires, is one of:
+///
+/// - `default` - (none) - as [`default_evaluator()`];
+/// - `asymmetric_margin` - `lower_factor,upper_factor`;
+/// - `full_scale` - `range,fraction`;
+/// - `log_scale` - `base,margin_factor`;
+/// - `margin` - `factor`;
+/// - `margin_from_condition` - `base_eps,condition_number`;
+/// - `multiplier` - `factor`;
+/// - `numpy_close` - `rtol,atol`;
+/// - `ppm` - `parts_per_million`;
+/// - `relative_geomean` - `multiplier_factor`;
+/// - `significant_figures` - `figures` (an unsigned integer);
+/// - `significant_figures_with_rounding_mode` - `figures,rounding_mode`,
+///   where `rounding_mode` is one of `half_even`, `half_up`, and
+///   `toward_zero`;
+/// - `ulps` - `max_ulps` (an unsigned integer);
+/// - `zero_margin_or_multiplier` - `multiplier_factor,zero_margin_factor`;
+/// - `zero_margin_or_relative_geomean` -
+///   `multiplier_factor,zero_margin_factor`;
+///
+/// each corresponding to the function of the same name, as in
+/// `"margin:1e-6"`, `"multiplier:0.001"`, or `"ulps:4"`. Other arguments
+/// are parsed as `f64`, and must be finite and non-negative; further, a
+/// `full_scale` range must be positive, a `log_scale` base must be positive
+/// and not 1, a `condition_number` must be at least 1, and `figures` must
+/// be at least 1. An argument that does not meet these is reported as
+/// [`ParseError::InvalidArgument`].
+///
+/// NOTE: the wrapping evaluators, such as [`nan_strict()`], are not
+/// supported.
+pub fn parse_evaluator(spec : &str) -> Result<Box<dyn traits::ApproximateEqualityEvaluator>, ParseError> {
+    use traits::ApproximateEqualityEvaluator as _;
+
+    let (mode, arguments) = match spec.split_once(':') {
+        Some((mode, arguments)) => (mode.trim(), arguments.split(',').map(str::trim).collect::<Vec<_>>()),
+        None => (spec.trim(), Vec::new()),
+    };
+
+    if mode.is_empty() && arguments.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    if !PARSE_EVALUATOR_MODES_.contains(&mode) {
+        return Err(ParseError::UnknownMode {
+            mode : mode.into()
+        });
+    }
+
+    let expected = match mode {
+        "default" => 0,
+        "asymmetric_margin"
+        | "full_scale"
+        | "log_scale"
+        | "margin_from_condition"
+        | "numpy_close"
+        | "significant_figures_with_rounding_mode"
+        | "zero_margin_or_multiplier"
+        | "zero_margin_or_relative_geomean" => 2,
+        _ => 1,
+    };
+
+    if arguments.len() != expected {
+        return Err(ParseError::WrongArgumentCount {
+            mode : mode.into(),
+            expected,
+            actual : arguments.len(),
+        });
+    }
+
+    fn parse_argument_<T : std::str::FromStr>(
+        mode : &str,
+        argument : &str,
+    ) -> Result<T, ParseError> {
+        argument.parse::<T>().map_err(|_| {
+            ParseError::InvalidArgument {
+                mode :     mode.into(),
+                argument : argument.into(),
+            }
+        })
+    }
+
+    // each argument is validated, so that the evaluator created cannot
+    // fail the debug assertions of its factory function
+    fn validate_argument_<T : std::str::FromStr + Copy>(
+        mode : &str,
+        argument : &str,
+        is_valid : fn(T) -> bool,
+    ) -> Result<T, ParseError> {
+        let value = parse_argument_::<T>(mode, argument)?;
+
+        if is_valid(value) {
+            Ok(value)
+        } else {
+            Err(ParseError::InvalidArgument {
+                mode :     mode.into(),
+                argument : argument.into(),
+            })
+        }
+    }
+
+    let factor = |index : usize| validate_argument_::<f64>(mode, arguments[index], |v| v.is_finite() && v >= 0.0);
+    let positive = |index : usize| validate_argument_::<f64>(mode, arguments[index], |v| v.is_finite() && v > 0.0);
+    let base =
+        |index : usize| validate_argument_::<f64>(mode, arguments[index], |v| v.is_finite() && v > 0.0 && v != 1.0);
+    let figures = |index : usize| validate_argument_::<u32>(mode, arguments[index], |v| v >= 1);
+
+    let evaluator = match mode {
+        "default" => default_evaluator().boxed(),
+        "asymmetric_margin" => asymmetric_margin(factor(0)?, factor(1)?).boxed(),
+        "full_scale" => full_scale(positive(0)?, factor(1)?).boxed(),
+        "log_scale" => log_scale(base(0)?, factor(1)?).boxed(),
+        "margin" => margin(factor(0)?).boxed(),
+        "margin_from_condition" => {
+            let condition_number = validate_argument_::<f64>(mode, arguments[1], |v| v.is_finite() && v >= 1.0)?;
+
+            margin_from_condition(factor(0)?, condition_number).boxed()
+        },
+        "multiplier" => multiplier(factor(0)?).boxed(),
+        "numpy_close" => numpy_close(factor(0)?, factor(1)?).boxed(),
+        "ppm" => ppm(factor(0)?).boxed(),
+        "relative_geomean" => relative_geomean(factor(0)?).boxed(),
+        "significant_figures" => significant_figures(figures(0)?).boxed(),
+        "significant_figures_with_rounding_mode" => {
+            let rounding_mode = match arguments[1] {
+                "half_even" => RoundingMode::HalfEven,
+                "half_up" => RoundingMode::HalfUp,
+                "toward_zero" => RoundingMode::TowardZero,
+                argument => {
+                    return Err(ParseError::InvalidArgument {
+                        mode :     mode.into(),
+                        argument : argument.into(),
+                    })
+                },
+            };
+
+            significant_figures_with_rounding_mode(figures(0)?, rounding_mode).boxed()
+        },
+        "ulps" => ulps(parse_argument_::<u64>(mode, arguments[0])?).boxed(),
+        "zero_margin_or_multiplier" => zero_margin_or_multiplier(factor(0)?, factor(1)?).boxed(),
+        "zero_margin_or_relative_geomean" => zero_margin_or_relative_geomean(factor(0)?, factor(1)?).boxed(),
+        _ => unreachable!(),
+    };
+
+    Ok(evaluator)
+}
+
+
+// /////////////////////////////////////////////////////////
+// macros
+
+/// Converts the given comparand reference - which, if labeled `expected`,
+/// may also be of a type that implements [`traits::CarriesTolerance`] - to
+/// `f64`, as a `Result<f64, String>`, via [`utils::TestableProbe`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! try_testable_as_f64_ {
+    (expected = $comparand:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::utils::{
+            TestableViaCarriedTolerance as _,
+            TestableViaDeref as _,
+            TestableViaDerefDeref as _,
+            TestableViaExtended as _,
+            TestableViaInto as _,
+            TestableViaTry as _,
+        };
+
+        (&&&&&&$crate::utils::TestableProbe::of_expected($comparand)).probe_as_f64()
+    }};
+    ($comparand:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::utils::{
+            TestableViaDeref as _,
+            TestableViaDerefDeref as _,
+            TestableViaExtended as _,
+            TestableViaInto as _,
+            TestableViaTry as _,
+        };
+
+        (&&&&&$crate::utils::TestableProbe::of($comparand)).probe_as_f64()
+    }};
+}
+
+// NOTE: skipped by rustfmt, which otherwise removes the space following
+// the comma in each labeled matcher; it must therefore not be invoked by
+// `crate::` path from within this crate
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! assert_scalar_eq_approx {
+    // labeled forms, which may be given in either order
+    (expected = $expected:expr, actual = $actual:expr, $evaluator:expr $(,)?) => {
+        assert_scalar_eq_approx!($expected, $actual, $evaluator);
+    };
+    (expected = $expected:expr, actual = $actual:expr $(,)?) => {
+        assert_scalar_eq_approx!($expected, $actual);
+    };
+    (actual = $actual:expr, expected = $expected:expr, $evaluator:expr $(,)?) => {
+        assert_scalar_eq_approx!($expected, $actual, $evaluator);
+    };
+    (actual = $actual:expr, expected = $expected:expr $(,)?) => {
+        assert_scalar_eq_approx!($expected, $actual);
+    };
+    ($expected:expr, $actual:expr, $evaluator:expr $(,)?) => {
+        let expected_param = &$expected;
+        let actual_param = &$actual;
+
+        let (expected, actual) = {
+            let expected = match $crate::try_testable_as_f64_!(expected = expected_param) {
+                Ok(expected) => expected,
+                Err(reason) => panic!("assertion failed: failed to verify approximate equality: {reason}"),
+            };
+            let actual = match $crate::try_testable_as_f64_!(actual_param) {
+                Ok(actual) => actual,
+                Err(reason) => panic!("assertion failed: failed to verify approximate equality: {reason}"),
+            };
+
+            (expected, actual)
+        };
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        let (comparison_result, diagnostic) =
+            $crate::utils::evaluate_and_describe_f64(&expected_param, &actual_param, expected, actual, evaluator);
+
+        if !$crate::result_passes(&comparison_result, true) {
+            assert!(false, "{diagnostic}");
+        }
+    };
+    ($expected:expr, $actual:expr $(,)?) => {
+        let expected_param = &$expected;
+        let evaluator = {
+            #[allow(unused_imports)]
+            use $crate::utils::{
+                DefaultToleranceViaFallback as _,
+                DefaultToleranceViaType as _,
+                ToleranceViaNone as _,
+                ToleranceViaValue as _,
+            };
+
+            (&$crate::utils::ToleranceProbe::of(expected_param))
+                .carried_tolerance()
+                .unwrap_or_else(|| {
+                    (&$crate::utils::DefaultToleranceProbe::of(expected_param)).default_tolerance_evaluator()
+                })
+        };
+
+        assert_scalar_eq_approx!(*expected_param, $actual, evaluator);
+    };
+}
+
+// NOTE: skipped by rustfmt, which otherwise removes the space following
+// the comma in each labeled matcher; it must therefore not be invoked by
+// `crate::` path from within this crate
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! assert_scalar_ne_approx {
+    // labeled forms, which may be given in either order
+    (expected = $expected:expr, actual = $actual:expr, $evaluator:expr $(,)?) => {
+        assert_scalar_ne_approx!($expected, $actual, $evaluator);
+    };
+    (expected = $expected:expr, actual = $actual:expr $(,)?) => {
+        assert_scalar_ne_approx!($expected, $actual);
+    };
+    (actual = $actual:expr, expected = $expected:expr, $evaluator:expr $(,)?) => {
+        assert_scalar_ne_approx!($expected, $actual, $evaluator);
+    };
+    (actual = $actual:expr, expected = $expected:expr $(,)?) => {
+        assert_scalar_ne_approx!($expected, $actual);
+    };
+    ($expected:expr, $actual:expr, $evaluator:expr $(,)?) => {
+        let expected_param = &$expected;
+        let actual_param = &$actual;
+
+        let (expected, actual) = {
+            let expected = match $crate::try_testable_as_f64_!(expected = expected_param) {
+                Ok(expected) => expected,
+                Err(reason) => panic!("assertion failed: failed to verify approximate inequality: {reason}"),
+            };
+            let actual = match $crate::try_testable_as_f64_!(actual_param) {
+                Ok(actual) => actual,
+                Err(reason) => panic!("assertion failed: failed to verify approximate inequality: {reason}"),
+            };
+
+            (expected, actual)
+        };
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        let (comparison_result, margin_factor, multiplier_factor) = evaluator.evaluate(expected, actual);
+
+        $crate::utils::report_comparison_metrics(expected, actual, margin_factor, multiplier_factor);
+
+        if !$crate::result_passes(&comparison_result, false) {
+            let description = $crate::utils::describe_evaluation(evaluator, expected, actual)
+                .map(|d| format!(": {d}"))
+                .unwrap_or_default();
+            let expected_param = $crate::utils::format_message_param(&expected_param, expected);
+            let actual_param = $crate::utils::format_message_param(&actual_param, actual);
+
+            match margin_factor {
+                Some(margin_factor) => {
+                    match multiplier_factor {
+                        Some(multiplier_factor) => {
+                            assert!(
+                                false,
+                                "assertion failed: failed to verify approximate inequality: expected={expected_param}, actual={actual_param}, margin_factor={margin_factor}, multiplier_factor={multiplier_factor}{description}",
+                            );
+                        },
+                        None => {
+                            assert!(
+                                false,
+                                "assertion failed: failed to verify approximate inequality: expected={expected_param}, actual={actual_param}, margin_factor={margin_factor}{description}",
+                            );
+                        },
+                    };
+                },
+                None => {
+                    match multiplier_factor {
+                        Some(multiplier_factor) => {
+                            assert!(
+                                false,
+                                "assertion failed: failed to verify approximate inequality: expected={expected_param}, actual={actual_param}, multiplier_factor={multiplier_factor}{description}",
+                            );
+                        },
+                        // evaluators such as `ulps()` report neither factor
+                        None => {
+                            assert!(
+                                false,
+                                "assertion failed: failed to verify approximate inequality: expected={expected_param}, actual={actual_param}{description}",
+                            );
+                        },
+                    };
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr $(,)?) => {
+        let expected_param = &$expected;
+        let evaluator = {
+            #[allow(unused_imports)]
+            use $crate::utils::{
+                DefaultToleranceViaFallback as _,
+                DefaultToleranceViaType as _,
+                ToleranceViaNone as _,
+                ToleranceViaValue as _,
+            };
+
+            (&$crate::utils::ToleranceProbe::of(expected_param))
+                .carried_tolerance()
+                .unwrap_or_else(|| {
+                    (&$crate::utils::DefaultToleranceProbe::of(expected_param)).default_tolerance_evaluator()
+                })
+        };
+
+        assert_scalar_ne_approx!(*expected_param, $actual, evaluator);
+    };
+}
+
+// NOTE: skipped by rustfmt, which otherwise removes the space following
+// the comma in each labeled matcher; it must therefore not be invoked by
+// `crate::` path from within this crate
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! assert_vector_eq_approx {
+    // labeled forms, which may be given in either order
+    (expected = $expected:expr, actual = $actual:expr, $evaluator:expr $(,)?) => {
+        assert_vector_eq_approx!($expected, $actual, $evaluator);
+    };
+    (expected = $expected:expr, actual = $actual:expr $(,)?) => {
+        assert_vector_eq_approx!($expected, $actual);
+    };
+    (actual = $actual:expr, expected = $expected:expr, $evaluator:expr $(,)?) => {
+        assert_vector_eq_approx!($expected, $actual, $evaluator);
+    };
+    (actual = $actual:expr, expected = $expected:expr $(,)?) => {
+        assert_vector_eq_approx!($expected, $actual);
+    };
+    ($expected:expr, $actual:expr, $evaluator:expr $(,)?) => {
+        /*
+        let expected_param = &$expected;
+        let actual_param = &$actual;
+         */
+        let expected = &$expected;
+        let actual = &$actual;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::utils::AsElementSlice as _;
+            use $crate::VectorComparisonResult as CR;
+
+            // view comparands, such as `Arc<Vec<f64>>`, that are vectors
+            // only via `Deref` as slices
+            let expected = expected.as_element_slice();
+            let actual = actual.as_element_slice();
+
+            $crate::utils::warn_if_self_comparison("assert_vector_eq_approx!()", expected, actual);
+
+            let (comparison_result, margin_factor, multiplier_factor) = $crate::evaluate_vector_eq_approx(&expected, &actual, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::DifferentLengths {
+                    expected_length,
+                    actual_length,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for vectors: expected-length {expected_length} differs from actual-length {actual_length}",
+                    );
+                },
+                CR::UnequalElements {
+                    index_of_first_unequal_element,
+                    expected_value_of_first_unequal_element,
+                    actual_value_of_first_unequal_element,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(
+                            evaluator,
+                            expected_value_of_first_unequal_element,
+                            actual_value_of_first_unequal_element,
+                        ),
+                    );
+                    let expected_value = $crate::utils::format_message_value(expected_value_of_first_unequal_element);
+                    let actual_value = $crate::utils::format_message_value(actual_value_of_first_unequal_element);
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for vectors: at index {index_of_first_unequal_element} expected={expected_value}, actual={actual_value}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr $(,)?) => {
+        let expected_param = &$expected;
+        let expected_param = {
+            use $crate::utils::AsElementSlice as _;
+
+            expected_param.as_element_slice()
+        };
+        let evaluator = {
+            #[allow(unused_imports)]
+            use $crate::utils::{
+                DefaultToleranceViaFallback as _,
+                DefaultToleranceViaType as _,
+            };
+
+            (&$crate::utils::DefaultToleranceProbe::of_elements(expected_param)).default_tolerance_evaluator()
+        };
+
+        assert_vector_eq_approx!(expected_param, $actual, evaluator);
+    };
+}
+
+// NOTE: skipped by rustfmt, which otherwise removes the space following
+// the comma in each labeled matcher; it must therefore not be invoked by
+// `crate::` path from within this crate
+#[macro_export]
+#[rustfmt::skip]
+macro_rules! assert_vector_ne_approx {
+    // labeled forms, which may be given in either order
+    (expected = $expected:expr, actual = $actual:expr, $evaluator:expr $(,)?) => {
+        assert_vector_ne_approx!($expected, $actual, $evaluator);
+    };
+    (expected = $expected:expr, actual = $actual:expr $(,)?) => {
+        assert_vector_ne_approx!($expected, $actual);
+    };
+    (actual = $actual:expr, expected = $expected:expr, $evaluator:expr $(,)?) => {
+        assert_vector_ne_approx!($expected, $actual, $evaluator);
+    };
+    (actual = $actual:expr, expected = $expected:expr $(,)?) => {
+        assert_vector_ne_approx!($expected, $actual);
+    };
+    ($expected:expr, $actual:expr, $evaluator:expr $(,)?) => {
+        /*
+        let expected_param = &$expected;
+        let actual_param = &$actual;
+         */
+        let expected = &$expected;
+        let actual = &$actual;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::{
+                utils::AsElementSlice as _,
+                VectorComparisonResult as CR,
+            };
+
+            // view comparands, such as `Arc<Vec<f64>>`, that are vectors
+            // only via `Deref` as slices
+            let expected = expected.as_element_slice();
+            let actual = actual.as_element_slice();
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_vector_eq_approx(&expected, &actual, evaluator);
+
+            match comparison_result {
+                CR::DifferentLengths {
+                    ..
+                }
+                | CR::UnequalElements {
+                    ..
+                } => (),
+                CR::ExactlyEqual | CR::ApproximatelyEqual => {
+                    let suffix = $crate::utils::format_evaluation_suffix(margin_factor, multiplier_factor, None);
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate inequality for vectors{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr $(,)?) => {
+        let expected_param = &$expected;
+        let expected_param = {
+            use $crate::utils::AsElementSlice as _;
+
+            expected_param.as_element_slice()
+        };
+        let evaluator = {
+            #[allow(unused_imports)]
+            use $crate::utils::{
+                DefaultToleranceViaFallback as _,
+                DefaultToleranceViaType as _,
+            };
+
+            (&$crate::utils::DefaultToleranceProbe::of_elements(expected_param)).default_tolerance_evaluator()
+        };
+
+        assert_vector_ne_approx!(expected_param, $actual, evaluator);
+    };
+}
+
+/// Implements [`assert_angles_complementary_approx!`] and
+/// [`assert_angles_supplementary_approx!`], comparing the sum of the two
+/// angles with the angle obtained by the given [`AngleUnit`] method.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! assert_angles_sum_approx_ {
+    ($relation:literal, $target_angle:ident, $a:expr, $b:expr, $evaluator:expr, $unit:expr) => {
+        let a_param = &$a;
+        let b_param = &$b;
+        let relation = $relation;
+
+        let (a, b) = {
+            let a = match $crate::try_testable_as_f64_!(a_param) {
+                Ok(a) => a,
+                Err(reason) => panic!("assertion failed: failed to verify {relation} angles: {reason}"),
+            };
+            let b = match $crate::try_testable_as_f64_!(b_param) {
+                Ok(b) => b,
+                Err(reason) => panic!("assertion failed: failed to verify {relation} angles: {reason}"),
+            };
+
+            (a, b)
+        };
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+        let unit : $crate::AngleUnit = $unit;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::ComparisonResult as CR;
+
+            let sum = a + b;
+            let expected_sum = unit.$target_angle();
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_scalar_eq_approx(&expected_sum, &sum, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::BothNaN | CR::ApproximatelyEqual => (),
+                CR::Unequal => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(evaluator, expected_sum, sum),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify {relation} angles: a={a_param:?}, b={b_param:?}, sum={sum:?}, expected-sum={expected_sum:?} ({unit:?}){suffix}",
+                    );
+                },
+            };
+        }
+    };
+}
+
+/// Asserts that two angles are approximately complementary, i.e. that their sum
+/// is approximately a right angle, with an optional evaluator and an optional
+/// [`AngleUnit`] (which defaults to `AngleUnit::Degrees`), reporting the
+/// actual sum on failure. The evaluator compares the right angle with the sum.
+#[macro_export]
+macro_rules! assert_angles_complementary_approx {
+    ($a:expr, $b:expr, $evaluator:expr, $unit:expr) => {
+        $crate::assert_angles_sum_approx_!("complementary", right_angle, $a, $b, $evaluator, $unit);
+    };
+    ($a:expr, $b:expr, $evaluator:expr) => {
+        assert_angles_complementary_approx!($a, $b, $evaluator, $crate::AngleUnit::Degrees);
+    };
+    ($a:expr, $b:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_angles_complementary_approx!($a, $b, evaluator);
+    };
+}
+
+/// Asserts that two angles are approximately supplementary, i.e. that their sum
+/// is approximately a straight angle, with an optional evaluator and an optional
+/// [`AngleUnit`] (which defaults to `AngleUnit::Degrees`), reporting the
+/// actual sum on failure. The evaluator compares the straight angle with the sum.
+#[macro_export]
+macro_rules! assert_angles_supplementary_approx {
+    ($a:expr, $b:expr, $evaluator:expr, $unit:expr) => {
+        $crate::assert_angles_sum_approx_!("supplementary", straight_angle, $a, $b, $evaluator, $unit);
+    };
+    ($a:expr, $b:expr, $evaluator:expr) => {
+        assert_angles_supplementary_approx!($a, $b, $evaluator, $crate::AngleUnit::Degrees);
+    };
+    ($a:expr, $b:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_angles_supplementary_approx!($a, $b, evaluator);
+    };
+}
+
+/// Asserts that two scalars are approximately reciprocal, i.e. that their
+/// product is approximately `1.0`, with an optional evaluator, reporting the
+/// actual product on failure. The evaluator compares `1.0` with the product.
+///
+/// NOTE: fails, with a dedicated message, if either scalar is zero.
+#[macro_export]
+macro_rules! assert_scalar_reciprocal_approx {
+    ($a:expr, $b:expr, $evaluator:expr) => {
+        let a_param = &$a;
+        let b_param = &$b;
+
+        let (a, b) = {
+            let a = match $crate::try_testable_as_f64_!(a_param) {
+                Ok(a) => a,
+                Err(reason) => panic!("assertion failed: failed to verify approximate reciprocal: {reason}"),
+            };
+            let b = match $crate::try_testable_as_f64_!(b_param) {
+                Ok(b) => b,
+                Err(reason) => panic!("assertion failed: failed to verify approximate reciprocal: {reason}"),
+            };
+
+            (a, b)
+        };
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        if 0.0 == a || 0.0 == b {
+            assert!(
+                false,
+                "assertion failed: failed to verify approximate reciprocal: a={a_param:?}, b={b_param:?}: zero has no reciprocal",
+            );
+        }
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::ComparisonResult as CR;
+
+            let product = a * b;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_scalar_eq_approx(&1.0, &product, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::BothNaN | CR::ApproximatelyEqual => (),
+                CR::Unequal => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(evaluator, 1.0, product),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate reciprocal: a={a_param:?}, b={b_param:?}, product={product:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($a:expr, $b:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_scalar_reciprocal_approx!($a, $b, evaluator);
+    };
+}
+
+/// Asserts that applying a scalar function, `f`, twice to an input gives
+/// approximately the same result as applying it once, i.e. that
+/// `f(f(input))` is approximately equal to `f(input)`, as for idempotent or
+/// convergent operations, with an optional evaluator, reporting both
+/// results on failure. The evaluator compares `f(input)`, as expected, with
+/// `f(f(input))`, as actual.
+///
+/// For vector-valued functions use [`assert_vector_idempotent_approx!()`].
+#[macro_export]
+macro_rules! assert_idempotent_approx {
+    ($f:expr, $input:expr, $evaluator:expr) => {
+        let f = $f;
+        let input = $input;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        let once = f(input);
+        let twice = f(once);
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::traits::TestableAsF64 as _;
+            use $crate::ComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_scalar_eq_approx(&once, &twice, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::BothNaN | CR::ApproximatelyEqual => (),
+                CR::Unequal => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(evaluator, once.testable_as_f64(), twice.testable_as_f64()),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate idempotence: input={input:?}, once={once:?}, twice={twice:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($f:expr, $input:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_idempotent_approx!($f, $input, evaluator);
+    };
+}
+
+/// Asserts that applying a vector-valued function, `f`, that takes a slice,
+/// twice to an input gives approximately the same result as applying it
+/// once, as does [`assert_idempotent_approx!()`] for scalar functions, with
+/// an optional evaluator, reporting both results on failure.
+#[macro_export]
+macro_rules! assert_vector_idempotent_approx {
+    ($f:expr, $input:expr, $evaluator:expr) => {
+        let f = $f;
+        let input = &$input;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        let once = f(input);
+        let twice = f(&once);
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::VectorComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_vector_eq_approx(&once, &twice, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::DifferentLengths {
+                    expected_length,
+                    actual_length,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate idempotence for vectors: once={once:?}, twice={twice:?}: once-length {expected_length} differs from twice-length {actual_length}",
+                    );
+                },
+                CR::UnequalElements {
+                    index_of_first_unequal_element,
+                    expected_value_of_first_unequal_element,
+                    actual_value_of_first_unequal_element,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(
+                            evaluator,
+                            expected_value_of_first_unequal_element,
+                            actual_value_of_first_unequal_element,
+                        ),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate idempotence for vectors: once={once:?}, twice={twice:?}: at index {index_of_first_unequal_element} once={expected_value_of_first_unequal_element:?}, twice={actual_value_of_first_unequal_element:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($f:expr, $input:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_vector_idempotent_approx!($f, $input, evaluator);
+    };
+}
+
+/// Asserts that a value changed, from `before` to `after`, by approximately
+/// `expected_delta`, with an optional evaluator, reporting the observed and
+/// expected deltas on failure. The evaluator compares `expected_delta` with
+/// `after - before`.
+#[macro_export]
+macro_rules! assert_delta_approx {
+    ($before:expr, $after:expr, $expected_delta:expr, $evaluator:expr) => {
+        let before_param = &$before;
+        let after_param = &$after;
+        let expected_delta_param = &$expected_delta;
+
+        let (before, after, expected_delta) = {
+            let before = match $crate::try_testable_as_f64_!(before_param) {
+                Ok(before) => before,
+                Err(reason) => panic!("assertion failed: failed to verify approximate delta: {reason}"),
+            };
+            let after = match $crate::try_testable_as_f64_!(after_param) {
+                Ok(after) => after,
+                Err(reason) => panic!("assertion failed: failed to verify approximate delta: {reason}"),
+            };
+            let expected_delta = match $crate::try_testable_as_f64_!(expected_delta_param) {
+                Ok(expected_delta) => expected_delta,
+                Err(reason) => panic!("assertion failed: failed to verify approximate delta: {reason}"),
+            };
+
+            (before, after, expected_delta)
+        };
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::ComparisonResult as CR;
+
+            let observed_delta = after - before;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_scalar_eq_approx(&expected_delta, &observed_delta, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::BothNaN | CR::ApproximatelyEqual => (),
+                CR::Unequal => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(evaluator, expected_delta, observed_delta),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate delta: before={before_param:?}, after={after_param:?}, observed-delta={observed_delta:?}, expected-delta={expected_delta_param:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($before:expr, $after:expr, $expected_delta:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_delta_approx!($before, $after, $expected_delta, evaluator);
+    };
+}
+
+/// Asserts that expected and actual values are no more than `max_ulps`
+/// units in the last place (ULPs) apart, stating the measured and allowed
+/// ULP distances on failure. It is equivalent to
+/// `assert_scalar_eq_approx!(expected, actual, ulps(max_ulps))`.
+#[macro_export]
+macro_rules! assert_scalar_eq_ulps {
+    ($expected:expr, $actual:expr, $max_ulps:expr) => {
+        let evaluator = $crate::ulps($max_ulps);
+
+        assert_scalar_eq_approx!($expected, $actual, evaluator);
+    };
+}
+
+/// Asserts approximate equality of expected and actual vectors, with an
+/// optional evaluator, reporting (compactly) up to the first `k` unequal
+/// elements on failure.
+#[macro_export]
+macro_rules! assert_vector_eq_approx_first_k {
+    ($expected:expr, $actual:expr, $k:expr, $evaluator:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::VectorComparisonResult as CR;
+
+            let (comparison_result, mismatches, margin_factor, multiplier_factor) =
+                $crate::evaluate_vector_eq_approx_first_k(&expected, &actual, evaluator, $k);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::DifferentLengths {
+                    expected_length,
+                    actual_length,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for vectors: expected-length {expected_length} differs from actual-length {actual_length}",
+                    );
+                },
+                CR::UnequalElements { .. } => {
+                    let count = mismatches.len();
+                    let elements = mismatches
+                        .iter()
+                        .map(|m| format!("[{}] expected={:?}, actual={:?}", m.index, m.expected_value, m.actual_value))
+                        .collect::<Vec<_>>()
+                        .join("; ");
+                    let suffix = $crate::utils::format_evaluation_suffix(margin_factor, multiplier_factor, None);
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for vectors: first {count} unequal element(s): {elements}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr, $k:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_vector_eq_approx_first_k!($expected, $actual, $k, evaluator);
+    };
+}
+
+/// Asserts approximate equality of expected and actual
+/// `rust_decimal::Decimal` values, compared natively (without conversion to
+/// `f64`) with a decimal margin.
+///
+/// NOTE: requires the `"rust_decimal"` feature.
+#[cfg(feature = "rust_decimal")]
+#[macro_export]
+macro_rules! assert_decimal_eq_approx {
+    ($expected:expr, $actual:expr, $margin:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let margin = &$margin;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::ComparisonResult as CR;
+
+            match $crate::evaluate_decimal_eq_approx(expected, actual, margin) {
+                CR::ExactlyEqual | CR::BothNaN | CR::ApproximatelyEqual => (),
+                CR::Unequal => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for decimals: expected={expected}, actual={actual}, margin={margin}",
+                    );
+                },
+            };
+        }
+    };
+}
+
+/// Asserts that the elements of a vector are approximately constant, with
+/// an optional evaluator and an optional [`ConstantReference`] (which
+/// defaults to `ConstantReference::First`), reporting the first element
+/// that strays from the reference value and its deviation.
+#[macro_export]
+macro_rules! assert_vector_constant_approx {
+    ($values:expr, $evaluator:expr, $reference:expr) => {
+        let values = &$values;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+        let reference : $crate::ConstantReference = $reference;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::ConstantComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_vector_constant_approx(&values, evaluator, reference);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::StrayElement {
+                    index,
+                    reference_value,
+                    value,
+                    deviation,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(evaluator, reference_value, value),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate constancy for vectors: at index {index} value={value:?} strays from reference={reference_value:?} ({reference:?}) by deviation={deviation:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($values:expr, $evaluator:expr) => {
+        assert_vector_constant_approx!($values, $evaluator, $crate::ConstantReference::First);
+    };
+    ($values:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_vector_constant_approx!($values, evaluator);
+    };
+}
+
+/// Asserts that every element of a vector is approximately equal to a
+/// single scalar target, with an optional evaluator, reporting the first
+/// element that deviates from the target and its deviation.
+///
+/// NOTE: this is equivalent to [`assert_vector_constant_approx!`] with a
+/// reference of `ConstantReference::Target(target)`, and an empty vector
+/// satisfies it.
+#[macro_export]
+macro_rules! assert_vector_all_approx {
+    ($values:expr, $target:expr, $evaluator:expr) => {
+        let values = &$values;
+        let target_param = &$target;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::ConstantComparisonResult as CR;
+
+            let target = $crate::traits::TestableAsF64::testable_as_f64(target_param);
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_vector_constant_approx(&values, evaluator, $crate::ConstantReference::Target(target));
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::StrayElement {
+                    index,
+                    reference_value,
+                    value,
+                    deviation,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(evaluator, reference_value, value),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality of all elements to target: at index {index} value={value:?} deviates from target={target_param:?} by deviation={deviation:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($values:expr, $target:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_vector_all_approx!($values, $target, evaluator);
+    };
+}
+
+/// Asserts approximate equality of expected and actual vectors of values,
+/// skipping the elements at the given indices, with an optional evaluator,
+/// reporting any difference in length or the first unequal element that is
+/// not ignored.
+#[macro_export]
+macro_rules! assert_vector_eq_approx_ignoring {
+    ($expected:expr, $actual:expr, $ignore:expr, $evaluator:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let ignore : &[usize] = &$ignore;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::VectorComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_vector_eq_approx_ignoring(&expected, &actual, evaluator, ignore);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::DifferentLengths {
+                    expected_length,
+                    actual_length,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for vectors: expected-length {expected_length} differs from actual-length {actual_length}",
+                    );
+                },
+                CR::UnequalElements {
+                    index_of_first_unequal_element,
+                    expected_value_of_first_unequal_element,
+                    actual_value_of_first_unequal_element,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(
+                            evaluator,
+                            expected_value_of_first_unequal_element,
+                            actual_value_of_first_unequal_element,
+                        ),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for vectors (ignoring indices {ignore:?}): at index {index_of_first_unequal_element} expected={expected_value_of_first_unequal_element:?}, actual={actual_value_of_first_unequal_element:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr, $ignore:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_vector_eq_approx_ignoring!($expected, $actual, $ignore, evaluator);
+    };
+}
+
+/// Asserts that two vectors are approximately equal irrespective of the
+/// order of their elements, with an optional evaluator, by sorting both and
+/// comparing them element-wise, reporting the first sorted index at which
+/// they differ. See [`evaluate_vector_eq_approx_sorted()`].
+#[macro_export]
+macro_rules! assert_vector_eq_approx_sorted {
+    ($expected:expr, $actual:expr, $evaluator:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::VectorComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_vector_eq_approx_sorted(&expected, &actual, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::DifferentLengths {
+                    expected_length,
+                    actual_length,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for sorted vectors: expected-length {expected_length} differs from actual-length {actual_length}",
+                    );
+                },
+                CR::UnequalElements {
+                    index_of_first_unequal_element,
+                    expected_value_of_first_unequal_element,
+                    actual_value_of_first_unequal_element,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(
+                            evaluator,
+                            expected_value_of_first_unequal_element,
+                            actual_value_of_first_unequal_element,
+                        ),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for sorted vectors: at sorted index {index_of_first_unequal_element} expected={expected_value_of_first_unequal_element:?}, actual={actual_value_of_first_unequal_element:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_vector_eq_approx_sorted!($expected, $actual, evaluator);
+    };
+}
+
+/// Asserts that the actual vector is approximately the element-wise
+/// negation of the expected vector, i.e. `actual ≈ -expected`, with an
+/// optional evaluator, reporting the first index at which `actual[i]` is
+/// not approximately `-expected[i]`.
+#[macro_export]
+macro_rules! assert_vector_eq_neg_approx {
+    ($expected:expr, $actual:expr, $evaluator:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::VectorComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_vector_eq_neg_approx(&expected, &actual, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::DifferentLengths {
+                    expected_length,
+                    actual_length,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate negation for vectors: expected-length {expected_length} differs from actual-length {actual_length}",
+                    );
+                },
+                CR::UnequalElements {
+                    index_of_first_unequal_element,
+                    expected_value_of_first_unequal_element,
+                    actual_value_of_first_unequal_element,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(
+                            evaluator,
+                            expected_value_of_first_unequal_element,
+                            actual_value_of_first_unequal_element,
+                        ),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate negation for vectors: at index {index_of_first_unequal_element} negated-expected={expected_value_of_first_unequal_element:?}, actual={actual_value_of_first_unequal_element:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_vector_eq_neg_approx!($expected, $actual, evaluator);
+    };
+}
+
+/// Asserts approximate equality of the cumulative (prefix) sums of
+/// expected and actual vectors of values, with an optional evaluator,
+/// reporting the first index at which the cumulative totals diverge.
+#[macro_export]
+macro_rules! assert_cumsum_eq_approx {
+    ($expected:expr, $actual:expr, $evaluator:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::VectorComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_cumsum_eq_approx(&expected, &actual, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::DifferentLengths {
+                    expected_length,
+                    actual_length,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality of cumulative sums for vectors: expected-length {expected_length} differs from actual-length {actual_length}",
+                    );
+                },
+                CR::UnequalElements {
+                    index_of_first_unequal_element,
+                    expected_value_of_first_unequal_element,
+                    actual_value_of_first_unequal_element,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(
+                            evaluator,
+                            expected_value_of_first_unequal_element,
+                            actual_value_of_first_unequal_element,
+                        ),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality of cumulative sums for vectors: at index {index_of_first_unequal_element} expected-total={expected_value_of_first_unequal_element:?}, actual-total={actual_value_of_first_unequal_element:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_cumsum_eq_approx!($expected, $actual, evaluator);
+    };
+}
+
+/// Asserts approximate equality of expected and actual vectors of values,
+/// each element of which is named by the corresponding entry of `labels`,
+/// with an optional evaluator, identifying any unequal element by its
+/// label, as in "at 'velocity_z' expected=.., actual=..".
+#[macro_export]
+macro_rules! assert_vector_eq_approx_labeled {
+    ($expected:expr, $actual:expr, $labels:expr, $evaluator:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let labels : &[&str] = &$labels;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::LabeledVectorComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_vector_eq_approx_labeled(&expected, &actual, labels, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::DifferentLengths {
+                    expected_length,
+                    actual_length,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for vectors: expected-length {expected_length} differs from actual-length {actual_length}",
+                    );
+                },
+                CR::DifferentLabelCount {
+                    length,
+                    label_count,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for vectors: label-count {label_count} differs from length {length}",
+                    );
+                },
+                CR::UnequalElements {
+                    label_of_first_unequal_element,
+                    expected_value_of_first_unequal_element,
+                    actual_value_of_first_unequal_element,
+                    ..
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(
+                            evaluator,
+                            expected_value_of_first_unequal_element,
+                            actual_value_of_first_unequal_element,
+                        ),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for vectors: at '{label_of_first_unequal_element}' expected={expected_value_of_first_unequal_element:?}, actual={actual_value_of_first_unequal_element:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr, $labels:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_vector_eq_approx_labeled!($expected, $actual, $labels, evaluator);
+    };
+}
+
+/// Asserts approximate equality, up to a constant scale, of expected and
+/// actual vectors, with an optional evaluator, i.e. that `expected` is
+/// approximately equal to `k * actual` where `k` is the least-squares
+/// best-fit scale, reporting the estimated `k` and the first mismatch after
+/// scaling.
+#[macro_export]
+macro_rules! assert_vector_eq_approx_up_to_scale {
+    ($expected:expr, $actual:expr, $evaluator:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::VectorComparisonResult as CR;
+
+            let (comparison_result, scale, margin_factor, multiplier_factor) =
+                $crate::evaluate_vector_eq_approx_up_to_scale(&expected, &actual, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::DifferentLengths {
+                    expected_length,
+                    actual_length,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality up to scale for vectors: expected-length {expected_length} differs from actual-length {actual_length}",
+                    );
+                },
+                CR::UnequalElements {
+                    index_of_first_unequal_element,
+                    expected_value_of_first_unequal_element,
+                    actual_value_of_first_unequal_element,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(
+                            evaluator,
+                            expected_value_of_first_unequal_element,
+                            actual_value_of_first_unequal_element,
+                        ),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality up to scale for vectors: with estimated scale k={scale:?}, at index {index_of_first_unequal_element} expected={expected_value_of_first_unequal_element:?}, scaled-actual={actual_value_of_first_unequal_element:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_vector_eq_approx_up_to_scale!($expected, $actual, evaluator);
+    };
+}
+
+/// Asserts approximate equality of expected and actual vectors of
+/// 3-tuples, such as RGB or XYZ triples, with an optional evaluator,
+/// reporting which component (0, 1, or 2) of which index first diverged.
+///
+/// NOTE: only 3-tuples are accepted; vectors of tuples of other arity may
+/// be compared with [`evaluate_tuple_vector_eq_approx()`].
+#[macro_export]
+macro_rules! assert_tuple3_eq_approx {
+    ($expected:expr, $actual:expr, $evaluator:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::TupleVectorComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_tuple_vector_eq_approx(
+                    &$crate::utils::as_tuple3_slice(expected),
+                    &$crate::utils::as_tuple3_slice(actual),
+                    evaluator,
+                );
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::DifferentLengths {
+                    expected_length,
+                    actual_length,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for tuples: expected-length {expected_length} differs from actual-length {actual_length}",
+                    );
+                },
+                CR::UnequalComponents {
+                    index,
+                    component,
+                    expected_value,
+                    actual_value,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(evaluator, expected_value, actual_value),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for tuples: at index {index} component {component} expected={expected_value:?}, actual={actual_value:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_tuple3_eq_approx!($expected, $actual, evaluator);
+    };
+}
+
+/// Asserts approximate equality of expected and actual vectors of optional
+/// values, with an optional evaluator, where a `None` matches only a `None`,
+/// reporting the index of any `Some`/`None` mismatch and which side was
+/// `None`.
+#[macro_export]
+macro_rules! assert_optional_vector_eq_approx {
+    ($expected:expr, $actual:expr, $evaluator:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::OptionalVectorComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_optional_vector_eq_approx(&expected, &actual, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::DifferentLengths {
+                    expected_length,
+                    actual_length,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for optional vectors: expected-length {expected_length} differs from actual-length {actual_length}",
+                    );
+                },
+                CR::MismatchedPresence {
+                    index,
+                    expected_value,
+                    actual_value,
+                } => {
+                    let missing_side = if expected_value.is_none() { "expected" } else { "actual" };
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for optional vectors: at index {index} {missing_side} is None: expected={expected_value:?}, actual={actual_value:?}",
+                    );
+                },
+                CR::UnequalElements {
+                    index_of_first_unequal_element,
+                    expected_value_of_first_unequal_element,
+                    actual_value_of_first_unequal_element,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(
+                            evaluator,
+                            expected_value_of_first_unequal_element,
+                            actual_value_of_first_unequal_element,
+                        ),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for optional vectors: at index {index_of_first_unequal_element} expected={expected_value_of_first_unequal_element:?}, actual={actual_value_of_first_unequal_element:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_optional_vector_eq_approx!($expected, $actual, evaluator);
+    };
+}
+
+/// Asserts approximate equality of expected and actual `Result`s of scalar
+/// values, with an optional evaluator and an optional
+/// [`BothErrDisposition`] (by default
+/// [`BothErrDisposition::CompareDebug`]), stating which side was `Err` when
+/// only one was.
+#[macro_export]
+macro_rules! assert_result_eq_approx {
+    ($expected:expr, $actual:expr, $evaluator:expr, $both_err:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::ComparisonResult as CR;
+            use $crate::ResultComparisonResult as RCR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_result_eq_approx(expected, actual, evaluator, $both_err);
+
+            match comparison_result {
+                RCR::Ok(CR::ExactlyEqual | CR::BothNaN | CR::ApproximatelyEqual) | RCR::BothErr => (),
+                RCR::Ok(CR::Unequal) => {
+                    let description = match (expected, actual) {
+                        (Ok(expected_value), Ok(actual_value)) => {
+                            use $crate::traits::TestableAsF64 as _;
+
+                            $crate::utils::describe_evaluation(
+                                evaluator,
+                                expected_value.testable_as_f64(),
+                                actual_value.testable_as_f64(),
+                            )
+                        },
+                        _ => None,
+                    };
+                    let suffix = $crate::utils::format_evaluation_suffix(margin_factor, multiplier_factor, description);
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for results: expected={expected:?}, actual={actual:?}{suffix}",
+                    );
+                },
+                RCR::UnequalErrs {
+                    ..
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for results: both are Err: expected={expected:?}, actual={actual:?}",
+                    );
+                },
+                RCR::MismatchedErr {
+                    expected : expected_value,
+                    ..
+                } => {
+                    let err_side = if expected_value.is_err() { "expected" } else { "actual" };
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for results: {err_side} is Err: expected={expected:?}, actual={actual:?}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr, $evaluator:expr) => {
+        assert_result_eq_approx!($expected, $actual, $evaluator, $crate::BothErrDisposition::CompareDebug);
+    };
+    ($expected:expr, $actual:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_result_eq_approx!($expected, $actual, evaluator);
+    };
+}
+
+/// Asserts approximate equality of expected and actual complex numbers in
+/// polar form, applying separate evaluators to their magnitudes and
+/// (wrap-aware) phases, and reporting which of them diverged.
+///
+/// NOTE: requires the `"num-complex"` feature.
+#[cfg(feature = "num-complex")]
+#[macro_export]
+macro_rules! assert_complex_polar_eq_approx {
+    ($expected:expr, $actual:expr, $magnitude_evaluator:expr, $phase_evaluator:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let magnitude_evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$magnitude_evaluator;
+        let phase_evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$phase_evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::ComplexPolarComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_complex_polar_eq_approx(expected, actual, magnitude_evaluator, phase_evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::UnequalMagnitudes {
+                    expected_magnitude,
+                    actual_magnitude,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(magnitude_evaluator, expected_magnitude, actual_magnitude),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for complex numbers: magnitude diverged: expected={expected:?}, actual={actual:?}, expected-magnitude={expected_magnitude:?}, actual-magnitude={actual_magnitude:?}{suffix}",
+                    );
+                },
+                CR::UnequalPhases {
+                    expected_phase,
+                    actual_phase,
+                    phase_difference,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(
+                            phase_evaluator,
+                            expected_phase,
+                            expected_phase + phase_difference,
+                        ),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for complex numbers: phase diverged: expected={expected:?}, actual={actual:?}, expected-phase={expected_phase:?}, actual-phase={actual_phase:?}, phase-difference={phase_difference:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+}
+
+/// Asserts approximate equality of the total energies - the sums of the
+/// squared magnitudes of their bins - of expected and actual complex
+/// spectra, with an optional evaluator, reporting both energies. See
+/// [`evaluate_energy_eq_approx()`].
+///
+/// NOTE: requires the `"num-complex"` feature.
+#[cfg(feature = "num-complex")]
+#[macro_export]
+macro_rules! assert_energy_eq_approx {
+    ($expected:expr, $actual:expr, $evaluator:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::ComparisonResult as CR;
+
+            let (comparison_result, expected_energy, actual_energy, margin_factor, multiplier_factor) =
+                $crate::evaluate_energy_eq_approx(expected, actual, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::BothNaN | CR::ApproximatelyEqual => (),
+                CR::Unequal => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(evaluator, expected_energy, actual_energy),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality of energy for spectra: expected-energy={expected_energy:?}, actual-energy={actual_energy:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_energy_eq_approx!($expected, $actual, evaluator);
+    };
+}
+
+/// Asserts approximate equality of expected and actual `serde_json::Value`s
+/// that are arrays - which may be nested - of numbers, with an optional
+/// evaluator, reporting any mismatch by its JSON-pointer-like path.
+///
+/// NOTE: requires the `"serde_json"` feature.
+#[cfg(feature = "serde_json")]
+#[macro_export]
+macro_rules! assert_json_eq_approx {
+    ($expected:expr, $actual:expr, $evaluator:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::JsonComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_json_eq_approx(expected, actual, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::DifferentLengths {
+                    path,
+                    expected_length,
+                    actual_length,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for JSON arrays: at '{path}' expected-length {expected_length} differs from actual-length {actual_length}",
+                    );
+                },
+                CR::NonNumericElement {
+                    path,
+                    expected_value,
+                    actual_value,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for JSON arrays: at '{path}' non-numeric element: expected={expected_value}, actual={actual_value}",
+                    );
+                },
+                CR::MismatchedStructure {
+                    path,
+                    expected_value,
+                    actual_value,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for JSON arrays: at '{path}' array and number mismatch: expected={expected_value}, actual={actual_value}",
+                    );
+                },
+                CR::UnequalElements {
+                    path,
+                    expected_value,
+                    actual_value,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(evaluator, expected_value, actual_value),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for JSON arrays: at '{path}' expected={expected_value:?}, actual={actual_value:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_json_eq_approx!($expected, $actual, evaluator);
+    };
+}
+
+/// Asserts approximate equality of expected and actual `ndarray` arrays,
+/// with an optional evaluator, reporting any unequal element by its
+/// multi-dimensional index.
+///
+/// NOTE: requires the `"ndarray"` feature.
+#[cfg(feature = "ndarray")]
+#[macro_export]
+macro_rules! assert_ndarray_eq_approx {
+    ($expected:expr, $actual:expr, $evaluator:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::NdarrayComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_ndarray_eq_approx(expected, actual, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::DifferentShapes {
+                    expected_shape,
+                    actual_shape,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for arrays: expected-shape {expected_shape:?} differs from actual-shape {actual_shape:?}",
+                    );
+                },
+                CR::UnequalElements {
+                    index,
+                    expected_value,
+                    actual_value,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(evaluator, expected_value, actual_value),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for arrays: at {index:?} expected={expected_value:?}, actual={actual_value:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_ndarray_eq_approx!($expected, $actual, evaluator);
+    };
+}
+
+/// Asserts approximate equality of expected and actual matrices, given as
+/// vectors of rows, with an optional evaluator.
+#[macro_export]
+macro_rules! assert_matrix_eq_approx {
+    ($expected:expr, $actual:expr, $evaluator:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::MatrixComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_matrix_eq_approx(&expected, &actual, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::DifferentRowCounts {
+                    expected_row_count,
+                    actual_row_count,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for matrices: expected-row-count {expected_row_count} differs from actual-row-count {actual_row_count}",
+                    );
+                },
+                CR::DifferentRowLengths {
+                    row_index,
+                    expected_length,
+                    actual_length,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for matrices: at row {row_index} expected-length {expected_length} differs from actual-length {actual_length}",
+                    );
+                },
+                CR::UnequalElements {
+                    row_index,
+                    column_index,
+                    expected_value,
+                    actual_value,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(evaluator, expected_value, actual_value),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for matrices: at ({row_index}, {column_index}) expected={expected_value:?}, actual={actual_value:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_matrix_eq_approx!($expected, $actual, evaluator);
+    };
+}
+
+/// Asserts approximate equality of an expected matrix and either an actual
+/// matrix or its transpose, given as vectors of rows, with an optional
+/// evaluator. When both fail, the failure of each is reported.
+#[macro_export]
+macro_rules! assert_matrix_eq_approx_or_transpose {
+    ($expected:expr, $actual:expr, $evaluator:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::TransposableMatrixComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_matrix_eq_approx_or_transpose(&expected, &actual, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual {
+                    ..
+                }
+                | CR::ApproximatelyEqual {
+                    ..
+                } => (),
+                CR::Unequal {
+                    direct,
+                    transposed,
+                } => {
+                    let description = match direct {
+                        $crate::MatrixComparisonResult::UnequalElements {
+                            expected_value,
+                            actual_value,
+                            ..
+                        } => $crate::utils::describe_evaluation(evaluator, expected_value, actual_value),
+                        _ => None,
+                    };
+                    let direct = $crate::utils::describe_matrix_mismatch(&direct);
+                    let transposed = match transposed {
+                        Some(transposed) => $crate::utils::describe_matrix_mismatch(&transposed),
+                        None => "not attempted, as actual is ragged".to_string(),
+                    };
+                    let suffix = $crate::utils::format_evaluation_suffix(margin_factor, multiplier_factor, description);
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for matrices or transpose: as given: {direct}; transposed: {transposed}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_matrix_eq_approx_or_transpose!($expected, $actual, evaluator);
+    };
+}
+
+/// Asserts approximate equality of expected and actual rank-3 tensors,
+/// given as nested three-level vectors, with an optional evaluator.
+#[macro_export]
+macro_rules! assert_tensor3_eq_approx {
+    ($expected:expr, $actual:expr, $evaluator:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::Tensor3ComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_tensor3_eq_approx(&expected, &actual, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::DifferentOuterLengths {
+                    expected_length,
+                    actual_length,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for tensors: expected-length {expected_length} differs from actual-length {actual_length}",
+                    );
+                },
+                CR::DifferentMiddleLengths {
+                    i,
+                    expected_length,
+                    actual_length,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for tensors: at ({i}) expected-length {expected_length} differs from actual-length {actual_length}",
+                    );
+                },
+                CR::DifferentInnerLengths {
+                    i,
+                    j,
+                    expected_length,
+                    actual_length,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for tensors: at ({i}, {j}) expected-length {expected_length} differs from actual-length {actual_length}",
+                    );
+                },
+                CR::UnequalElements {
+                    i,
+                    j,
+                    k,
+                    expected_value,
+                    actual_value,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(evaluator, expected_value, actual_value),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for tensors: at ({i}, {j}, {k}) expected={expected_value:?}, actual={actual_value:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_tensor3_eq_approx!($expected, $actual, evaluator);
+    };
+}
+
+/// Asserts approximate equality of two functions - the reference and the
+/// one under test - sampled at `steps` evenly spaced points across the
+/// closed interval `[domain_start, domain_end]`, with an optional
+/// evaluator, reporting the first point at which they diverge.
+#[macro_export]
+macro_rules! assert_fn_eq_approx {
+    ($f:expr, $g:expr, $domain_start:expr, $domain_end:expr, $steps:expr, $evaluator:expr) => {
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::FunctionComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_fn_eq_approx($f, $g, $domain_start, $domain_end, $steps, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::UnequalAtPoint {
+                    x,
+                    expected_value,
+                    actual_value,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(evaluator, expected_value, actual_value),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for functions: at x={x:?} expected={expected_value:?}, actual={actual_value:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($f:expr, $g:expr, $domain_start:expr, $domain_end:expr, $steps:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_fn_eq_approx!($f, $g, $domain_start, $domain_end, $steps, evaluator);
+    };
+}
+
+/// Asserts that a function matches a reference polynomial, given by its
+/// coefficients in ascending order of power, when both are sampled at N
+/// evenly spaced points across a closed interval, with an optional
+/// evaluator, reporting the first point, `x`, at which they diverge, and
+/// the values of both there.
+#[macro_export]
+macro_rules! assert_fn_matches_poly_approx {
+    ($f:expr, $coefficients:expr, $domain_start:expr, $domain_end:expr, $steps:expr, $evaluator:expr) => {
+        let coefficients : &[f64] = &$coefficients;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::FunctionComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_fn_matches_poly_approx($f, coefficients, $domain_start, $domain_end, $steps, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::UnequalAtPoint {
+                    x,
+                    expected_value,
+                    actual_value,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(evaluator, expected_value, actual_value),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality of function and polynomial {coefficients:?}: at x={x:?} polynomial={expected_value:?}, function={actual_value:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($f:expr, $coefficients:expr, $domain_start:expr, $domain_end:expr, $steps:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_fn_matches_poly_approx!($f, $coefficients, $domain_start, $domain_end, $steps, evaluator);
+    };
+}
+
+/// Asserts approximate equality of expected and actual distributions,
+/// given as vectors of bin counts, with an optional evaluator. Both are
+/// normalised to sum to 1.0 before being compared bin-by-bin.
+#[macro_export]
+macro_rules! assert_distribution_eq_approx {
+    ($expected:expr, $actual:expr, $evaluator:expr) => {
+        let expected = &$expected;
+        let actual = &$actual;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::{
+                DistributionError as DE,
+                VectorComparisonResult as CR,
+            };
+
+            match $crate::evaluate_distribution_eq_approx(&expected, &actual, evaluator) {
+                Err(DE::ExpectedSumIsZero) => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for distributions: expected bins sum to zero",
+                    );
+                },
+                Err(DE::ActualSumIsZero) => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate equality for distributions: actual bins sum to zero",
+                    );
+                },
+                Ok((comparison_result, margin_factor, multiplier_factor)) => {
+                    match comparison_result {
+                        CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                        CR::DifferentLengths {
+                            expected_length,
+                            actual_length,
+                        } => {
+                            assert!(
+                                false,
+                                "assertion failed: failed to verify approximate equality for distributions: expected-length {expected_length} differs from actual-length {actual_length}",
+                            );
+                        },
+                        CR::UnequalElements {
+                            index_of_first_unequal_element,
+                            expected_value_of_first_unequal_element,
+                            actual_value_of_first_unequal_element,
+                        } => {
+                            let suffix = $crate::utils::format_evaluation_suffix(
+                                margin_factor,
+                                multiplier_factor,
+                                $crate::utils::describe_evaluation(
+                                    evaluator,
+                                    expected_value_of_first_unequal_element,
+                                    actual_value_of_first_unequal_element,
+                                ),
+                            );
+
+                            assert!(
+                                false,
+                                "assertion failed: failed to verify approximate equality for distributions: at bin {index_of_first_unequal_element} expected-mass={expected_value_of_first_unequal_element:?}, actual-mass={actual_value_of_first_unequal_element:?}{suffix}",
+                            );
+                        },
+                    };
+                },
+            };
+        }
+    };
+    ($expected:expr, $actual:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_distribution_eq_approx!($expected, $actual, evaluator);
+    };
+}
+
+/// Asserts that a value lies within the closed interval `[lo, hi]`, or is
+/// approximately equal to the nearer bound, with an optional evaluator.
+#[macro_export]
+macro_rules! assert_in_range_approx {
+    ($value:expr, $lo:expr, $hi:expr, $evaluator:expr) => {
+        let value_param = &$value;
+        let lo_param = &$lo;
+        let hi_param = &$hi;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::RangeComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_scalar_in_range_approx(value_param, lo_param, hi_param, evaluator);
+
+            match comparison_result {
+                CR::WithinRange | CR::ApproximatelyWithinRange => (),
+                CR::BelowLowerBound {
+                    lower_bound,
+                    shortfall,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(margin_factor, multiplier_factor, None);
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify value within range approximately: value={value_param:?}, range=[{lo_param:?}, {hi_param:?}]: below lower bound {lower_bound:?} by {shortfall:?}{suffix}",
+                    );
+                },
+                CR::AboveUpperBound {
+                    upper_bound,
+                    excess,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(margin_factor, multiplier_factor, None);
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify value within range approximately: value={value_param:?}, range=[{lo_param:?}, {hi_param:?}]: above upper bound {upper_bound:?} by {excess:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($value:expr, $lo:expr, $hi:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_in_range_approx!($value, $lo, $hi, evaluator);
+    };
+}
+
+/// Asserts that a value lies within the closed band `[lo, hi]`, which is
+/// given directly rather than as a centre and a tolerance.
+#[macro_export]
+macro_rules! assert_scalar_in_band {
+    ($actual:expr, $lo:expr, $hi:expr) => {
+        let actual_param = &$actual;
+        let lo_param = &$lo;
+        let hi_param = &$hi;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::ComparisonResult as CR;
+
+            match $crate::evaluate_scalar_in_band(actual_param, lo_param, hi_param) {
+                CR::ExactlyEqual | CR::BothNaN | CR::ApproximatelyEqual => (),
+                CR::Unequal => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify value within band: actual={actual_param:?}, band=[{lo_param:?}, {hi_param:?}]",
+                    );
+                },
+            };
+        }
+    };
+}
+
+/// Asserts that two values with measurement uncertainties - "value ±
+/// uncertainty" - are equal, in accordance with an optional
+/// [`UncertaintyCriterion`] (which defaults to
+/// `UncertaintyCriterion::IntervalsOverlap`), reporting their separation
+/// in units of their combined uncertainty on failure.
+#[macro_export]
+macro_rules! assert_scalar_eq_approx_with_uncertainty {
+    ($expected:expr, $expected_uncertainty:expr, $actual:expr, $actual_uncertainty:expr, $criterion:expr) => {
+        let expected_param = &$expected;
+        let expected_uncertainty : f64 = $expected_uncertainty;
+        let actual_param = &$actual;
+        let actual_uncertainty : f64 = $actual_uncertainty;
+        let criterion : $crate::UncertaintyCriterion = $criterion;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::ComparisonResult as CR;
+
+            let (comparison_result, separation_in_sigmas) = $crate::evaluate_scalar_eq_approx_with_uncertainty(
+                expected_param,
+                expected_uncertainty,
+                actual_param,
+                actual_uncertainty,
+                criterion,
+            );
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::BothNaN | CR::ApproximatelyEqual => (),
+                CR::Unequal => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify equality within uncertainty: expected={expected_param:?} ± {expected_uncertainty:?}, actual={actual_param:?} ± {actual_uncertainty:?}: separation {separation_in_sigmas:.1}σ ({criterion:?})",
+                    );
+                },
+            };
+        }
+    };
+    ($expected:expr, $expected_uncertainty:expr, $actual:expr, $actual_uncertainty:expr) => {
+        assert_scalar_eq_approx_with_uncertainty!(
+            $expected,
+            $expected_uncertainty,
+            $actual,
+            $actual_uncertainty,
+            $crate::UncertaintyCriterion::IntervalsOverlap
+        );
+    };
+}
+
+/// Asserts that a value is approximately an integer, i.e. that it is
+/// approximately equal to its nearest integer, with an optional evaluator,
+/// reporting the value and the nearest integer on failure.
+#[macro_export]
+macro_rules! assert_approx_integer {
+    ($value:expr, $evaluator:expr) => {
+        let value_param = &$value;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::ComparisonResult as CR;
+
+            let (comparison_result, nearest_integer, margin_factor, multiplier_factor) =
+                $crate::evaluate_approx_integer(value_param, evaluator);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::BothNaN | CR::ApproximatelyEqual => (),
+                CR::Unequal => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(evaluator, nearest_integer, $crate::traits::TestableAsF64::testable_as_f64(value_param)),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate integer: value={value_param:?}, nearest-integer={nearest_integer:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($value:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_approx_integer!($value, evaluator);
+    };
+}
+
+/// Asserts that the relative error of `actual` with respect to `expected`
+/// does not exceed `threshold`, evaluating to the computed error.
+///
+/// When `expected` is zero the absolute error is used instead, and this is
+/// noted in the failure message.
+#[macro_export]
+macro_rules! assert_relative_error_below {
+    ($expected:expr, $actual:expr, $threshold:expr) => {{
+        let expected_param = &$expected;
+        let actual_param = &$actual;
+        let threshold : f64 = $threshold;
+
+        let (error, is_absolute) = $crate::evaluate_relative_error(expected_param, actual_param);
+
+        if error.is_nan() || error > threshold {
+            if is_absolute {
+                assert!(
+                    false,
+                    "assertion failed: failed to verify relative error: expected={expected_param:?}, actual={actual_param:?}: absolute error {error:e} (expected is zero) exceeds {threshold:e}",
+                );
+            } else {
+                assert!(
+                    false,
+                    "assertion failed: failed to verify relative error: expected={expected_param:?}, actual={actual_param:?}: relative error {error:e} exceeds {threshold:e}",
+                );
+            }
+        }
+
+        error
+    }};
+}
+
+
+/// Asserts that the root-mean-square error (RMSE) of `actual` with respect
+/// to `expected` does not exceed `threshold`, evaluating to the computed
+/// RMSE.
+///
+/// Fails if the vectors differ in length.
+#[macro_export]
+macro_rules! assert_rmse_below {
+    ($expected:expr, $actual:expr, $threshold:expr) => {{
+        let expected = &$expected;
+        let actual = &$actual;
+        let threshold : f64 = $threshold;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::RmseError as RE;
+
+            match $crate::evaluate_rmse(expected, actual) {
+                Ok(rmse) => {
+                    if rmse.is_nan() || rmse > threshold {
+                        assert!(
+                            false,
+                            "assertion failed: failed to verify root-mean-square error for vectors: RMSE {rmse:.4e} exceeds {threshold:.4e}",
+                        );
+                    }
+
+                    rmse
+                },
+                Err(RE::DifferentLengths {
+                    expected_length,
+                    actual_length,
+                }) => {
+                    panic!(
+                        "assertion failed: failed to verify root-mean-square error for vectors: expected-length {expected_length} differs from actual-length {actual_length}",
+                    );
+                },
+            }
+        }
+    }};
+}
+
+
+/// Asserts that the Pearson correlation coefficient of `expected` and
+/// `actual` is not below `min_correlation`, i.e. that the two signals are
+/// shaped alike, evaluating to the computed coefficient.
+///
+/// Fails if the vectors differ in length, have fewer than two elements, or
+/// either of them is constant (has zero variance).
+#[macro_export]
+macro_rules! assert_correlation_above {
+    ($expected:expr, $actual:expr, $min_correlation:expr) => {{
+        let expected = &$expected;
+        let actual = &$actual;
+        let min_correlation : f64 = $min_correlation;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::CorrelationError as CE;
+
+            match $crate::evaluate_correlation(expected, actual) {
+                Ok(correlation) => {
+                    if correlation.is_nan() || correlation < min_correlation {
+                        assert!(
+                            false,
+                            "assertion failed: failed to verify correlation for vectors: correlation {correlation} is below {min_correlation}",
+                        );
+                    }
+
+                    correlation
+                },
+                Err(CE::DifferentLengths {
+                    expected_length,
+                    actual_length,
+                }) => {
+                    panic!(
+                        "assertion failed: failed to verify correlation for vectors: expected-length {expected_length} differs from actual-length {actual_length}",
+                    );
+                },
+                Err(CE::InsufficientElements {
+                    length,
+                }) => {
+                    panic!(
+                        "assertion failed: failed to verify correlation for vectors: correlation is undefined for {length} element(s)",
+                    );
+                },
+                Err(CE::ZeroVariance {
+                    expected_is_constant,
+                    actual_is_constant,
+                }) => {
+                    let which = match (expected_is_constant, actual_is_constant) {
+                        (true, true) => "expected and actual are",
+                        (true, false) => "expected is",
+                        _ => "actual is",
+                    };
+
+                    panic!(
+                        "assertion failed: failed to verify correlation for vectors: correlation is undefined because {which} constant (zero variance)",
+                    );
+                },
+            }
+        }
+    }};
+}
+
+/// Asserts that a sampled time-series is approximately periodic with the
+/// given period (in samples, which need not be integral), with an optional
+/// evaluator and an optional [`PeriodInterpolation`] (which defaults to
+/// `PeriodInterpolation::Linear`), reporting the first sample that
+/// diverges from that one period later.
+#[macro_export]
+macro_rules! assert_periodic_approx {
+    ($samples:expr, $period_in_samples:expr, $evaluator:expr, $interpolation:expr) => {
+        let samples = &$samples;
+        let period_in_samples = $period_in_samples as f64;
+        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+        let interpolation : $crate::PeriodInterpolation = $interpolation;
+
+        // scope to protect against multiple `use`s of crate type(s)
+        {
+            use $crate::PeriodicComparisonResult as CR;
+
+            let (comparison_result, margin_factor, multiplier_factor) =
+                $crate::evaluate_periodic_approx(samples, period_in_samples, evaluator, interpolation);
+
+            match comparison_result {
+                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
+                CR::InsufficientSamples {
+                    length,
+                    period,
+                } => {
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate periodicity: {length} sample(s) insufficient for period {period:?}",
+                    );
+                },
+                CR::UnequalSamples {
+                    index,
+                    value,
+                    period_value,
+                } => {
+                    let suffix = $crate::utils::format_evaluation_suffix(
+                        margin_factor,
+                        multiplier_factor,
+                        $crate::utils::describe_evaluation(evaluator, value, period_value),
+                    );
+
+                    assert!(
+                        false,
+                        "assertion failed: failed to verify approximate periodicity: samples[{index}]={value:?} differs from samples[{index} + {period_in_samples:?}]={period_value:?}{suffix}",
+                    );
+                },
+            };
+        }
+    };
+    ($samples:expr, $period_in_samples:expr, $evaluator:expr) => {
+        assert_periodic_approx!($samples, $period_in_samples, $evaluator, $crate::PeriodInterpolation::Linear);
+    };
+    ($samples:expr, $period_in_samples:expr) => {
+        let evaluator = $crate::default_evaluator();
+
+        assert_periodic_approx!($samples, $period_in_samples, evaluator);
+    };
+}
+
+
+#[cfg(test)]
+#[rustfmt::skip]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use crate as test_helpers;
+
+    use test_helpers::{
+        traits::ApproximateEqualityEvaluator,
+        ComparisonResult,
+        asymmetric_margin,
+        flush_subnormals_to_zero,
+        full_scale,
+        log_scale,
+        margin,
+        margin_from_condition,
+        multiplier,
+        nan_strict,
+        noise_floor,
+        numpy_close,
+        ppm,
+        relative_geomean,
+        saturating,
+        significant_figures,
+        significant_figures_with_rounding_mode,
+        ulps,
+        zero_margin_or_multiplier,
+        zero_margin_or_relative_geomean,
+    };
+
+    use std::rc as std_rc;
+
+
+    mod TEST_margin {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_margin_TEST_1() {
+            let margin_factor = 0.0;
+            let m = margin(margin_factor);
+
+            assert_eq!(ComparisonResult::ExactlyEqual, m.evaluate(0.0, 0.0).0);
+
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.000001, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.00001, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.0001, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.001, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.01, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.1, 0.0).0);
+        }
+
+        #[test]
+        fn TEST_margin_TEST_2() {
+            let margin_factor = 0.001;
+            let m = margin(margin_factor);
+
+            assert_eq!(ComparisonResult::ExactlyEqual, m.evaluate(0.0, 0.0).0);
+
+            assert_eq!(ComparisonResult::ApproximatelyEqual, m.evaluate(0.000001, 0.0).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, m.evaluate(0.00001, 0.0).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, m.evaluate(0.0001, 0.0).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, m.evaluate(0.001, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.0010001, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.001001, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.00101, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.0011, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.01, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.1, 0.0).0);
+        }
+    }
+
+
+    mod TEST_BARE_MARGIN {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::evaluators::MarginEvaluator;
+
+
+        #[test]
+        fn TEST_BARE_MARGIN_EQUIVALENT_TO_margin() {
+            for (expected, actual) in [ (0.0, 0.0), (0.0, 0.0005), (1.0, 1.002), (-10.0, -10.0009) ] {
+                assert_eq!(margin(0.001).evaluate(expected, actual), 0.001.evaluate(expected, actual));
+            }
+
+            let m : MarginEvaluator = 0.001.into();
+
+            assert_eq!(0.001, m.factor());
+        }
+
+        #[test]
+        fn TEST_BARE_MARGIN_IN_MACROS() {
+            let tolerance = 1e-6;
+
+            assert_scalar_eq_approx!(1.0, 1.0000005, 1e-6);
+            assert_scalar_ne_approx!(1.0, 1.000002, 1e-6);
+            assert_scalar_eq_approx!(1.0, 1.0000005, tolerance);
+            assert_vector_eq_approx!([ 1.0, 2.0 ], [ 1.0000005, 2.0 ], 1e-6);
+            assert_vector_ne_approx!([ 1.0, 2.0 ], [ 1.000002, 2.0 ], tolerance);
+
+            // evaluator expressions are unaffected
+            assert_scalar_eq_approx!(1.0, 1.0000005, margin(1e-6));
+            assert_scalar_eq_approx!(1.0, 1.0000005, MarginEvaluator::from(1e-6));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=1.0, actual=1.000002, margin_factor=0.000001")]
+        fn TEST_BARE_MARGIN_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(1.0, 1.000002, 1e-6);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=1.23456, actual=1.23457, margin_factor=0.000001 (agree to 5 significant digits)")]
+        fn TEST_SHOULD_FAIL_REPORTING_SIGNIFICANT_DIGITS_IN_COMMON() {
+            assert_scalar_eq_approx!(1.23456, 1.23457, 1e-6);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=1.0, actual=1.0000010003, margin_factor=0.000001 (agree to 6 significant digits) (would pass at margin 1.0003e-6, 1.0003x current)")]
+        fn TEST_SHOULD_FAIL_REPORTING_HEADROOM_OF_NEAR_MISS() {
+            assert_scalar_eq_approx!(1.0, 1.0000010003, 1e-6);
+        }
+
+        #[test]
+        fn TEST_margin_from_condition_1() {
+            assert_eq!(1e-10, margin_from_condition(1e-16, 1e6).factor());
+            assert_eq!(f64::EPSILON, margin_from_condition(f64::EPSILON, 1.0).factor());
+        }
+
+        #[test]
+        fn TEST_margin_from_condition_ILL_CONDITIONED_SOLVE() {
+            // solve [[1, 1], [1, 1 + d]] x = b, whose condition number is
+            // approximately 4 / d, by Cramer's rule
+            let d = 3e-9;
+            let condition_number = 4.0 / d;
+            let x = [0.1, 0.7];
+
+            let (a11, a12, a21, a22) = (1.0, 1.0, 1.0, 1.0 + d);
+            let (b1, b2) = (a11 * x[0] + a12 * x[1], a21 * x[0] + a22 * x[1]);
+            let det = a11 * a22 - a12 * a21;
+
+            let solution = [(b1 * a22 - a12 * b2) / det, (a11 * b2 - a21 * b1) / det];
+
+            assert_vector_ne_approx!(x, solution, margin(f64::EPSILON));
+            assert_vector_eq_approx!(x, solution, margin_from_condition(f64::EPSILON, condition_number));
+        }
+    }
+
+
+    mod TEST_multiplier {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_multiplier_TEST_1() {
+            let multiplier_factor = 0.0;
+            let m = multiplier(multiplier_factor);
+
+            assert_eq!(ComparisonResult::ExactlyEqual, m.evaluate(0.0, 0.0).0);
+
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.000001, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.00001, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.0001, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.001, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.01, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.1, 0.0).0);
+        }
+
+        #[test]
+        fn TEST_multiplier_TEST_2() {
+            let multiplier_factor = 0.001;
+            let m = multiplier(multiplier_factor);
+
+            assert_eq!(ComparisonResult::ExactlyEqual, m.evaluate(0.0, 0.0).0);
+
+            assert_eq!(ComparisonResult::ExactlyEqual, m.evaluate(1.0, 1.0).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, m.evaluate(1.000001, 1.0).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, m.evaluate(1.00001, 1.0).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, m.evaluate(1.0001, 1.0).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, m.evaluate(1.001, 1.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.0010001, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.001001, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.00101, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.0011, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.01, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.1, 0.0).0);
+        }
+    }
+
+
+    mod TEST_ACCESSORS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_ACCESSORS_1() {
+            assert_eq!(0.001, margin(0.001).factor());
+            assert_eq!(0.002, multiplier(0.002).factor());
+
+            let e = zero_margin_or_multiplier(0.003, 0.004);
+
+            assert_eq!(0.003, e.multiplier_factor());
+            assert_eq!(0.004, e.zero_margin_factor());
+
+            assert_eq!(0.005, nan_strict(margin(0.005)).inner().factor());
+        }
+    }
+
+
+    mod TEST_EVALUATOR_REUSE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        struct Fixture<E> {
+            tolerance : E,
+        }
+
+
+        #[test]
+        fn TEST_EVALUATOR_REUSE_BY_VALUE_AND_BY_REFERENCE() {
+            let tol = margin(1e-6);
+            let fixture = Fixture { tolerance : multiplier(0.001) };
+
+            // by value (copied)
+            assert_scalar_eq_approx!(1.0, 1.0000005, tol);
+            assert_scalar_ne_approx!(1.0, 1.000002, tol);
+            assert_vector_eq_approx!([1.0, 2.0], [1.0000005, 2.0], tol);
+            assert_scalar_eq_approx!(1000.0, 1000.5, fixture.tolerance);
+
+            // by reference
+            assert_scalar_eq_approx!(1.0, 1.0000005, &tol);
+            assert_scalar_ne_approx!(1.0, 1.000002, &tol);
+            assert_vector_ne_approx!([1.0, 2.0], [1.0000005, 2.1], &tol);
+            assert_scalar_eq_approx!(1000.0, 1000.5, &fixture.tolerance);
+
+            // cloned
+            #[allow(clippy::clone_on_copy)]
+            let cloned = tol.clone();
+
+            assert_eq!(tol.factor(), cloned.factor());
+            assert_eq!(ComparisonResult::ApproximatelyEqual, cloned.evaluate(1.0, 1.0000005).0);
+
+            let _ = (zero_margin_or_multiplier(0.1, 0.2), asymmetric_margin(0.1, 0.2), relative_geomean(0.1), nan_strict(tol));
+            let copies = [zero_margin_or_multiplier(0.1, 0.2); 2];
+
+            assert_eq!(copies[0].zero_margin_factor(), copies[1].zero_margin_factor());
+        }
+    }
+
+
+    mod TEST_BOXED_EVALUATORS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_BOXED_EVALUATORS_IN_HETEROGENEOUS_COLLECTION() {
+            let evaluators : Vec<Box<dyn ApproximateEqualityEvaluator>> = vec![
+                margin(0.01).boxed(),
+                multiplier(0.01).boxed(),
+                ulps(4).boxed(),
+                nan_strict(asymmetric_margin(0.0, 0.02)).boxed(),
+            ];
+
+            let results : Vec<ComparisonResult> = evaluators.iter().map(|e| e.evaluate(1.0, 1.005).0).collect();
+
+            assert_eq!(
+                vec![
+                    ComparisonResult::ApproximatelyEqual,
+                    ComparisonResult::ApproximatelyEqual,
+                    ComparisonResult::Unequal,
+                    ComparisonResult::ApproximatelyEqual,
+                ],
+                results
+            );
+
+            for e in &evaluators {
+                assert_scalar_eq_approx!(2.0, 2.0, e);
+                assert_vector_eq_approx!([ 1.0, 2.0 ], [ 1.0, 2.0 ], e);
+            }
+
+            assert_scalar_eq_approx!(1.0, 1.005, evaluators[0]);
+        }
+    }
+
+
+    mod TEST_asymmetric_margin {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_asymmetric_margin_TEST_1() {
+            let e = asymmetric_margin(0.1, 0.5);
+
+            assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(10.0, 10.0).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(10.0, 10.45).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(10.0, 9.85).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(10.0, 9.95).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(10.0, 10.55).0);
+
+            assert_eq!(Some(0.5), e.evaluate(10.0, 10.55).1);
+            assert_eq!(0.1, e.lower_factor());
+            assert_eq!(0.5, e.upper_factor());
+        }
+
+        #[test]
+        fn TEST_asymmetric_margin_TEST_2() {
+            assert_scalar_eq_approx!(10.0, 10.45, asymmetric_margin(0.1, 0.5));
+            assert_scalar_ne_approx!(10.0, 9.55, asymmetric_margin(0.1, 0.5));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=10.0, actual=9.5, margin_factor=0.5: acceptance interval [9.75, 10.5] (lower_factor=0.25, upper_factor=0.5)")]
+        fn TEST_asymmetric_margin_SHOULD_FAIL_BELOW() {
+            assert_scalar_eq_approx!(10.0, 9.5, asymmetric_margin(0.25, 0.5));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=10.0, actual=10.5, margin_factor=0.5: acceptance interval [9.5, 10.25] (lower_factor=0.5, upper_factor=0.25)")]
+        fn TEST_asymmetric_margin_SHOULD_FAIL_ABOVE() {
+            assert_scalar_eq_approx!(10.0, 10.5, asymmetric_margin(0.5, 0.25));
+        }
+    }
+
+
+    mod TEST_full_scale {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_full_scale_BEHAVES_AS_FIXED_MARGIN() {
+            let e = full_scale(1000.0, 0.001);
+            let m = margin(1.0);
+
+            assert_eq!(1000.0, e.range());
+            assert_eq!(0.001, e.fraction());
+            assert_eq!(1.0, e.margin_factor());
+
+            for (expected, actual) in [
+                (0.0, 0.0),
+                (0.0, 0.5),
+                (0.0, 1.5),
+                (1.0, 1.9),
+                (1.0, 2.1),
+                (999.0, 998.5),
+                (999.0, 997.5),
+                (-5.0, -5.75),
+            ] {
+                assert_eq!(m.evaluate(expected, actual), e.evaluate(expected, actual));
+            }
+
+            assert_scalar_eq_approx!(0.0, 0.9, e);
+            assert_scalar_eq_approx!(500.0, 500.9, e);
+            assert_scalar_ne_approx!(500.0, 501.1, e);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=10.0, actual=11.5, margin_factor=1: full-scale range 1000, fraction 0.001")]
+        fn TEST_full_scale_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(10.0, 11.5, full_scale(1000.0, 0.001));
+        }
+    }
+
+
+    mod TEST_ppm {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_ppm_BEHAVES_AS_MULTIPLIER() {
+            let e = ppm(2.5);
+            let m = multiplier(2.5 / 1e6);
+
+            assert_eq!(2.5, e.parts_per_million());
+            assert_eq!(0.0000025, e.multiplier_factor());
+
+            for (expected, actual) in [
+                (0.0, 0.0),
+                (0.0, 0.000001),
+                (1.0, 1.000002),
+                (1.0, 1.000003),
+                (-1e6, -1e6 - 2.0),
+                (-1e6, -1e6 - 3.0),
+            ] {
+                assert_eq!(m.evaluate(expected, actual), e.evaluate(expected, actual));
+            }
+        }
+
+        #[test]
+        fn TEST_ppm_AT_1_PPM_BOUNDARY() {
+            let e = ppm(1.0);
+
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(1e6, 1e6 + 0.999).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(1e6, 1e6 - 0.999).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(1e6, 1e6 + 1.001).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(1e6, 1e6 - 1.001).0);
+
+            // a 10 MHz oscillator
+            assert_scalar_eq_approx!(10_000_000.0, 10_000_009.99, ppm(1.0));
+            assert_scalar_ne_approx!(10_000_000.0, 10_000_010.01, ppm(1.0));
+        }
+
+        #[test]
+        fn TEST_ppm_describe() {
+            let e = ppm(1.0);
+
+            assert_eq!(Some("error 3.200 ppm exceeds 1.000 ppm".into()), e.describe(1e6, 1e6 + 3.2));
+            assert_eq!(Some("error 0.500 ppm is within 1.000 ppm".into()), e.describe(1e6, 1e6 + 0.5));
+            assert_eq!(Some("error cannot be expressed in ppm of a zero expected value (tolerance 1.000 ppm)".into()), e.describe(0.0, 1.0));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=10000000.0, actual=10000032.0, multiplier_factor=0.000001: error 3.200 ppm exceeds 1.000 ppm")]
+        fn TEST_ppm_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(10_000_000.0, 10_000_032.0, ppm(1.0));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate inequality: expected=1000000.0, actual=1000000.0, multiplier_factor=0.000001: error 0.000 ppm is within 1.000 ppm")]
+        fn TEST_ppm_NE_SHOULD_FAIL() {
+            assert_scalar_ne_approx!(1e6, 1e6, ppm(1.0));
+        }
+    }
+
+
+    mod TEST_numpy_close {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_numpy_close_ACCESSORS() {
+            let e = numpy_close(1e-5, 1e-8);
+
+            assert_eq!(1e-5, e.rtol());
+            assert_eq!(1e-8, e.atol());
+        }
+
+        #[test]
+        fn TEST_numpy_close_AS_numpy_isclose() {
+            let e = numpy_close(1e-5, 1e-8);
+
+            // np.isclose([1e10, 1e-7], [1.00001e10, 1e-8]) -> [True, False]
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(1e10, 1.00001e10).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(1e-7, 1e-8).0);
+
+            // np.isclose([1e10, 1e-8], [1.0001e10, 1e-9]) -> [False, True]
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(1e10, 1.0001e10).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(1e-8, 1e-9).0);
+
+            // np.isclose([1e-100, 1e-7], [0.0, 0.0], atol=0.0) -> [False, False]
+            let e = numpy_close(1e-5, 0.0);
+
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(1e-100, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(1e-7, 0.0).0);
+
+            // np.isclose(1.0, 1.0) -> True
+            assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(1.0, 1.0).0);
+        }
+
+        #[test]
+        fn TEST_numpy_close_IS_RELATIVE_TO_ACTUAL() {
+            let e = numpy_close(0.1, 0.0);
+
+            // 0.105 <= 0.1 * 1.105, but not <= 0.1 * 1.0
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(1.0, 1.105).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(1.105, 1.0).0);
+        }
+
+        #[test]
+        fn TEST_numpy_close_SUMS_TOLERANCES() {
+            let e = numpy_close(0.01, 0.5);
+
+            // 1.0 <= 0.5 + 0.01 * 100.0, though neither tolerance alone suffices
+            assert_scalar_eq_approx!(99.0, 100.0, e);
+            assert_scalar_ne_approx!(98.0, 100.0, e);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=1e-7, actual=1e-8: |actual - expected| = 9e-8 exceeds atol + rtol * |actual| = 1.00001e-8 (rtol=0.00001, atol=0.00000001)")]
+        fn TEST_numpy_close_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(1e-7, 1e-8, numpy_close(1e-5, 1e-8));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate inequality: expected=99.0, actual=100.0: |actual - expected| = 1e0 <= atol + rtol * |actual| = 1.5e0 (rtol=0.01, atol=0.5)")]
+        fn TEST_numpy_close_NE_SHOULD_FAIL() {
+            assert_scalar_ne_approx!(99.0, 100.0, numpy_close(0.01, 0.5));
+        }
+    }
+
+
+    mod TEST_log_scale {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_log_scale_OCTAVES() {
+            let e = log_scale(2.0, 1.0);
+
+            assert_eq!(2.0, e.base());
+            assert_eq!(1.0, e.margin_factor());
+
+            // an octave apart is exactly 1.0 in base-2 log space
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(440.0, 880.0).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(440.0, 220.0).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(440.0, 1760.0).0);
+            assert_eq!(ComparisonResult::Unequal, log_scale(2.0, 0.5).evaluate(440.0, 880.0).0);
+
+            // a semitone is 1/12 of an octave
+            assert_eq!(ComparisonResult::ApproximatelyEqual, log_scale(2.0, 0.1).evaluate(440.0, 466.16).0);
+            assert_eq!(ComparisonResult::Unequal, log_scale(2.0, 0.05).evaluate(440.0, 466.16).0);
+        }
+
+        #[test]
+        fn TEST_log_scale_NON_POSITIVE() {
+            let e = log_scale(10.0, 0.1);
+
+            assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(0.0, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(0.0, 1e-300).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(-1.0, -1.01).0);
+            assert_eq!(Some("non-positive comparand cannot be compared in log space (base 10)".into()), e.describe(-1.0, -1.01));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=440.0, actual=1760.0, margin_factor=1: log-space difference 2 (base 2)")]
+        fn TEST_log_scale_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(440.0, 1760.0, log_scale(2.0, 1.0));
+        }
+    }
+
+
+    mod TEST_ulps {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::utils::ulps_distance;
+
+
+        #[test]
+        fn TEST_ulps_distance() {
+            assert_eq!(Some(0), ulps_distance(1.0, 1.0));
+            assert_eq!(Some(0), ulps_distance(0.0, -0.0));
+            assert_eq!(Some(1), ulps_distance(1.0, f64::from_bits(1.0f64.to_bits() + 1)));
+            assert_eq!(Some(4), ulps_distance(f64::from_bits(1.0f64.to_bits() - 2), f64::from_bits(1.0f64.to_bits() + 2)));
+            assert_eq!(Some(2), ulps_distance(-f64::from_bits(1), f64::from_bits(1)));
+            assert_eq!(None, ulps_distance(f64::NAN, 1.0));
+        }
+
+        #[test]
+        fn TEST_ulps_TEST_1() {
+            let e = ulps(4);
+            let next = |v : f64, n : u64| f64::from_bits(v.to_bits() + n);
+
+            assert_eq!(4, e.max_ulps());
+            assert_eq!((ComparisonResult::ExactlyEqual, None, None), e.evaluate(1.0, 1.0));
+            assert_eq!((ComparisonResult::ApproximatelyEqual, None, None), e.evaluate(1.0, next(1.0, 4)));
+            assert_eq!((ComparisonResult::Unequal, None, None), e.evaluate(1.0, next(1.0, 5)));
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(1e300, next(1e300, 3)).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(f64::MAX, f64::INFINITY).0);
+        }
+
+        #[test]
+        fn TEST_assert_scalar_eq_ulps_1() {
+            assert_scalar_eq_ulps!(0.1 + 0.2, 0.3, 1);
+            assert_scalar_eq_ulps!(1.0, 1.0, 0);
+            assert_scalar_ne_approx!(1.0, 1.0 + 1e-15, ulps(2));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=1.0, actual=1.0000000000000009: ULP distance 4 (allowed 2)")]
+        fn TEST_assert_scalar_eq_ulps_SHOULD_FAIL() {
+            assert_scalar_eq_ulps!(1.0, 1.0000000000000009, 2);
+        }
+    }
+
+
+    mod TEST_significant_figures {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            utils::round_to_significant_figures,
+            RoundingMode,
+        };
+
+
+        #[test]
+        fn TEST_round_to_significant_figures() {
+            assert_eq!(1.2, round_to_significant_figures(1.25, 2, RoundingMode::HalfEven));
+            assert_eq!(1.3, round_to_significant_figures(1.25, 2, RoundingMode::HalfUp));
+            assert_eq!(1.2, round_to_significant_figures(1.25, 2, RoundingMode::TowardZero));
+
+            assert_eq!(1.4, round_to_significant_figures(1.35, 2, RoundingMode::HalfEven));
+            assert_eq!(1.4, round_to_significant_figures(1.45, 2, RoundingMode::HalfEven));
+            assert_eq!(1.5, round_to_significant_figures(1.45, 2, RoundingMode::HalfUp));
+            assert_eq!(1.2, round_to_significant_figures(1.2, 2, RoundingMode::TowardZero));
+            assert_eq!(-0.13, round_to_significant_figures(-0.125, 2, RoundingMode::HalfUp));
+            assert_eq!(-0.12, round_to_significant_figures(-0.125, 2, RoundingMode::HalfEven));
+            assert_eq!(100.0, round_to_significant_figures(99.96, 3, RoundingMode::HalfEven));
+            assert_eq!(123000.0, round_to_significant_figures(123456.0, 3, RoundingMode::TowardZero));
+            assert_eq!(0.0, round_to_significant_figures(0.0, 3, RoundingMode::HalfUp));
+        }
+
+        #[test]
+        fn TEST_significant_figures_DEFAULTS_TO_HalfEven() {
+            let e = significant_figures(3);
+
+            assert_eq!(3, e.figures());
+            assert_eq!(RoundingMode::HalfEven, e.rounding_mode());
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(1234.0, 1231.0).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(1234.0, 1236.0).0);
+        }
+
+        #[test]
+        fn TEST_significant_figures_MODES_DISTINGUISHED_ON_TIE() {
+            let half_even = significant_figures_with_rounding_mode(2, RoundingMode::HalfEven);
+            let half_up = significant_figures_with_rounding_mode(2, RoundingMode::HalfUp);
+            let toward_zero = significant_figures_with_rounding_mode(2, RoundingMode::TowardZero);
+
+            // 1.25 is a tie at the 2nd figure
+            assert_eq!(ComparisonResult::ApproximatelyEqual, half_even.evaluate(1.25, 1.2).0);
+            assert_eq!(ComparisonResult::Unequal, half_even.evaluate(1.25, 1.3).0);
+
+            assert_eq!(ComparisonResult::Unequal, half_up.evaluate(1.25, 1.2).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, half_up.evaluate(1.25, 1.3).0);
+
+            assert_eq!(ComparisonResult::ApproximatelyEqual, toward_zero.evaluate(1.25, 1.2).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, toward_zero.evaluate(1.25, 1.29).0);
+            assert_eq!(ComparisonResult::Unequal, toward_zero.evaluate(1.25, 1.3).0);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=1.25, actual=1.2: rounded to 2 significant figure(s) (HalfUp): expected 1.3, actual 1.2")]
+        fn TEST_significant_figures_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(1.25, 1.2, significant_figures_with_rounding_mode(2, RoundingMode::HalfUp));
+        }
+    }
+
+
+    mod TEST_DEFAULT_TOLERANCE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::traits::DefaultTolerance;
+
+
+        #[test]
+        fn TEST_default_tolerance() {
+            assert_eq!((ComparisonResult::ApproximatelyEqual, Some(1e-4), Some(1e-6)), f64::default_tolerance().evaluate(1.0, 1.0000005));
+            assert_eq!(ComparisonResult::Unequal, f64::default_tolerance().evaluate(1.0, 1.000005).0);
+
+            assert_eq!((ComparisonResult::ApproximatelyEqual, Some(1e-4), Some(1e-5)), f32::default_tolerance().evaluate(1.0, 1.000005));
+            assert_eq!(ComparisonResult::Unequal, f32::default_tolerance().evaluate(1.0, 1.00005).0);
+        }
+
+        #[test]
+        fn TEST_TWO_PARAMETER_MACROS_USE_TYPE_DEFAULT() {
+            // f32 comparands obtain the looser f32 default ...
+            assert_scalar_eq_approx!(1.0_f32, 1.000005_f32);
+            assert_scalar_ne_approx!(1.0_f32, 1.00005_f32);
+            assert_vector_eq_approx!([1.0_f32, 2.0], [1.000005_f32, 2.00001]);
+            assert_vector_ne_approx!(vec![1.0_f32, 2.0], [1.00005_f32, 2.0]);
+
+            // ... whereas f64 comparands, including unsuffixed literals, do not
+            assert_scalar_ne_approx!(1.0, 1.000005);
+            assert_scalar_ne_approx!(1.0_f64, 1.000005_f64);
+            assert_vector_ne_approx!([1.0, 2.0], [1.000005, 2.0]);
+
+            // types without a default tolerance obtain the default evaluator
+            assert_scalar_eq_approx!(1, 1.0000005);
+            assert_scalar_ne_approx!(1, 1.000005);
+            assert_scalar_eq_approx!("1.0", 1.0000005);
+            assert_vector_eq_approx!([1, 2], [1.0000005, 2.0]);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=1.0, actual=1.00005, margin_factor=0.0001, multiplier_factor=0.00001")]
+        fn TEST_assert_scalar_eq_approx_WITH_F32_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(1.0_f32, 1.00005_f32);
+        }
+    }
+
+
+    mod TEST_Approx {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::Approx;
+
+
+        #[test]
+        fn TEST_Approx_new() {
+            let a = Approx::new(1.5, 0.01);
+
+            assert_eq!(1.5, a.value());
+            assert_eq!(0.01, a.margin());
+        }
+
+        #[test]
+        fn TEST_Approx_WITHOUT_EVALUATOR_USES_ITS_OWN_MARGIN() {
+            assert_scalar_eq_approx!(Approx::new(1.0, 0.1), 1.05);
+            assert_scalar_ne_approx!(Approx::new(1.0, 0.01), 1.05);
+
+            // labeled forms
+            assert_scalar_eq_approx!(expected = Approx::new(1.0, 0.1), actual = 1.05);
+            assert_scalar_eq_approx!(actual = 0.95, expected = Approx::new(1.0, 0.1));
+        }
+
+        #[test]
+        fn TEST_Approx_TABLE_WITH_PER_ROW_TOLERANCES() {
+            let table = [
+                (Approx::new(0.0, 1e-9), 1e-10),
+                (Approx::new(100.0, 0.5), 100.4),
+                (Approx::new(-3.0, 0.25), -3.2),
+            ];
+
+            for (expected, actual) in table {
+                assert_scalar_eq_approx!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn TEST_Approx_WITH_EVALUATOR_USES_EVALUATOR() {
+            assert_scalar_ne_approx!(Approx::new(1.0, 0.1), 1.05, margin(0.01));
+            assert_scalar_eq_approx!(Approx::new(1.0, 0.0), 1.05, margin(0.1));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=Approx { value: 1.0, margin: 0.01 }, actual=1.05, margin_factor=0.01")]
+        fn TEST_Approx_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(Approx::new(1.0, 0.01), 1.05);
+        }
+    }
+
+
+    mod TEST_Approximately {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::Approximately;
+
+
+        #[test]
+        fn TEST_Approximately_EVALUATOR() {
+            assert_eq!(margin(0.1).evaluate(1.0, 1.05), Approximately::new().margin(0.1).evaluator().evaluate(1.0, 1.05));
+            assert_eq!(multiplier(0.1).evaluate(10.0, 10.5), Approximately::new().multiplier(0.1).evaluator().evaluate(10.0, 10.5));
+            assert_eq!(
+                zero_margin_or_multiplier(0.1, 0.01).evaluate(0.0, 0.005),
+                Approximately::new().multiplier(0.1).margin(0.01).evaluator().evaluate(0.0, 0.005)
+            );
+            assert_eq!(
+                test_helpers::default_evaluator().evaluate(1.0, 1.0000001),
+                Approximately::new().evaluator().evaluate(1.0, 1.0000001)
+            );
+        }
+
+        #[test]
+        fn TEST_Approximately_NAN_EQUAL() {
+            assert_eq!(ComparisonResult::BothNaN, Approximately::new().nan_equal(true).evaluator().evaluate(f64::NAN, f64::NAN).0);
+            assert_eq!(ComparisonResult::Unequal, Approximately::new().nan_equal(true).evaluator().evaluate(f64::NAN, 1.0).0);
+            assert_eq!(ComparisonResult::Unequal, Approximately::new().nan_equal(false).evaluator().evaluate(f64::NAN, f64::NAN).0);
+
+            Approximately::new().margin(1e-6).nan_equal(true).assert_eq(f64::NAN, f64::NAN);
+            Approximately::new().nan_equal(false).assert_ne(f64::NAN, f64::NAN);
+        }
+
+        #[test]
+        fn TEST_Approximately_FLUENT_ASSERTS() {
+            Approximately::new().margin(1e-6).assert_eq(1.0, 1.0000005);
+            Approximately::new().margin(1e-6).assert_ne(1.0, 1.00001);
+            Approximately::new().multiplier(0.01).assert_eq(200, 201.5f32);
+            Approximately::new().assert_eq(0.1 + 0.2, 0.3);
+
+            Approximately::new().margin(0.01).assert_vector_eq([1.0, 2.0, 3.0], vec![1.001, 1.999, 3.0]);
+            Approximately::new().margin(0.01).assert_vector_ne(vec![1.0, 2.0], [1.0, 2.5]);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=1.0, actual=1.001, margin_factor=0.000001")]
+        fn TEST_Approximately_assert_eq_SHOULD_FAIL() {
+            Approximately::new().margin(1e-6).assert_eq(1.0, 1.001);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for vectors: at index 1 expected=2.0, actual=2.5, margin_factor=0.01")]
+        fn TEST_Approximately_assert_vector_eq_SHOULD_FAIL() {
+            Approximately::new().margin(0.01).assert_vector_eq([1.0, 2.0], [1.0, 2.5]);
+        }
+    }
+
+
+    mod TEST_parse_evaluator {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            parse_evaluator,
+            ParseError,
+        };
+
+
+        #[test]
+        fn TEST_parse_evaluator_VALID_SPECS() {
+            let e = parse_evaluator("margin:1e-6").unwrap();
+
+            assert_eq!((ComparisonResult::ApproximatelyEqual, Some(1e-6), None), e.evaluate(1.0, 1.0000005));
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(1.0, 1.000002).0);
+
+            let e = parse_evaluator(" multiplier : 0.001 ").unwrap();
+
+            assert_eq!((ComparisonResult::ApproximatelyEqual, None, Some(0.001)), e.evaluate(1000.0, 1000.5));
+
+            let e = parse_evaluator("ulps:4").unwrap();
+
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(1.0, 1.0_f64.next_up().next_up()).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(1.0, 1.0 + 5.0 * f64::EPSILON).0);
+
+            let e = parse_evaluator("zero_margin_or_multiplier:0.01, 0.5").unwrap();
+
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(0.0, 0.4).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(100.0, 102.0).0);
+
+            let e = parse_evaluator("margin_from_condition:1e-9,1000").unwrap();
+
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(1.0, 1.0 + 5e-7).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(1.0, 1.0 + 2e-6).0);
+
+            let e = parse_evaluator("numpy_close:0.01,0.5").unwrap();
+
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(0.0, 0.5).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(100.0, 101.5).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(100.0, 102.0).0);
+
+            // 1.25 rounds to 1.2 toward zero, but to 1.3 half up
+            let e = parse_evaluator("significant_figures_with_rounding_mode:2,toward_zero").unwrap();
+
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(1.25, 1.3).0);
+
+            let e = parse_evaluator("significant_figures_with_rounding_mode:2, half_up").unwrap();
+
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(1.25, 1.3).0);
+
+            for spec in [
+                "default",
+                "asymmetric_margin:0.1,0.2",
+                "full_scale:100,0.001",
+                "log_scale:10,0.01",
+                "ppm:5",
+                "relative_geomean:0.01",
+                "significant_figures:3",
+                "zero_margin_or_relative_geomean:0.01,0.001",
+            ] {
+                let e = parse_evaluator(spec).unwrap_or_else(|x| panic!("failed to parse '{spec}': {x}"));
+
+                assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(1.5, 1.5).0, "spec '{spec}'");
+            }
+        }
+
+        #[test]
+        fn TEST_parse_evaluator_MALFORMED_SPECS() {
+            assert_eq!(Some(ParseError::Empty), parse_evaluator("").err());
+            assert_eq!(Some(ParseError::Empty), parse_evaluator("  ").err());
+            assert_eq!(Some(ParseError::UnknownMode { mode : "tolerance".into() }), parse_evaluator("tolerance:0.1").err());
+            assert_eq!(Some(ParseError::UnknownMode { mode : "".into() }), parse_evaluator(":0.1").err());
+            assert_eq!(
+                Some(ParseError::WrongArgumentCount { mode : "margin".into(), expected : 1, actual : 0 }),
+                parse_evaluator("margin").err()
+            );
+            assert_eq!(
+                Some(ParseError::WrongArgumentCount { mode : "margin".into(), expected : 1, actual : 2 }),
+                parse_evaluator("margin:0.1,0.2").err()
+            );
+            assert_eq!(
+                Some(ParseError::InvalidArgument { mode : "margin".into(), argument : "".into() }),
+                parse_evaluator("margin:").err()
+            );
+            assert_eq!(
+                Some(ParseError::InvalidArgument { mode : "ulps".into(), argument : "4.5".into() }),
+                parse_evaluator("ulps:4.5").err()
+            );
+            assert_eq!(
+                Some(ParseError::WrongArgumentCount { mode : "numpy_close".into(), expected : 2, actual : 1 }),
+                parse_evaluator("numpy_close:1e-5").err()
+            );
+            assert_eq!(
+                Some(ParseError::InvalidArgument { mode : "significant_figures_with_rounding_mode".into(), argument : "HalfUp".into() }),
+                parse_evaluator("significant_figures_with_rounding_mode:3,HalfUp").err()
+            );
+        }
+
+        #[test]
+        fn TEST_parse_evaluator_OUT_OF_RANGE_ARGUMENTS() {
+            assert_eq!(
+                Some(ParseError::InvalidArgument { mode : "margin".into(), argument : "-1".into() }),
+                parse_evaluator("margin:-1").err()
+            );
+            assert_eq!(
+                Some(ParseError::InvalidArgument { mode : "multiplier".into(), argument : "nan".into() }),
+                parse_evaluator("multiplier:nan").err()
+            );
+            assert_eq!(
+                Some(ParseError::InvalidArgument { mode : "margin".into(), argument : "inf".into() }),
+                parse_evaluator("margin:inf").err()
+            );
+            assert_eq!(
+                Some(ParseError::InvalidArgument { mode : "ppm".into(), argument : "-3".into() }),
+                parse_evaluator("ppm:-3").err()
+            );
+            assert_eq!(
+                Some(ParseError::InvalidArgument { mode : "significant_figures".into(), argument : "0".into() }),
+                parse_evaluator("significant_figures:0").err()
+            );
+            assert_eq!(
+                Some(ParseError::InvalidArgument { mode : "log_scale".into(), argument : "1".into() }),
+                parse_evaluator("log_scale:1,0.1").err()
+            );
+            assert_eq!(
+                Some(ParseError::InvalidArgument { mode : "log_scale".into(), argument : "-2".into() }),
+                parse_evaluator("log_scale:-2,0.1").err()
+            );
+            assert_eq!(
+                Some(ParseError::InvalidArgument { mode : "asymmetric_margin".into(), argument : "-1".into() }),
+                parse_evaluator("asymmetric_margin:-1,1").err()
+            );
+            assert_eq!(
+                Some(ParseError::InvalidArgument { mode : "full_scale".into(), argument : "0".into() }),
+                parse_evaluator("full_scale:0,0.1").err()
+            );
+            assert_eq!(
+                Some(ParseError::InvalidArgument { mode : "full_scale".into(), argument : "-0.1".into() }),
+                parse_evaluator("full_scale:10,-0.1").err()
+            );
+            assert_eq!(
+                Some(ParseError::InvalidArgument { mode : "margin_from_condition".into(), argument : "0.5".into() }),
+                parse_evaluator("margin_from_condition:1e-9,0.5").err()
+            );
+            assert_eq!(
+                Some(ParseError::InvalidArgument { mode : "numpy_close".into(), argument : "-1e-8".into() }),
+                parse_evaluator("numpy_close:1e-5,-1e-8").err()
+            );
+            assert_eq!(
+                Some(ParseError::InvalidArgument { mode : "significant_figures_with_rounding_mode".into(), argument : "0".into() }),
+                parse_evaluator("significant_figures_with_rounding_mode:0,half_even").err()
+            );
+        }
+
+        #[test]
+        fn TEST_ParseError_Display() {
+            assert_eq!("evaluator spec is empty", ParseError::Empty.to_string());
+            assert_eq!(
+                "unknown evaluator mode 'tolerance'; expected one of: default, asymmetric_margin, full_scale, log_scale, margin, margin_from_condition, multiplier, numpy_close, ppm, relative_geomean, significant_figures, significant_figures_with_rounding_mode, ulps, zero_margin_or_multiplier, zero_margin_or_relative_geomean",
+                parse_evaluator("tolerance:0.1").err().unwrap().to_string()
+            );
+            assert_eq!(
+                "evaluator mode 'margin' requires 1 argument(s), but 2 given",
+                parse_evaluator("margin:0.1,0.2").err().unwrap().to_string()
+            );
+            assert_eq!("invalid argument 'abc' for evaluator mode 'multiplier'", parse_evaluator("multiplier:abc").err().unwrap().to_string());
+        }
+    }
+
+
+    mod TEST_presets {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::presets;
+
+
+        #[test]
+        fn TEST_presets_FACTORS() {
+            assert_eq!(4.0 * f32::EPSILON as f64, presets::single_precision().multiplier_factor());
+            assert_eq!(f32::EPSILON as f64, presets::single_precision().zero_margin_factor());
+            assert_eq!(4.0 * f64::EPSILON, presets::double_precision().multiplier_factor());
+            assert_eq!(f64::EPSILON, presets::double_precision().zero_margin_factor());
+            assert_eq!(0.005, presets::currency().factor());
+            assert_eq!(1e-9, presets::physics_si().factor());
+        }
+
+        #[test]
+        fn TEST_presets_IN_MACROS() {
+            assert_scalar_eq_approx!(0.1f32 as f64, 0.1, presets::single_precision());
+            assert_scalar_ne_approx!(0.1f32 as f64, 0.1, presets::double_precision());
+            assert_scalar_eq_approx!(0.1 + 0.2, 0.3, presets::double_precision());
+            assert_scalar_eq_approx!(19.99, 19.994, presets::currency());
+            assert_scalar_ne_approx!(19.99, 20.0, presets::currency());
+            assert_scalar_eq_approx!(6.62607015e-34, 6.626070150001e-34, presets::physics_si());
+            assert_scalar_ne_approx!(6.62607015e-34, 6.6260702e-34, presets::physics_si());
+        }
+    }
+
+
+    mod TEST_relative_geomean {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_relative_geomean_TEST_1() {
+            let e = relative_geomean(0.01);
+
+            assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(0.0, 0.0).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(100.0, 100.9).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(100.9, 100.0).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(100.0, 101.1).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(0.0, 0.0000001).0);
+
+            assert_eq!((None, Some(0.01)), (e.evaluate(1.0, 2.0).1, e.evaluate(1.0, 2.0).2));
+            assert_eq!(None, e.zero_margin_factor());
+        }
+
+        #[test]
+        fn TEST_zero_margin_or_relative_geomean_TEST_1() {
+            let e = zero_margin_or_relative_geomean(0.01, 0.001);
+
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(0.0, 0.0005).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(0.0, 0.002).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(100.0, 100.9).0);
+
+            assert_eq!((Some(0.001), Some(0.01)), (e.evaluate(1.0, 2.0).1, e.evaluate(1.0, 2.0).2));
+            assert_eq!(0.01, e.multiplier_factor());
+            assert_eq!(Some(0.001), e.zero_margin_factor());
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=1.0, actual=4.0, multiplier_factor=0.25: geometric mean 2, acceptance band ±0.5")]
+        fn TEST_relative_geomean_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(1.0, 4.0, relative_geomean(0.25));
+        }
+    }
+
+
+    mod TEST_nan_strict {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_nan_strict_TEST_1() {
+            let e = nan_strict(margin(0.001));
+
+            assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(1.0, 1.0).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(1.0, 1.001).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(1.0, 1.01).0);
+
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(f64::NAN, 1.0).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(1.0, f64::NAN).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(f64::NAN, f64::NAN).0);
+
+            assert_eq!(None, e.describe(1.0, 1.01));
+            assert_eq!(Some("comparand is NaN".into()), e.describe(f64::NAN, f64::NAN));
+        }
+
+        #[test]
+        fn TEST_nan_strict_TEST_2() {
+
+            assert_scalar_eq_approx!(1.0, 1.0001, nan_strict(margin(0.001)));
+            assert_scalar_ne_approx!(f64::NAN, f64::NAN, nan_strict(margin(0.001)));
+            assert_vector_ne_approx!([1.0, f64::NAN], [1.0, f64::NAN], nan_strict(margin(0.001)));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=NaN, actual=NaN, margin_factor=0.001: comparand is NaN")]
+        fn TEST_nan_strict_SHOULD_FAIL_1() {
+
+            assert_scalar_eq_approx!(f64::NAN, f64::NAN, nan_strict(margin(0.001)));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for vectors: at index 1 expected=2.0, actual=NaN, margin_factor=0.001: comparand is NaN")]
+        fn TEST_nan_strict_SHOULD_FAIL_2() {
+
+            assert_vector_eq_approx!([1.0, 2.0], [1.0, f64::NAN], nan_strict(margin(0.001)));
+        }
+    }
+
+
+    mod TEST_flush_subnormals_to_zero {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        const SUBNORMAL : f64 = f64::MIN_POSITIVE / 2.0;
+
+
+        #[test]
+        fn TEST_flush_subnormals_to_zero_1() {
+            assert!(SUBNORMAL.is_subnormal());
+
+            let m = multiplier(0.001);
+            let e = flush_subnormals_to_zero(m);
+
+            // without flushing, two subnormals (or a subnormal and zero)
+            // differ by far more than the multiplier allows
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(SUBNORMAL, SUBNORMAL * 1.5).0);
+            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.0, SUBNORMAL).0);
+
+            assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(SUBNORMAL, SUBNORMAL * 1.5).0);
+            assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(0.0, SUBNORMAL).0);
+            assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(-SUBNORMAL, 0.0).0);
+            assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(-SUBNORMAL, SUBNORMAL).0);
+        }
+
+        #[test]
+        fn TEST_flush_subnormals_to_zero_NORMALS_UNAFFECTED() {
+            let m = zero_margin_or_multiplier(0.001, 1e-300);
+            let e = flush_subnormals_to_zero(m);
+
+            for (expected, actual) in [
+                (1.0, 1.0005),
+                (1.0, 1.01),
+                (f64::MIN_POSITIVE, f64::MIN_POSITIVE),
+                (f64::MIN_POSITIVE, 0.0),
+                (0.0, 1e-299),
+            ] {
+                assert_eq!(m.evaluate(expected, actual), e.evaluate(expected, actual));
+            }
+
+            // a subnormal against a normal value is compared as zero, and so
+            // is subject to the zero-margin
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(SUBNORMAL, 1e-301).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(SUBNORMAL, 1e-299).0);
+        }
+
+        #[test]
+        fn TEST_flush_subnormals_to_zero_ASSERTS() {
+            assert_scalar_eq_approx!(SUBNORMAL, 0.0, flush_subnormals_to_zero(multiplier(0.001)));
+            assert_scalar_ne_approx!(SUBNORMAL, 0.0, multiplier(0.001));
+            assert_vector_eq_approx!([1.0, SUBNORMAL], [1.0, -SUBNORMAL], flush_subnormals_to_zero(multiplier(0.001)));
+        }
+    }
+
+
+    mod TEST_signed_zero_as_approx {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            signed_zero_as_approx,
+            vector_comparison,
+            VectorComparisonResult,
+        };
+
+
+        #[test]
+        fn TEST_signed_zero_as_approx_DOWNGRADES_SIGN_CHANGE() {
+            let e = signed_zero_as_approx(margin(0.001));
+
+            assert_eq!(ComparisonResult::ExactlyEqual, margin(0.001).evaluate(0.0, -0.0).0);
+
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(0.0, -0.0).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(-0.0, 0.0).0);
+            assert_eq!((ComparisonResult::ApproximatelyEqual, Some(0.001), None), e.evaluate(-0.0, 0.0));
+        }
+
+        #[test]
+        fn TEST_signed_zero_as_approx_OTHERWISE_DELEGATES() {
+            let e = signed_zero_as_approx(margin(0.001));
+
+            assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(0.0, 0.0).0);
+            assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(-0.0, -0.0).0);
+            assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(1.0, 1.0).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(0.0, 0.0005).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(-0.0, 0.01).0);
+            assert_eq!(0.001, e.inner().factor());
+        }
+
+        #[test]
+        fn TEST_signed_zero_as_approx_ASSERTIONS_STILL_PASS() {
+            assert_scalar_eq_approx!(0.0, -0.0, signed_zero_as_approx(margin(0.001)));
+            assert_vector_eq_approx!([1.0, -0.0], [1.0, 0.0], signed_zero_as_approx(margin(0.001)));
+
+            assert_eq!(
+                VectorComparisonResult::ApproximatelyEqual,
+                vector_comparison(&[1.0, -0.0], &[1.0, 0.0], &signed_zero_as_approx(margin(0.001)))
+            );
+        }
+    }
+
+
+    mod TEST_noise_floor {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_noise_floor_BOTH_BELOW() {
+            let e = noise_floor(1e-9, multiplier(0.001));
+
+            assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(0.0, 0.0).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(1e-12, 1e-9).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(-1e-9, 1e-10).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(0.0, -1e-9).0);
+
+            assert_scalar_eq_approx!(1e-12, -5e-10, noise_floor(1e-9, multiplier(0.001)));
+            assert_vector_eq_approx!([1.0, 1e-15], [1.0, 3e-10], noise_floor(1e-9, multiplier(0.001)));
+        }
+
+        #[test]
+        fn TEST_noise_floor_ONE_BELOW() {
+            let e = noise_floor(1e-9, multiplier(0.001));
+
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(1e-12, 2e-9).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(-2e-9, 0.0).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(f64::NAN, 0.0).0);
+
+            assert_scalar_ne_approx!(0.0, 2e-9, noise_floor(1e-9, multiplier(0.001)));
+        }
+
+        #[test]
+        fn TEST_noise_floor_BOTH_ABOVE() {
+            let e = noise_floor(1e-9, multiplier(0.001));
+
+            assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(1.0, 1.0).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(1.0, 1.0005).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(2e-9, 3e-9).0);
+            assert_eq!(e.inner().evaluate(1.0, 1.01), e.evaluate(1.0, 1.01));
+
+            assert_eq!(1e-9, e.floor());
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=1.0, actual=1.01, multiplier_factor=0.001")]
+        fn TEST_noise_floor_SHOULD_FAIL() {
+
+            assert_scalar_eq_approx!(1.0, 1.01, noise_floor(1e-9, multiplier(0.001)));
+        }
+    }
+
+
+    mod TEST_saturating {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_saturating_BOTH_SATURATED_SAME_SIGN() {
+            let e = saturating(10.0, margin(0.01));
+
+            assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(12.0, 12.0).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(10.5, 99.0).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(-11.0, -1e9).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(f64::INFINITY, 10.001).0);
+
+            assert_scalar_eq_approx!(32767.0, 40000.0, saturating(32000.0, margin(0.5)));
+            assert_vector_eq_approx!([0.5, 11.0, -12.0], [0.501, 15.0, -10.1], saturating(10.0, margin(0.01)));
+
+            assert_eq!(10.0, e.limit());
+        }
+
+        #[test]
+        fn TEST_saturating_BOTH_SATURATED_OPPOSITE_SIGN() {
+            let e = saturating(10.0, margin(0.01));
+
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(11.0, -11.0).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(-f64::INFINITY, f64::INFINITY).0);
+
+            assert_scalar_ne_approx!(-50.0, 50.0, saturating(10.0, margin(0.01)));
+        }
+
+        #[test]
+        fn TEST_saturating_ONE_SATURATED() {
+            let e = saturating(10.0, margin(0.01));
+
+            // the limit itself is not exceeded
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(10.0, 11.0).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(9.0, 11.0).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(f64::NAN, 11.0).0);
+            assert_eq!(e.inner().evaluate(1.0, 1.005), e.evaluate(1.0, 1.005));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=9.0, actual=11.0, margin_factor=0.01")]
+        fn TEST_saturating_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(9.0, 11.0, saturating(10.0, margin(0.01)));
+        }
+    }
+
+
+    mod TEST_sign_dependent {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            sign_dependent,
+            sign_dependent_with_zero_dispatch,
+            ZeroDispatch,
+        };
+
+
+        #[test]
+        fn TEST_sign_dependent_POSITIVE() {
+            let e = sign_dependent(margin(0.01), margin(0.1));
+
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(5.0, 5.005).0);
+            assert_eq!((ComparisonResult::Unequal, Some(0.01), None), e.evaluate(5.0, 5.05));
+
+            assert_scalar_eq_approx!(5.0, 5.005, sign_dependent(margin(0.01), margin(0.1)));
+            assert_scalar_ne_approx!(5.0, 5.05, sign_dependent(margin(0.01), margin(0.1)));
+        }
+
+        #[test]
+        fn TEST_sign_dependent_NEGATIVE() {
+            let e = sign_dependent(margin(0.01), margin(0.1));
+
+            assert_eq!((ComparisonResult::ApproximatelyEqual, Some(0.1), None), e.evaluate(-5.0, -5.05));
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(-5.0, -5.5).0);
+
+            // dispatch is on the sign of expected, not actual
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(-0.05, 0.04).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(0.05, -0.04).0);
+
+            assert_vector_eq_approx!([5.0, -5.0], [5.005, -5.05], sign_dependent(margin(0.01), margin(0.1)));
+        }
+
+        #[test]
+        fn TEST_sign_dependent_ZERO() {
+            let e = sign_dependent(margin(0.01), margin(0.1));
+
+            assert_eq!(ZeroDispatch::Positive, e.zero_dispatch());
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(0.0, 0.05).0);
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(-0.0, 0.05).0);
+
+            let e = sign_dependent_with_zero_dispatch(margin(0.01), margin(0.1), ZeroDispatch::Negative);
+
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(0.0, 0.05).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(-0.0, 0.05).0);
+
+            let e = sign_dependent_with_zero_dispatch(margin(0.01), margin(0.1), ZeroDispatch::SignBit);
+
+            assert_eq!(ComparisonResult::Unequal, e.evaluate(0.0, 0.05).0);
+            assert_eq!(ComparisonResult::ApproximatelyEqual, e.evaluate(-0.0, 0.05).0);
+
+            assert_eq!(0.01, e.positive().factor());
+            assert_eq!(0.1, e.negative().factor());
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=-5.0, actual=-5.5, margin_factor=0.1")]
+        fn TEST_sign_dependent_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(-5.0, -5.5, sign_dependent(margin(0.01), margin(0.1)));
+        }
+    }
+
+
+    mod TEST_EVALUATOR_INVARIANTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            check_evaluator_invariants,
+            EvaluatorInvariantViolation,
+        };
+
+
+        struct AlwaysExactlyEqualEvaluator;
+
+        impl ApproximateEqualityEvaluator for AlwaysExactlyEqualEvaluator {
+            fn evaluate(
+                &self,
+                _expected : f64,
+                _actual : f64,
+            ) -> (ComparisonResult, Option<f64>, Option<f64>) {
+                (ComparisonResult::ExactlyEqual, None, None)
+            }
+        }
+
+        struct AlwaysUnequalEvaluator;
+
+        impl ApproximateEqualityEvaluator for AlwaysUnequalEvaluator {
+            fn evaluate(
+                &self,
+                _expected : f64,
+                _actual : f64,
+            ) -> (ComparisonResult, Option<f64>, Option<f64>) {
+                (ComparisonResult::Unequal, None, None)
+            }
+        }
+
+
+        #[test]
+        fn TEST_check_evaluator_invariants_BUILTIN_EVALUATORS() {
+            let evaluators : [&dyn ApproximateEqualityEvaluator; 8] = [
+                &margin(0.001),
+                &multiplier(0.001),
+                &zero_margin_or_multiplier(0.001, 0.0001),
+                &asymmetric_margin(0.0, 0.5),
+                &relative_geomean(0.01),
+                &full_scale(10.0, 0.001),
+                &ulps(4),
+                &nan_strict(margin(0.0)),
+            ];
+
+            for seed in [ 0, 1, 0xDEAD_BEEF ] {
+                for e in evaluators {
+                    assert_eq!(Vec::<EvaluatorInvariantViolation>::new(), check_evaluator_invariants(e, seed));
+                }
+            }
+        }
+
+        #[test]
+        fn TEST_check_evaluator_invariants_BROKEN_EVALUATORS() {
+            let violations = check_evaluator_invariants(&AlwaysExactlyEqualEvaluator, 42);
+
+            assert!(!violations.is_empty());
+            assert!(violations.iter().all(|v| matches!(v, EvaluatorInvariantViolation::ExactlyEqualForUnequalComparands { .. })));
+            // two NaNs may be equal only as `ComparisonResult::BothNaN`
+            assert!(violations.iter().any(|v| matches!(v, EvaluatorInvariantViolation::ExactlyEqualForUnequalComparands { expected, actual } if expected.is_nan() && actual.is_nan())));
+
+            let violations = check_evaluator_invariants(&AlwaysUnequalEvaluator, 42);
+
+            assert!(!violations.is_empty());
+            assert!(violations.iter().all(|v| matches!(v, EvaluatorInvariantViolation::NotReflexive { value } if value.is_finite())));
+        }
+
+        #[test]
+        fn TEST_check_evaluator_invariants_IS_DETERMINISTIC() {
+            assert_eq!(check_evaluator_invariants(&AlwaysUnequalEvaluator, 7), check_evaluator_invariants(&AlwaysUnequalEvaluator, 7));
+            assert_ne!(check_evaluator_invariants(&AlwaysUnequalEvaluator, 7), check_evaluator_invariants(&AlwaysUnequalEvaluator, 8));
+        }
+    }
+
+
+    mod TEST_COMPARISON_RESULT_COMBINATION {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::fold_comparison_results;
+
+
+        #[test]
+        fn TEST_combine_ALL_PAIRS() {
+            use ComparisonResult::*;
+
+            assert_eq!(ExactlyEqual, ExactlyEqual.combine(ExactlyEqual));
+            assert_eq!(ApproximatelyEqual, ExactlyEqual.combine(ApproximatelyEqual));
+            assert_eq!(Unequal, ExactlyEqual.combine(Unequal));
+
+            assert_eq!(ApproximatelyEqual, ApproximatelyEqual.combine(ExactlyEqual));
+            assert_eq!(ApproximatelyEqual, ApproximatelyEqual.combine(ApproximatelyEqual));
+            assert_eq!(Unequal, ApproximatelyEqual.combine(Unequal));
+
+            assert_eq!(Unequal, Unequal.combine(ExactlyEqual));
+            assert_eq!(Unequal, Unequal.combine(ApproximatelyEqual));
+            assert_eq!(Unequal, Unequal.combine(Unequal));
+
+            assert_eq!(BothNaN, BothNaN.combine(ExactlyEqual));
+            assert_eq!(BothNaN, ExactlyEqual.combine(BothNaN));
+            assert_eq!(BothNaN, BothNaN.combine(BothNaN));
+            assert_eq!(ApproximatelyEqual, BothNaN.combine(ApproximatelyEqual));
+            assert_eq!(Unequal, BothNaN.combine(Unequal));
+        }
+
+        #[test]
+        fn TEST_ORDERING_IS_BY_SEVERITY() {
+            use std::cmp::Ordering;
+
+            use ComparisonResult::*;
+
+            assert!(ExactlyEqual < ApproximatelyEqual);
+            assert!(ApproximatelyEqual < Unequal);
+            assert!(ExactlyEqual < Unequal);
+            assert!(ExactlyEqual < BothNaN);
+            assert!(BothNaN < ApproximatelyEqual);
+
+            assert_eq!(Some(Ordering::Equal), ApproximatelyEqual.partial_cmp(&ApproximatelyEqual));
+            assert_eq!(Some(Ordering::Greater), Unequal.partial_cmp(&ExactlyEqual));
+
+            // only a failed result is worse than ApproximatelyEqual
+            assert!(ExactlyEqual <= ApproximatelyEqual);
+            assert!(ApproximatelyEqual <= ApproximatelyEqual);
+            assert!(Unequal > ApproximatelyEqual);
+        }
+
+        #[test]
+        fn TEST_is_exact_AND_is_approximate() {
+            assert!(ComparisonResult::ExactlyEqual.is_exact());
+            assert!(!ComparisonResult::ApproximatelyEqual.is_exact());
+            assert!(!ComparisonResult::Unequal.is_exact());
+
+            assert!(!ComparisonResult::ExactlyEqual.is_approximate());
+            assert!(ComparisonResult::ApproximatelyEqual.is_approximate());
+            assert!(!ComparisonResult::Unequal.is_approximate());
+
+            assert!(!ComparisonResult::BothNaN.is_exact());
+            assert!(!ComparisonResult::BothNaN.is_approximate());
+            assert!(ComparisonResult::BothNaN.is_both_nan());
+            assert!(!ComparisonResult::ExactlyEqual.is_both_nan());
+        }
+
+        #[test]
+        fn TEST_summary_tag() {
+            use test_helpers::VectorComparisonResult;
+
+            assert_eq!("PASS_EXACT", ComparisonResult::ExactlyEqual.summary_tag());
+            assert_eq!("PASS_BOTH_NAN", ComparisonResult::BothNaN.summary_tag());
+            assert_eq!("PASS_APPROX", ComparisonResult::ApproximatelyEqual.summary_tag());
+            assert_eq!("FAIL_UNEQUAL", ComparisonResult::Unequal.summary_tag());
+
+            assert_eq!("PASS_EXACT", VectorComparisonResult::ExactlyEqual.summary_tag());
+            assert_eq!("PASS_APPROX", VectorComparisonResult::ApproximatelyEqual.summary_tag());
+            assert_eq!("FAIL_LENGTH@2:3", VectorComparisonResult::DifferentLengths { expected_length : 2, actual_length : 3 }.summary_tag());
+            assert_eq!(
+                "FAIL_UNEQUAL@3",
+                VectorComparisonResult::UnequalElements {
+                    index_of_first_unequal_element :          3,
+                    expected_value_of_first_unequal_element : 1.0,
+                    actual_value_of_first_unequal_element :   2.0,
+                }
+                .summary_tag()
+            );
+        }
+
+        #[test]
+        fn TEST_passed_AND_failed() {
+            use test_helpers::VectorComparisonResult;
+
+            assert!(ComparisonResult::ExactlyEqual.passed());
+            assert!(ComparisonResult::BothNaN.passed());
+            assert!(ComparisonResult::ApproximatelyEqual.passed());
+            assert!(!ComparisonResult::Unequal.passed());
+
+            assert!(!ComparisonResult::ExactlyEqual.failed());
+            assert!(!ComparisonResult::BothNaN.failed());
+            assert!(!ComparisonResult::ApproximatelyEqual.failed());
+            assert!(ComparisonResult::Unequal.failed());
+
+            let unequal_elements = VectorComparisonResult::UnequalElements {
+                index_of_first_unequal_element :          0,
+                expected_value_of_first_unequal_element : 1.0,
+                actual_value_of_first_unequal_element :   2.0,
+            };
+            let different_lengths = VectorComparisonResult::DifferentLengths { expected_length : 1, actual_length : 2 };
+
+            assert!(VectorComparisonResult::ExactlyEqual.passed());
+            assert!(VectorComparisonResult::ApproximatelyEqual.passed());
+            assert!(!different_lengths.passed());
+            assert!(!unequal_elements.passed());
+
+            assert!(!VectorComparisonResult::ExactlyEqual.failed());
+            assert!(!VectorComparisonResult::ApproximatelyEqual.failed());
+            assert!(different_lengths.failed());
+            assert!(unequal_elements.failed());
+        }
+
+        #[test]
+        fn TEST_fold_comparison_results() {
+            use ComparisonResult::*;
+
+            assert_eq!(ExactlyEqual, fold_comparison_results([]));
+            assert_eq!(ExactlyEqual, fold_comparison_results([ ExactlyEqual, ExactlyEqual ]));
+            assert_eq!(ApproximatelyEqual, fold_comparison_results([ ExactlyEqual, ApproximatelyEqual, ExactlyEqual ]));
+            assert_eq!(Unequal, fold_comparison_results([ ApproximatelyEqual, Unequal, ExactlyEqual ]));
+
+            let e = margin(0.01);
+
+            assert_eq!(ApproximatelyEqual, fold_comparison_results([ (1.0, 1.0), (2.0, 2.005) ].iter().map(|&(x, y)| e.evaluate(x, y).0)));
+        }
+
+        #[test]
+        fn TEST_result_passes_BOTH_POLARITIES() {
+            use ComparisonResult::*;
+
+            for (result, passes_eq) in [ (ExactlyEqual, true), (BothNaN, true), (ApproximatelyEqual, true), (Unequal, false) ] {
+                assert_eq!(passes_eq, test_helpers::result_passes(&result, true));
+                assert_eq!(!passes_eq, test_helpers::result_passes(&result, false));
+                assert_eq!(!passes_eq, result.is_unequal());
+            }
+        }
+    }
+
+
+    mod TEST_SUGGESTIONS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            suggest_margin,
+            suggest_multiplier,
+        };
+
+
+        #[test]
+        fn TEST_suggest_margin_1() {
+            assert_eq!(0.0, suggest_margin(&[]));
+            assert_eq!(0.0, suggest_margin(&[(1.0, 1.0), (-2.0, -2.0)]));
+            assert_eq!(0.5, suggest_margin(&[(1.0, 1.25), (2.0, 1.5), (0.0, 0.0)]));
+            assert_eq!(f64::INFINITY, suggest_margin(&[(1.0, 1.25), (1.0, f64::NAN)]));
+            assert_eq!(f64::INFINITY, suggest_margin(&[(1.0, f64::INFINITY)]));
+        }
+
+        #[test]
+        fn TEST_suggest_margin_ACCEPTS_ALL_PAIRS() {
+            let pairs = [(0.1, 0.3), (1.0, 1.1), (1e6, 1e6 + 0.2), (-0.7, -0.6)];
+
+            let m = margin(suggest_margin(&pairs));
+
+            for (expected, actual) in pairs {
+                assert_scalar_eq_approx!(expected, actual, m);
+            }
+        }
+
+        #[test]
+        fn TEST_suggest_multiplier_1() {
+            assert_eq!(0.0, suggest_multiplier(&[]));
+            assert_eq!(0.0, suggest_multiplier(&[(1.0, 1.0), (0.0, 0.0)]));
+            assert_eq!(0.25, suggest_multiplier(&[(2.0, 2.25), (-4.0, -3.0)]));
+            assert_eq!(f64::INFINITY, suggest_multiplier(&[(0.0, 0.001)]));
+            assert_eq!(f64::INFINITY, suggest_multiplier(&[(f64::NAN, 1.0)]));
+        }
+
+        #[test]
+        fn TEST_suggest_multiplier_ACCEPTS_ALL_PAIRS() {
+            let pairs = [(0.1, 0.3), (1.0, 1.1), (1e6, 1e6 + 0.2), (-0.7, -0.6), (3.0, 2.9)];
+
+            let m = multiplier(suggest_multiplier(&pairs));
+
+            for (expected, actual) in pairs {
+                assert_scalar_eq_approx!(expected, actual, m);
+            }
+        }
+    }
+
+
+    mod TEST_evaluate_and_describe_scalar {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            default_evaluator,
+            evaluate_and_describe_scalar,
+        };
+
+
+        fn panic_message_of<F : FnOnce()>(f : F) -> String {
+            let payload = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).expect_err("expected a panic");
+
+            match payload.downcast::<String>() {
+                Ok(message) => *message,
+                Err(payload) => payload.downcast::<&str>().map(|message| message.to_string()).unwrap(),
+            }
+        }
+
+
+        #[test]
+        fn TEST_evaluate_and_describe_scalar_PASS() {
+            assert_eq!((ComparisonResult::ExactlyEqual, String::new()), evaluate_and_describe_scalar(&1.0, &1.0, &margin(1e-6)));
+            assert_eq!((ComparisonResult::ApproximatelyEqual, String::new()), evaluate_and_describe_scalar(&1.0, &1.0000005, &margin(1e-6)));
+        }
+
+        #[test]
+        fn TEST_evaluate_and_describe_scalar_FAIL() {
+            let (comparison_result, diagnostic) = evaluate_and_describe_scalar(&1.23456, &1.23457, &margin(1e-6));
+
+            assert_eq!(ComparisonResult::Unequal, comparison_result);
+            assert!(
+                diagnostic.starts_with(
+                    "assertion failed: failed to verify approximate equality: expected=1.23456, actual=1.23457, margin_factor=0.000001 (agree to 5 significant digits)"
+                ),
+                "{diagnostic}"
+            );
+        }
+
+        #[test]
+        fn TEST_evaluate_and_describe_scalar_MATCHES_MACRO_PANIC_MESSAGE() {
+            for (expected, actual, evaluator) in [
+                (1.23456, 1.23457, margin(1e-6).boxed()),
+                (100.0, 101.0, multiplier(0.001).boxed()),
+                (0.0, 1e-3, default_evaluator().boxed()),
+                (1.0, f64::INFINITY, ulps(4).boxed()),
+            ] {
+                let (comparison_result, diagnostic) = evaluate_and_describe_scalar(&expected, &actual, &evaluator);
+
+                assert_eq!(ComparisonResult::Unequal, comparison_result);
+                assert_eq!(
+                    diagnostic,
+                    panic_message_of(|| {
+                        assert_scalar_eq_approx!(expected, actual, evaluator);
+                    })
+                );
+            }
+        }
+    }
+
+
+    mod TEST_SCALAR_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        struct CustomEvaluator{}
+
+        impl ApproximateEqualityEvaluator for CustomEvaluator {
+            fn evaluate(
+                &self,
+                expected : f64,
+                actual : f64,
+            ) -> (
+                ComparisonResult, // comparison_result
+                Option<f64>,      // margin_factor
+                Option<f64>,      // multiplier_factor
+            )
+            {
+                (
+                    if expected == actual {
+                        ComparisonResult::ExactlyEqual
+                    } else {
+                        ComparisonResult::Unequal
+                    },
+                    Some(0.0),
+                    Some(0.0),
+                )
+            }
+        }
+
+
+        #[test]
+        fn TEST_assert_scalar_eq_approx_2_PARAMETER_FOR_EXACTLY_EQUAL_VALUES() {
+
+            assert_scalar_eq_approx!(-1.23456789e-10, -1.23456789e-10);
+            assert_scalar_eq_approx!(-0.123456789, -0.123456789);
+            assert_scalar_eq_approx!(-0.1, -0.1);
+            assert_scalar_eq_approx!(0.0, 0.0);
+            assert_scalar_eq_approx!(0.1, 0.1);
+            assert_scalar_eq_approx!(0.123456789, 0.123456789);
+            assert_scalar_eq_approx!(1.23456789e+10, 1.23456789e+10);
+
+            assert_scalar_eq_approx!(f64::INFINITY, f64::INFINITY);
+            assert_scalar_eq_approx!(f64::NEG_INFINITY, f64::NEG_INFINITY);
+
+            assert_scalar_eq_approx!(f64::MIN, f64::MIN);
+            assert_scalar_eq_approx!(f64::MIN_POSITIVE, f64::MIN_POSITIVE);
+            assert_scalar_eq_approx!(f64::MAX, f64::MAX);
+
+            #[cfg(feature = "nan-equality")]
+            {
+                assert_scalar_eq_approx!(f64::NAN, f64::NAN);
+            }
+            #[cfg(not(feature = "nan-equality"))]
+            {
+                assert_scalar_ne_approx!(f64::NAN, f64::NAN);
+            }
+
+            {
+                use std::f64::consts::*;
+
+                assert_scalar_eq_approx!(PI, PI);
+                assert_scalar_eq_approx!(TAU, TAU);
+                assert_scalar_eq_approx!(PHI, PHI);
+                assert_scalar_eq_approx!(EGAMMA, EGAMMA);
+                assert_scalar_eq_approx!(FRAC_PI_2, FRAC_PI_2);
+                assert_scalar_eq_approx!(FRAC_PI_3, FRAC_PI_3);
+                assert_scalar_eq_approx!(FRAC_PI_4, FRAC_PI_4);
+                assert_scalar_eq_approx!(FRAC_PI_6, FRAC_PI_6);
+                assert_scalar_eq_approx!(FRAC_PI_8, FRAC_PI_8);
+                assert_scalar_eq_approx!(FRAC_1_PI, FRAC_1_PI);
+                assert_scalar_eq_approx!(FRAC_1_SQRT_PI, FRAC_1_SQRT_PI);
+                assert_scalar_eq_approx!(FRAC_1_SQRT_2PI, FRAC_1_SQRT_2PI);
+                assert_scalar_eq_approx!(FRAC_2_PI, FRAC_2_PI);
+                assert_scalar_eq_approx!(FRAC_2_SQRT_PI, FRAC_2_SQRT_PI);
+                assert_scalar_eq_approx!(SQRT_2, SQRT_2);
+                assert_scalar_eq_approx!(FRAC_1_SQRT_2, FRAC_1_SQRT_2);
+                assert_scalar_eq_approx!(SQRT_3, SQRT_3);
+                assert_scalar_eq_approx!(FRAC_1_SQRT_3, FRAC_1_SQRT_3);
+                assert_scalar_eq_approx!(E, E);
+                assert_scalar_eq_approx!(LOG2_10, LOG2_10);
+                assert_scalar_eq_approx!(LOG2_E, LOG2_E);
+                assert_scalar_eq_approx!(LOG10_2, LOG10_2);
+                assert_scalar_eq_approx!(LOG10_E, LOG10_E);
+                assert_scalar_eq_approx!(LN_2, LN_2);
+                assert_scalar_eq_approx!(LN_10, LN_10);
+            }
+        }
+
+        #[cfg(feature = "nan-equality")]
+        #[test]
+        fn TEST_NAN_EQUALITY_IS_REPORTED_AS_BothNaN() {
+            use test_helpers::{
+                default_evaluator,
+                evaluate_scalar_eq_approx,
+            };
+
+            assert_eq!(ComparisonResult::BothNaN, default_evaluator().evaluate(f64::NAN, f64::NAN).0);
+            assert_eq!(ComparisonResult::BothNaN, margin(0.1).evaluate(f64::NAN, f64::NAN).0);
+            assert_eq!(ComparisonResult::BothNaN, multiplier(0.1).evaluate(f64::NAN, f64::NAN).0);
+            assert_eq!(ComparisonResult::Unequal, margin(0.1).evaluate(f64::NAN, 1.0).0);
+
+            assert_eq!(ComparisonResult::BothNaN, evaluate_scalar_eq_approx(&f64::NAN, &f64::NAN, &default_evaluator()).0);
+
+            // still a pass, for scalars and for vectors
+            assert_scalar_eq_approx!(f64::NAN, f64::NAN, margin(0.1));
+            assert_vector_eq_approx!([1.0, f64::NAN], [1.0, f64::NAN]);
+        }
+
+        #[test]
+        #[cfg_attr(not(feature = "nan-equality"), should_panic(expected = "assertion failed: failed to verify approximate equality: expected=NaN, actual=NaN, margin_factor=0.0001, multiplier_factor=0.000001"))]
+        fn TEST_assert_scalar_eq_approx_2_PARAMETER_WITH_NAN() {
+
+            assert_scalar_eq_approx!(f64::NAN, f64::NAN);
+        }
+        #[test]
+        #[cfg_attr(feature = "nan-equality", should_panic(expected = "assertion failed: failed to verify approximate inequality: expected=NaN, actual=NaN, margin_factor=0.0001, multiplier_factor=0.000001"))]
+        fn TEST_assert_scalar_ne_approx_2_PARAMETER_WITH_NAN() {
+
+            assert_scalar_ne_approx!(f64::NAN, f64::NAN);
+        }
+
+        /// Demonstrate that feature `"nan-equality"` only changes stock behaviour
+        #[test]
+        fn TEST_assert_scalar_ne_approx_3_PARAMETER_WITH_CustomEvaluator() {
+
+            assert_scalar_ne_approx!(f64::NAN, f64::NAN, CustomEvaluator{});
+        }
+
+        #[test]
+        fn TEST_assert_scalar_eq_approx_2_PARAMETER_FOR_APPROXIMATELY_EQUAL_VALUES() {
+
+            assert_scalar_eq_approx!(0.12345678, 0.12345679);
+            assert_scalar_eq_approx!(0.12345678, 0.12345677);
+        }
+
+        #[test]
+        fn TEST_assert_scalar_eq_approx_3_PARAMETER_margin_FOR_APPROXIMATELY_EQUAL_VALUES() {
+            assert_scalar_eq_approx!(0.12345678, 0.12345679, margin(0.1));
+            assert_scalar_eq_approx!(0.12345678, 0.12345679, margin(0.01));
+            assert_scalar_eq_approx!(0.12345678, 0.12345679, margin(0.001));
+            assert_scalar_eq_approx!(0.12345678, 0.12345679, margin(0.0001));
+            assert_scalar_eq_approx!(0.12345678, 0.12345679, margin(0.00001));
+            assert_scalar_eq_approx!(0.12345678, Box::new(0.12345679), margin(0.000001));
+            assert_scalar_eq_approx!(std_rc::Rc::new(0.123456780), 0.12345679, margin(0.0000001));
+            assert_scalar_eq_approx!(0.12345678, 0.12345679, margin(0.00000001));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=0.12345678, actual=0.12345679, margin_factor=0.000000001")]
+        fn TEST_assert_scalar_eq_approx_3_PARAMETER_margin_SHOULD_FAIL_1() {
+            assert_scalar_eq_approx!(0.12345678, 0.12345679, margin(0.000000001));
+        }
+
+        #[cfg(feature = "pretty")]
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=0.12345678, actual=0.12345679, margin_factor=0.000000001 (agree to 7 significant digits) (would pass at margin 1.0000e-8, 10.0000x current)\n  expected: 0.12345678\n  actual:   0.12345679\n                     ^")]
+        fn TEST_assert_scalar_eq_approx_PRETTY_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(0.12345678, 0.12345679, margin(0.000000001));
+        }
+
+        #[test]
+        fn TEST_assert_scalar_eq_approx_LABELED() {
+            assert_scalar_eq_approx!(expected = 1.0, actual = 1.00001, margin(0.0001));
+            assert_scalar_eq_approx!(actual = 1.00001, expected = 1.0, margin(0.0001));
+            assert_scalar_eq_approx!(expected = 0.12345678, actual = 0.12345678);
+            assert_scalar_eq_approx!(actual = 0.12345678, expected = 0.12345678,);
+            assert_scalar_ne_approx!(expected = 1.0, actual = 1.1, margin(0.0001));
+            assert_scalar_ne_approx!(actual = 1.1, expected = 1.0);
+
+            let expected = 2.0;
+            let actual = 2.0;
+
+            assert_scalar_eq_approx!(expected, actual);
+        }
+
+        #[test]
+        fn TEST_assert_scalar_eq_approx_TRAILING_COMMA() {
+            assert_scalar_eq_approx!(1.0, 1.00001, margin(0.0001),);
+            assert_scalar_eq_approx!(0.12345678, 0.12345678,);
+            assert_scalar_eq_approx!(expected = 1.0, actual = 1.00001, margin(0.0001),);
+            assert_scalar_ne_approx!(1.0, 1.1, margin(0.0001),);
+            assert_scalar_ne_approx!(1.0, 1.1,);
+            assert_scalar_ne_approx!(actual = 1.1, expected = 1.0, margin(0.0001),);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=1.0, actual=1.1, margin_factor=0.0001")]
+        fn TEST_assert_scalar_eq_approx_LABELED_REVERSED_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(actual = 1.1, expected = 1.0, margin(0.0001));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate inequality: expected=0.12345678, actual=0.12345678, margin_factor=0.0001, multiplier_factor=0.000001")]
+        fn TEST_assert_scalar_ne_approx_2_PARAMETER_FOR_APPROXIMATELY_EQUAL_VALUES_SHOULD_FAIL_1() {
+
+            assert_scalar_ne_approx!(0.12345678, 0.12345678);
+        }
+    }
+
+
+    mod TEST_evaluate_vector_eq_approx_with_progress {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_vector_eq_approx,
+            evaluate_vector_eq_approx_with_progress,
+            VectorComparisonResult,
+        };
+
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_with_progress_1() {
+            let expected : Vec<f64> = (0..10).map(f64::from).collect();
+            let actual : Vec<f64> = expected.iter().map(|v| v + 0.00001).collect();
+
+            let mut counts = Vec::new();
+
+            let (comparison_result, margin_factor, _) = evaluate_vector_eq_approx_with_progress(&expected, &actual, &margin(0.001), 3, |n| counts.push(n));
+
+            assert!(matches!(comparison_result, VectorComparisonResult::ApproximatelyEqual));
+            assert_eq!(Some(0.001), margin_factor);
+            assert_eq!(vec![ 3, 6, 9 ], counts);
+        }
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_with_progress_STOPS_AT_FIRST_UNEQUAL_ELEMENT() {
+            let expected = [ 1.0, 2.0, 3.0, 4.0, 5.0 ];
+            let actual = [ 1.0, 2.0, 3.0, 4.5, 5.0 ];
+
+            let mut counts = Vec::new();
+
+            let (comparison_result, _, _) = evaluate_vector_eq_approx_with_progress(&expected, &actual, &margin(0.001), 1, |n| counts.push(n));
+
+            assert!(matches!(comparison_result, VectorComparisonResult::UnequalElements { index_of_first_unequal_element : 3, .. }));
+            assert!(matches!(evaluate_vector_eq_approx(&expected, &actual, &margin(0.001)).0, VectorComparisonResult::UnequalElements { index_of_first_unequal_element : 3, .. }));
+            assert_eq!(vec![ 1, 2, 3 ], counts);
+        }
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_with_progress_WITH_ZERO_INTERVAL() {
+            let expected = [ 1.0, 2.0, 3.0 ];
+
+            let mut counts = Vec::new();
+
+            let (comparison_result, _, _) = evaluate_vector_eq_approx_with_progress(&expected, &expected, &margin(0.001), 0, |n| counts.push(n));
+
+            assert!(matches!(comparison_result, VectorComparisonResult::ExactlyEqual));
+            assert!(counts.is_empty());
+        }
+    }
+
+
+    mod TEST_VECTOR_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        /// Evaluator that reports neither margin nor multiplier factor.
+        struct FactorlessEvaluator;
+
+        impl ApproximateEqualityEvaluator for FactorlessEvaluator {
+            fn evaluate(
+                &self,
+                expected : f64,
+                actual : f64,
+            ) -> (ComparisonResult, Option<f64>, Option<f64>) {
+                if expected == actual {
+                    (ComparisonResult::ExactlyEqual, None, None)
+                } else if (expected - actual).abs() < 0.5 {
+                    (ComparisonResult::ApproximatelyEqual, None, None)
+                } else {
+                    (ComparisonResult::Unequal, None, None)
+                }
+            }
+        }
+
+        /// Comparand that counts the number of times it is converted.
+        #[derive(Debug)]
+        struct CountingComparand<'a> {
+            value :       f64,
+            conversions : &'a std::cell::Cell<usize>,
+        }
+
+        impl base_traits::ToF64 for CountingComparand<'_> {
+            fn to_f64(&self) -> f64 {
+                self.conversions.set(self.conversions.get() + 1);
+
+                self.value
+            }
+        }
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_CONVERTS_EACH_ELEMENT_AT_MOST_ONCE() {
+            use test_helpers::{
+                evaluate_vector_eq_approx,
+                VectorComparisonResult,
+            };
+
+            let conversions = std::cell::Cell::new(0);
+            let make = |values : &[f64]| values.iter().map(|&value| CountingComparand { value, conversions : &conversions }).collect::<Vec<_>>();
+
+            // all equal: each element (of each side) is converted once
+            {
+                let expected = make(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+                let actual = make(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+
+                conversions.set(0);
+
+                assert_eq!(VectorComparisonResult::ExactlyEqual, evaluate_vector_eq_approx(&expected, &actual, &margin(0.01)).0);
+                assert_eq!(10, conversions.get());
+            }
+
+            // mismatch at index 2: the mismatching pair is converted only
+            // once, and no element beyond it is converted
+            {
+                let expected = make(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+                let actual = make(&[1.0, 2.0, 3.5, 4.5, 5.5]);
+
+                conversions.set(0);
+
+                assert_eq!(
+                    VectorComparisonResult::UnequalElements {
+                        index_of_first_unequal_element :          2,
+                        expected_value_of_first_unequal_element : 3.0,
+                        actual_value_of_first_unequal_element :   3.5,
+                    },
+                    evaluate_vector_eq_approx(&expected, &actual, &margin(0.01)).0
+                );
+                assert_eq!(6, conversions.get());
+            }
+        }
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_with_AGREES_WITH_DYN_FORM() {
+            use test_helpers::{
+                evaluate_vector_eq_approx,
+                evaluate_vector_eq_approx_with,
+            };
+
+            let expected = [ 1.0, 2.0, 3.0, 4.0 ];
+
+            for actual in [ [ 1.0, 2.0, 3.0, 4.0 ], [ 1.0, 2.0, 3.0, 4.005 ], [ 1.0, 2.5, 3.0, 4.0 ] ] {
+                assert_eq!(evaluate_vector_eq_approx(&expected, &actual, &margin(0.01)), evaluate_vector_eq_approx_with(&expected, &actual, &margin(0.01)));
+                assert_eq!(evaluate_vector_eq_approx(&expected, &actual, &multiplier(0.01)), evaluate_vector_eq_approx_with(&expected, &actual, &multiplier(0.01)));
+                assert_eq!(evaluate_vector_eq_approx(&expected, &actual, &FactorlessEvaluator), evaluate_vector_eq_approx_with(&expected, &actual, &FactorlessEvaluator));
+            }
+
+            assert_eq!(evaluate_vector_eq_approx(&expected, &[ 1.0 ], &margin(0.01)), evaluate_vector_eq_approx_with(&expected, &[ 1.0 ], &margin(0.01)));
+        }
+
+        #[test]
+        fn TEST_vector_comparison() {
+            use test_helpers::{
+                vector_comparison,
+                VectorComparisonResult,
+            };
+
+            let e = margin(0.01);
+
+            assert_eq!(VectorComparisonResult::ExactlyEqual, vector_comparison(&[1.0, 2.0], &vec![1.0, 2.0], &e));
+            assert_eq!(VectorComparisonResult::ApproximatelyEqual, vector_comparison(&[1.0, 2.0], &[1.0, 2.005], &e));
+            assert_eq!(
+                VectorComparisonResult::DifferentLengths { expected_length : 2, actual_length : 1 },
+                vector_comparison(&[1.0, 2.0], &[1.0], &e)
+            );
+            assert!(vector_comparison(&[1.0, 2.0], &[1.0, 2.5], &e).failed());
+        }
+
+        #[test]
+        fn TEST_assert_vector_eq_approx_LABELED() {
+            assert_vector_eq_approx!(expected = [1.0, 2.0], actual = vec![1.0, 2.00001], margin(0.0001));
+            assert_vector_eq_approx!(actual = [1.0, 2.0], expected = [1.0, 2.0]);
+            assert_vector_ne_approx!(expected = [1.0, 2.0], actual = [1.0, 2.1], margin(0.0001));
+            assert_vector_ne_approx!(actual = [1.0], expected = [1.0, 2.0]);
+        }
+
+        #[test]
+        fn TEST_assert_vector_eq_approx_TRAILING_COMMA() {
+            assert_vector_eq_approx!([1.0, 2.0], vec![1.0, 2.00001], margin(0.0001),);
+            assert_vector_eq_approx!([1.0, 2.0], [1.0, 2.0],);
+            assert_vector_eq_approx!(actual = [1.0, 2.0], expected = [1.0, 2.0], margin(0.0001),);
+            assert_vector_ne_approx!([1.0, 2.0], [1.0, 2.1], margin(0.0001),);
+            assert_vector_ne_approx!([1.0], [1.0, 2.0],);
+            assert_vector_ne_approx!(expected = [1.0, 2.0], actual = [1.0, 2.1], margin(0.0001),);
+        }
+
+        #[test]
+        fn TEST_assert_vector_eq_approx_FACTORLESS_EVALUATOR() {
+            assert_vector_eq_approx!([ 1.0, 2.0 ], [ 1.0, 2.25 ], FactorlessEvaluator);
+            assert_vector_ne_approx!([ 1.0, 2.0 ], [ 1.0, 3.0 ], FactorlessEvaluator);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for vectors: at index 1 expected=2.0, actual=3.0")]
+        fn TEST_assert_vector_eq_approx_FACTORLESS_EVALUATOR_SHOULD_FAIL() {
+            assert_vector_eq_approx!([ 1.0, 2.0 ], [ 1.0, 3.0 ], FactorlessEvaluator);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate inequality for vectors")]
+        fn TEST_assert_vector_ne_approx_FACTORLESS_EVALUATOR_SHOULD_FAIL() {
+            assert_vector_ne_approx!([ 1.0, 2.0 ], [ 1.0, 2.25 ], FactorlessEvaluator);
+        }
+
+        #[test]
+        fn TEST_assert_vector_eq_approx_2_PARAMETER_EMPTY_ARRAY_INSTANCES() {
+            let expected : [f64; 0] = [];
+            let actual : [f64; 0] = [];
+
+            assert_vector_eq_approx!(expected, actual);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate inequality for vectors")]
+        fn TEST_assert_vector_ne_approx_2_PARAMETER_EMPTY_ARRAY_INSTANCES() {
+            let expected : [f64; 0] = [];
+            let actual : [f64; 0] = [];
+
+            assert_vector_ne_approx!(expected, actual);
+        }
+
+        #[test]
+        fn TEST_assert_vector_eq_approx_3_PARAMETER_EMPTY_SLICE_INSTANCES() {
+            let expected : &[f64] = &[];
+            let actual : &[f64] = &[];
+
+            assert_vector_eq_approx!(expected, actual, margin(0.0001));
+        }
+
+        #[test]
+        fn TEST_assert_vector_eq_approx_2_PARAMETER_EMPTY_Vec_INSTANCES() {
+            let expected : Vec<f64> = Vec::new();
+            let actual : Vec<f64> = Vec::new();
+
+            assert_vector_eq_approx!(expected, actual);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for vectors: expected-length 2 differs from actual-length 1")]
+        fn TEST_assert_vector_eq_approx_2_PARAMETER_SLICE_INSTANCES_DIFFERENT_LENGTHS() {
+            let expected : &[f64] = &[ -2.0, -3.0 ];
+            let actual : &[f64] = &[ 0.0 ];
+
+            assert_vector_eq_approx!(expected, actual);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for vectors: at index 1 expected=-3.0, actual=-3.001, margin_factor=0.01, multiplier_factor=0.0001")]
+        fn TEST_assert_vector_eq_approx_3_PARAMETER_VECTORS_SAME_LENGTH_DIFFERENT_ELEMENTS() {
+            let expected : &[f64] = &[ -2.0, -3.0, -4.0 ];
+            let actual = Vec::from([ -2.0, -3.001, -4.0 ]);
+
+            assert_vector_eq_approx!(expected, actual, zero_margin_or_multiplier(0.0001, 0.01));
+        }
+
+        #[test]
+        fn TEST_assert_vector_eq_approx_3_PARAMETER_VECTORS_SAME_LENGTH_DIFFERENT_ELEMENTS_WITH_PERMISSIVE_multiplier() {
+            let expected : &[f64] = &[ -2.0, -3.0, -4.0 ];
+            let actual = Vec::from([ -2.0, -3.000001, -4.0 ]);
+
+            assert_vector_eq_approx!(expected, actual, multiplier(0.01));
+        }
+
+        #[test]
+        fn TEST_assert_vector_eq_approx_REFERENCE_COUNTED_SLICES() {
+            use std::sync::Arc;
+
+            let expected = Arc::new(vec![ 1.0, 2.0, 3.0 ]);
+            let shared : Arc<[f64]> = Arc::from(vec![ 1.0, 2.0, 3.0 ]);
+
+            assert_vector_eq_approx!(expected, [ 1.0, 2.0, 3.0 ]);
+            assert_vector_eq_approx!(&expected, shared, margin(0.0));
+            assert_vector_eq_approx!(&shared, &expected);
+            assert_vector_eq_approx!(Arc::new(vec![ 1, 2, 3 ]), &shared, margin(0.0));
+            assert_vector_ne_approx!(expected, Arc::new(vec![ 1.0, 2.0, 3.5 ]));
+            assert_vector_ne_approx!(&shared, vec![ 1.0, 2.0 ], margin(0.1));
+        }
+
+        #[test]
+        fn TEST_assert_vector_eq_approx_ARC_SHARED_ACROSS_THREADS() {
+            use std::sync::Arc;
+
+            let expected = Arc::new((0..100).map(|i| (i as f64).sqrt()).collect::<Vec<f64>>());
+
+            let handles : Vec<_> = (0..4)
+                .map(|t| {
+                    let expected = Arc::clone(&expected);
+
+                    std::thread::spawn(move || -> Vec<f64> {
+                        expected.iter().map(|x| x * (1.0 + 1e-9 * t as f64)).collect()
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                let actual = handle.join().unwrap();
+
+                assert_vector_eq_approx!(expected, actual, multiplier(1e-6));
+                assert_vector_eq_approx!(&expected, Arc::new(actual));
+            }
+        }
+    }
+
+
+    mod TEST_STR_COMPARANDS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::traits::TryTestableAsF64;
+
+
+        #[test]
+        fn TEST_try_testable_as_f64_1() {
+            assert_eq!(Ok(1.23456), "1.23456".try_testable_as_f64());
+            assert_eq!(Ok(-1.0), " \t-1.0\n".try_testable_as_f64());
+            assert_eq!(Ok(2.5), 2.5.try_testable_as_f64());
+            assert_eq!(Err("could not parse 'abc' as f64".into()), "abc".try_testable_as_f64());
+        }
+
+        #[test]
+        fn TEST_assert_scalar_eq_approx_WITH_str_EXPECTED() {
+            let actual = 1.23457;
+
+            assert_scalar_eq_approx!("1.23456", actual, margin(1e-4));
+            assert_scalar_eq_approx!("  1.23456  ", actual, margin(1e-4));
+            assert_scalar_eq_approx!(String::from("1.23456"), actual, margin(1e-4));
+            assert_scalar_eq_approx!(&String::from("1.23456"), actual, margin(1e-4));
+            assert_scalar_eq_approx!(actual, "1.23457");
+            assert_scalar_ne_approx!("1.23456", actual, margin(1e-6));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: could not parse 'abc' as f64")]
+        fn TEST_assert_scalar_eq_approx_WITH_INVALID_str_SHOULD_FAIL() {
+            assert_scalar_eq_approx!("abc", 1.0, margin(1e-4));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate inequality: could not parse '1.0.0' as f64")]
+        fn TEST_assert_scalar_ne_approx_WITH_INVALID_str_SHOULD_FAIL() {
+            assert_scalar_ne_approx!(1.0, "1.0.0", margin(1e-4));
+        }
+    }
+
+
+    mod TEST_INTO_F64_COMPARANDS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_vector_eq_approx_into,
+            VectorComparisonResult,
+        };
+
+
+        /// Third-party-like type that implements `Into<f64>`, but not
+        /// `ToF64`.
+        #[derive(Clone)]
+        #[derive(Copy)]
+        #[derive(Debug)]
+        struct Celsius(f32);
+
+        impl From<Celsius> for f64 {
+            fn from(value : Celsius) -> Self {
+                value.0 as f64
+            }
+        }
+
+
+        #[test]
+        fn TEST_assert_scalar_eq_approx_WITH_Into_f64_COMPARANDS() {
+            assert_scalar_eq_approx!(Celsius(21.5), 21.5);
+            assert_scalar_eq_approx!(21.5, Celsius(21.5));
+            assert_scalar_eq_approx!(Celsius(21.5), Celsius(21.55), margin(0.1));
+            assert_scalar_ne_approx!(Celsius(21.5), Celsius(21.7), margin(0.1));
+            assert_scalar_ne_approx!(Celsius(21.5), 22.0);
+        }
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_into() {
+            let expected = [ Celsius(20.0), Celsius(21.0), Celsius(22.0) ];
+            let m = margin(0.1);
+
+            assert_eq!(VectorComparisonResult::ExactlyEqual, evaluate_vector_eq_approx_into(&expected, &[ 20.0, 21.0, 22.0 ], &m).0);
+            assert_eq!(VectorComparisonResult::ApproximatelyEqual, evaluate_vector_eq_approx_into(&expected, &vec![ Celsius(20.0), Celsius(21.05), Celsius(22.0) ], &m).0);
+            assert_eq!(
+                VectorComparisonResult::UnequalElements {
+                    index_of_first_unequal_element :          2,
+                    expected_value_of_first_unequal_element : 22.0,
+                    actual_value_of_first_unequal_element :   22.5,
+                },
+                evaluate_vector_eq_approx_into(&expected, &[ 20.0, 21.0, 22.5 ], &m).0
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=Celsius(21.5), actual=21.7, margin_factor=0.1")]
+        fn TEST_assert_scalar_eq_approx_WITH_Into_f64_COMPARAND_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(Celsius(21.5), 21.7, margin(0.1));
+        }
+    }
+
+
+    mod TEST_DISTRIBUTION_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_distribution_eq_approx,
+            DistributionError,
+            VectorComparisonResult,
+        };
+
+
+        #[test]
+        fn TEST_evaluate_distribution_eq_approx_1() {
+            let m = margin(0.001);
+
+            assert!(matches!(evaluate_distribution_eq_approx(&[1, 2, 1], &[10, 20, 10], &m), Ok((VectorComparisonResult::ExactlyEqual, _, _))));
+            assert!(matches!(evaluate_distribution_eq_approx(&[1, 2, 1], &[100, 201, 100], &margin(0.002)), Ok((VectorComparisonResult::ApproximatelyEqual, _, _))));
+            assert!(matches!(evaluate_distribution_eq_approx(&[1, 2, 1], &[1, 2, 2], &m), Ok((VectorComparisonResult::UnequalElements { index_of_first_unequal_element : 0, .. }, _, _))));
+            assert!(matches!(evaluate_distribution_eq_approx(&[1, 2, 1], &[1, 2], &m), Ok((VectorComparisonResult::DifferentLengths { expected_length : 3, actual_length : 2 }, _, _))));
+
+            assert_eq!(Some(DistributionError::ExpectedSumIsZero), evaluate_distribution_eq_approx(&[0.0, 0.0], &[1.0, 1.0], &m).err());
+            assert_eq!(Some(DistributionError::ExpectedSumIsZero), evaluate_distribution_eq_approx(&[0.0; 0], &[1.0, 1.0], &m).err());
+            assert_eq!(Some(DistributionError::ActualSumIsZero), evaluate_distribution_eq_approx(&[1.0, 1.0], &[0.0, 0.0], &m).err());
+        }
+
+        #[test]
+        fn TEST_assert_distribution_eq_approx_1() {
+            assert_distribution_eq_approx!([1.0, 2.0, 1.0], [0.25, 0.5, 0.25]);
+            assert_distribution_eq_approx!(vec![3u32, 5, 2], [30.1, 49.9, 20.0], margin(0.001));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for distributions: at bin 1 expected-mass=0.5, actual-mass=0.4, margin_factor=0.01")]
+        fn TEST_assert_distribution_eq_approx_SHOULD_FAIL_1() {
+            assert_distribution_eq_approx!([1.0, 2.0, 1.0], [1.0, 1.6, 1.4], margin(0.01));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for distributions: actual bins sum to zero")]
+        fn TEST_assert_distribution_eq_approx_SHOULD_FAIL_2() {
+            assert_distribution_eq_approx!([1.0, 2.0, 1.0], [0.0, 0.0, 0.0], margin(0.01));
+        }
+    }
+
+
+    mod TEST_EXTENDED_COMPARANDS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::traits::ExtendedTestableAsF64;
+
+
+        #[test]
+        fn TEST_bool_extended_testable_as_f64() {
+            assert_eq!(Ok(0.0), false.extended_testable_as_f64());
+            assert_eq!(Ok(1.0), true.extended_testable_as_f64());
+        }
+
+        #[test]
+        fn TEST_assert_scalar_eq_approx_WITH_bool() {
+            assert_scalar_eq_approx!(true, 0.9999, margin(0.001));
+            assert_scalar_eq_approx!(false, 0.0001, margin(0.001));
+            assert_scalar_eq_approx!(0.99999999, true);
+            assert_scalar_eq_approx!(true, true);
+            assert_scalar_ne_approx!(true, false);
+            assert_scalar_ne_approx!(true, 0.9, margin(0.001));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=true, actual=0.9, margin_factor=0.001")]
+        fn TEST_assert_scalar_eq_approx_WITH_bool_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(true, 0.9, margin(0.001));
+        }
+
+        #[test]
+        fn TEST_char_extended_testable_as_f64() {
+            assert_eq!(Ok(0.0), '\0'.extended_testable_as_f64());
+            assert_eq!(Ok(65.0), 'A'.extended_testable_as_f64());
+            assert_eq!(Ok(960.0), 'π'.extended_testable_as_f64());
+            assert_eq!(Ok(1114111.0), char::MAX.extended_testable_as_f64());
+        }
+
+        #[test]
+        fn TEST_assert_scalar_eq_approx_WITH_char() {
+            assert_scalar_eq_approx!('A', 65.0);
+            assert_scalar_eq_approx!(97, 'a');
+            assert_scalar_eq_approx!('a', 'b', margin(1.0));
+            assert_scalar_ne_approx!('a', 'b');
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected='A', actual=66.0, margin_factor=0.5")]
+        fn TEST_assert_scalar_eq_approx_WITH_char_SHOULD_FAIL() {
+            assert_scalar_eq_approx!('A', 66.0, margin(0.5));
+        }
+
+        #[test]
+        fn TEST_NonZero_extended_testable_as_f64() {
+            use std::num::{
+                NonZeroI64,
+                NonZeroU8,
+                NonZeroUsize,
+            };
+
+            assert_eq!(Ok(255.0), NonZeroU8::new(255).unwrap().extended_testable_as_f64());
+            assert_eq!(Ok(-12.0), NonZeroI64::new(-12).unwrap().extended_testable_as_f64());
+            assert_eq!(Ok(1.0), NonZeroUsize::MIN.extended_testable_as_f64());
+        }
+
+        #[test]
+        fn TEST_assert_scalar_eq_approx_WITH_NonZeroU32() {
+            use std::num::NonZeroU32;
+
+            let n = NonZeroU32::new(1000).unwrap();
+
+            assert_scalar_eq_approx!(n, 1000.0005);
+            assert_scalar_eq_approx!(1000.0005, n);
+            assert_scalar_eq_approx!(n, 1001.0, margin(1.5));
+            assert_scalar_ne_approx!(n, 1001.0);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=1000, actual=1001.0, margin_factor=0.5")]
+        fn TEST_assert_scalar_eq_approx_WITH_NonZeroU32_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(std::num::NonZeroU32::new(1000).unwrap(), 1001.0, margin(0.5));
+        }
+    }
+
+
+    #[cfg(feature = "half")]
+    mod TEST_HALF_COMPARANDS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::presets;
+
+        use half::{
+            bf16,
+            f16,
+        };
+
+
+        #[test]
+        fn TEST_assert_scalar_eq_approx_WITH_f16() {
+            // f16 cannot represent 1.0004: it rounds to 1.0, the adjacent
+            // values being 1.0 - 2^-11 and 1.0 + 2^-10
+            assert_eq!(f16::ONE, f16::from_f32(1.0004));
+
+            let expected = f16::from_f32(1.0);
+            let actual = f16::from_f32(1.001); // 1.0009765625
+
+            assert_scalar_eq_approx!(expected, actual, margin(1e-3));
+            assert_scalar_ne_approx!(expected, actual, margin(1e-4));
+            assert_scalar_eq_approx!(expected, actual, presets::half_precision());
+            assert_scalar_eq_approx!(actual, 1.0009765625, margin(0.0));
+            assert_scalar_ne_approx!(expected, f16::from_f32(1.01), presets::half_precision());
+        }
+
+        #[test]
+        fn TEST_assert_scalar_eq_approx_WITH_bf16() {
+            let expected = bf16::from_f32(100.0);
+            let actual = bf16::from_f32(100.5); // 100.5 (the spacing near 100 being 0.5)
+
+            assert_scalar_eq_approx!(expected, actual, presets::bfloat16());
+            assert_scalar_ne_approx!(expected, actual, presets::half_precision());
+            assert_scalar_eq_approx!(expected, 100.0, margin(0.0));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=1.0, actual=1.0097656, margin_factor=0.0009765625, multiplier_factor=0.00390625")]
+        fn TEST_assert_scalar_eq_approx_WITH_f16_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(f16::from_f32(1.0), f16::from_f32(1.01), presets::half_precision());
+        }
+    }
+
+
+    #[cfg(feature = "rust_decimal")]
+    mod TEST_DECIMAL_COMPARANDS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::evaluate_decimal_eq_approx;
+
+        use rust_decimal::Decimal;
+
+
+        #[test]
+        fn TEST_assert_scalar_eq_approx_WITH_Decimal() {
+            let expected = Decimal::new(10000, 2); // 100.00
+            let actual = Decimal::new(100004, 3); // 100.004
+
+            assert_scalar_eq_approx!(expected, actual, margin(0.005));
+            assert_scalar_eq_approx!(expected, 100.004, margin(0.005));
+            assert_scalar_ne_approx!(expected, actual, margin(0.003));
+        }
+
+        #[test]
+        fn TEST_evaluate_decimal_eq_approx_1() {
+            let margin = Decimal::new(1, 2); // 0.01
+
+            assert_eq!(ComparisonResult::ExactlyEqual, evaluate_decimal_eq_approx(&Decimal::new(10, 1), &Decimal::new(100, 2), &margin));
+            assert_eq!(ComparisonResult::ApproximatelyEqual, evaluate_decimal_eq_approx(&Decimal::new(110, 2), &Decimal::new(111, 2), &margin));
+            assert_eq!(ComparisonResult::ApproximatelyEqual, evaluate_decimal_eq_approx(&Decimal::new(111, 2), &Decimal::new(110, 2), &margin));
+            assert_eq!(ComparisonResult::Unequal, evaluate_decimal_eq_approx(&Decimal::new(110, 2), &Decimal::new(1111, 3), &margin));
+            assert_eq!(ComparisonResult::Unequal, evaluate_decimal_eq_approx(&Decimal::MIN, &Decimal::MAX, &margin));
+        }
+
+        #[test]
+        fn TEST_assert_decimal_eq_approx_EXACT_AT_BOUNDARY() {
+            // 0.1 + 0.2 is exactly 0.3 in decimal, unlike in binary
+            // floating-point
+            let a = Decimal::new(1, 1) + Decimal::new(2, 1);
+
+            assert_decimal_eq_approx!(Decimal::new(3, 1), a, Decimal::ZERO);
+            assert_decimal_eq_approx!(Decimal::new(30, 2), Decimal::new(31, 2), Decimal::new(1, 2));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for decimals: expected=0.30, actual=0.32, margin=0.01")]
+        fn TEST_assert_decimal_eq_approx_SHOULD_FAIL() {
+            assert_decimal_eq_approx!(Decimal::new(30, 2), Decimal::new(32, 2), Decimal::new(1, 2));
+        }
+    }
+
+
+    mod TEST_INFINITIES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::utils::describe_infinity_mismatch;
+
+
+        #[test]
+        fn TEST_INFINITY_COMBINATIONS_FOR_ALL_EVALUATORS() {
+            let evaluators : [&dyn ApproximateEqualityEvaluator; 7] = [
+                &margin(0.1),
+                &multiplier(0.1),
+                &zero_margin_or_multiplier(0.1, 0.1),
+                &asymmetric_margin(0.1, 0.1),
+                &relative_geomean(0.1),
+                &zero_margin_or_relative_geomean(0.1, 0.1),
+                &full_scale(100.0, 0.1),
+            ];
+
+            for e in evaluators {
+                // same-sign infinities
+                assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(f64::INFINITY, f64::INFINITY).0);
+                assert_eq!(ComparisonResult::ExactlyEqual, e.evaluate(f64::NEG_INFINITY, f64::NEG_INFINITY).0);
+
+                // opposite-sign infinities
+                assert_eq!(ComparisonResult::Unequal, e.evaluate(f64::INFINITY, f64::NEG_INFINITY).0);
+                assert_eq!(ComparisonResult::Unequal, e.evaluate(f64::NEG_INFINITY, f64::INFINITY).0);
+
+                // infinity vs finite
+                assert_eq!(ComparisonResult::Unequal, e.evaluate(f64::INFINITY, f64::MAX).0);
+                assert_eq!(ComparisonResult::Unequal, e.evaluate(f64::MAX, f64::INFINITY).0);
+                assert_eq!(ComparisonResult::Unequal, e.evaluate(f64::NEG_INFINITY, 0.0).0);
+            }
+        }
+
+        #[test]
+        fn TEST_describe_infinity_mismatch() {
+            assert_eq!(None, describe_infinity_mismatch(f64::INFINITY, f64::INFINITY));
+            assert_eq!(None, describe_infinity_mismatch(f64::NEG_INFINITY, f64::NEG_INFINITY));
+            assert_eq!(None, describe_infinity_mismatch(1.0, 2.0));
+            assert_eq!(None, describe_infinity_mismatch(f64::INFINITY, f64::NAN));
+            assert_eq!(Some("infinity mismatch: expected is inf but actual is -inf".into()), describe_infinity_mismatch(f64::INFINITY, f64::NEG_INFINITY));
+            assert_eq!(Some("overflow/infinity mismatch: expected is -inf but actual is finite".into()), describe_infinity_mismatch(f64::NEG_INFINITY, 1.0));
+            assert_eq!(Some("overflow/infinity mismatch: actual is inf but expected is finite".into()), describe_infinity_mismatch(1.0, f64::INFINITY));
+        }
+
+        #[test]
+        fn TEST_assert_scalar_eq_approx_WITH_SAME_SIGN_INFINITIES() {
+            assert_scalar_eq_approx!(f64::INFINITY, f64::INFINITY);
+            assert_scalar_eq_approx!(f64::NEG_INFINITY, f64::NEG_INFINITY, multiplier(0.1));
+            assert_scalar_ne_approx!(f64::INFINITY, f64::NEG_INFINITY);
+            assert_scalar_ne_approx!(f64::INFINITY, f64::MAX);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=inf, actual=-inf, margin_factor=0.1: infinity mismatch: expected is inf but actual is -inf")]
+        fn TEST_assert_scalar_eq_approx_WITH_OPPOSITE_SIGN_INFINITIES_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(f64::INFINITY, f64::NEG_INFINITY, margin(0.1));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=inf, actual=1e308, margin_factor=0.25: overflow/infinity mismatch: expected is inf but actual is finite")]
+        fn TEST_assert_scalar_eq_approx_WITH_INFINITY_VS_FINITE_SHOULD_FAIL() {
+            assert_scalar_eq_approx!(f64::INFINITY, 1e308, asymmetric_margin(0.25, 0.25));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for vectors: at index 1 expected=2.0, actual=inf, multiplier_factor=0.1: overflow/infinity mismatch: actual is inf but expected is finite")]
+        fn TEST_assert_vector_eq_approx_WITH_FINITE_VS_INFINITY_SHOULD_FAIL() {
+            assert_vector_eq_approx!([ 1.0, 2.0 ], [ 1.0, f64::INFINITY ], multiplier(0.1));
+        }
+    }
+
+
+    mod TEST_ANGLE_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::AngleUnit;
+
+        use std::f64::consts::{
+            FRAC_PI_3,
+            FRAC_PI_6,
+            PI,
+        };
+
+
+        #[test]
+        fn TEST_AngleUnit() {
+            assert_eq!(AngleUnit::Degrees, AngleUnit::default());
+            assert_eq!(90.0, AngleUnit::Degrees.right_angle());
+            assert_eq!(180.0, AngleUnit::Degrees.straight_angle());
+            assert_eq!(PI / 2.0, AngleUnit::Radians.right_angle());
+            assert_eq!(PI, AngleUnit::Radians.straight_angle());
+        }
+
+        #[test]
+        fn TEST_assert_angles_supplementary_approx_1() {
+            assert_angles_supplementary_approx!(30.0, 150.0);
+            assert_angles_supplementary_approx!(30, 150.05, margin(0.1));
+            assert_angles_supplementary_approx!(120.0, 60.0, margin(0.0), AngleUnit::Degrees);
+            assert_angles_supplementary_approx!(FRAC_PI_3, 2.0 * FRAC_PI_3, margin(1e-12), AngleUnit::Radians);
+        }
+
+        #[test]
+        fn TEST_assert_angles_complementary_approx_1() {
+            assert_angles_complementary_approx!(30.0, 60.0);
+            assert_angles_complementary_approx!(45, 45.05, margin(0.1));
+            assert_angles_complementary_approx!(FRAC_PI_6, FRAC_PI_3, margin(1e-12), AngleUnit::Radians);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify supplementary angles: a=30.0, b=160.0, sum=190.0, expected-sum=180.0 (Degrees), margin_factor=0.1")]
+        fn TEST_assert_angles_supplementary_approx_SHOULD_FAIL() {
+            assert_angles_supplementary_approx!(30.0, 160.0, margin(0.1));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify complementary angles: a=1.0, b=1.0, sum=2.0, expected-sum=1.5707963267948966 (Radians), margin_factor=0.001")]
+        fn TEST_assert_angles_complementary_approx_SHOULD_FAIL() {
+            assert_angles_complementary_approx!(1.0, 1.0, margin(0.001), AngleUnit::Radians);
+        }
+    }
+
+
+    mod TEST_RECIPROCAL_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_assert_scalar_reciprocal_approx_1() {
+            assert_scalar_reciprocal_approx!(4.0, 0.25);
+            assert_scalar_reciprocal_approx!(0.25, 4.0);
+            assert_scalar_reciprocal_approx!(3.0, 1.0 / 3.0);
+            assert_scalar_reciprocal_approx!(-8, -0.125);
+            assert_scalar_reciprocal_approx!(7.0, 0.1428, margin(0.001));
+            assert_scalar_reciprocal_approx!(std::f64::consts::PI, std::f64::consts::FRAC_1_PI, margin(1e-15));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate reciprocal: a=2.0, b=0.45, product=0.9, margin_factor=0.01")]
+        fn TEST_assert_scalar_reciprocal_approx_SHOULD_FAIL() {
+            assert_scalar_reciprocal_approx!(2.0, 0.45, margin(0.01));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate reciprocal: a=4.0, b=-0.25, product=-1.0")]
+        fn TEST_assert_scalar_reciprocal_approx_WITH_OPPOSITE_SIGNS_SHOULD_FAIL() {
+            assert_scalar_reciprocal_approx!(4.0, -0.25);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate reciprocal: a=0.0, b=1.0: zero has no reciprocal")]
+        fn TEST_assert_scalar_reciprocal_approx_WITH_ZERO_SHOULD_FAIL() {
+            assert_scalar_reciprocal_approx!(0.0, 1.0);
+        }
+    }
+
+
+    mod TEST_IDEMPOTENCE_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_assert_idempotent_approx_1() {
+            assert_idempotent_approx!(f64::abs, -2.5);
+            assert_idempotent_approx!(|x : f64| x.clamp(0.0, 1.0), 1.7);
+            assert_idempotent_approx!(|x : f64| (x * 1000.0).round() / 1000.0, std::f64::consts::PI, margin(0.0));
+
+            // convergent: one Newton step for sqrt(2) from a close guess
+            assert_idempotent_approx!(|x : f64| 0.5 * (x + 2.0 / x), 1.4, margin(1e-4));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate idempotence: input=1.0, once=2.0, twice=4.0, margin_factor=0.01")]
+        fn TEST_assert_idempotent_approx_SHOULD_FAIL() {
+            assert_idempotent_approx!(|x : f64| x * 2.0, 1.0, margin(0.01));
+        }
+
+        #[test]
+        fn TEST_assert_vector_idempotent_approx_1() {
+            let normalize = |v : &[f64]| -> Vec<f64> {
+                let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+                v.iter().map(|x| x / norm).collect()
+            };
+
+            assert_vector_idempotent_approx!(normalize, vec![ 3.0, 4.0 ]);
+            assert_vector_idempotent_approx!(normalize, [ 1.0, 2.0, 2.0 ], margin(1e-12));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate idempotence for vectors: once=[2.0, 4.0], twice=[4.0, 8.0]: at index 0 once=2.0, twice=4.0, margin_factor=0.01")]
+        fn TEST_assert_vector_idempotent_approx_SHOULD_FAIL() {
+            assert_vector_idempotent_approx!(|v : &[f64]| v.iter().map(|x| x * 2.0).collect::<Vec<f64>>(), [ 1.0, 2.0 ], margin(0.01));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate idempotence for vectors: once=[1.0, 1.0], twice=[1.0, 1.0, 1.0]: once-length 2 differs from twice-length 3")]
+        fn TEST_assert_vector_idempotent_approx_WITH_GROWING_LENGTH_SHOULD_FAIL() {
+            assert_vector_idempotent_approx!(|v : &[f64]| vec![ 1.0; v.len() + 1 ], [ 1.0 ], margin(0.01));
+        }
+    }
+
+
+    mod TEST_DELTA_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_assert_delta_approx_1() {
+            let before = 100.0;
+            let after = 110.004;
+
+            assert_delta_approx!(before, after, 10.0, margin(0.01));
+            assert_delta_approx!(10, 20, 10.0);
+            assert_delta_approx!(5.0, -5.0, -10);
+            assert_delta_approx!(1_000_000.0, 1_000_000.0, 0.0);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate delta: before=100.0, after=109.5, observed-delta=9.5, expected-delta=10.0, margin_factor=0.1")]
+        fn TEST_assert_delta_approx_SHOULD_FAIL() {
+            assert_delta_approx!(100.0, 109.5, 10.0, margin(0.1));
+        }
+    }
+
+
+    mod TEST_FUNCTION_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_fn_eq_approx,
+            evaluate_fn_matches_poly_approx,
+            FunctionComparisonResult,
+        };
+
+
+        #[test]
+        fn TEST_evaluate_fn_eq_approx_SAMPLES_EVENLY() {
+            let mut xs = Vec::new();
+
+            let (comparison_result, ..) = evaluate_fn_eq_approx(
+                |x : f64| {
+                    xs.push(x);
+                    x
+                },
+                |x : f64| x,
+                0.0,
+                1.0,
+                5,
+                &margin(0.001),
+            );
+
+            assert_eq!(FunctionComparisonResult::ExactlyEqual, comparison_result);
+            assert_eq!(vec![ 0.0, 0.25, 0.5, 0.75, 1.0 ], xs);
+
+            assert_eq!(FunctionComparisonResult::ExactlyEqual, evaluate_fn_eq_approx(|_| 1.0, |_| 2.0, 0.0, 1.0, 0, &margin(0.001)).0);
+        }
+
+        #[test]
+        fn TEST_evaluate_fn_eq_approx_FIRST_DIVERGENCE() {
+            let (comparison_result, margin_factor, _) = evaluate_fn_eq_approx(|x : f64| x * x, |x : f64| if x < 2.0 { x * x } else { x * x + 1.0 }, 0.0, 4.0, 9, &margin(0.001));
+
+            assert_eq!(FunctionComparisonResult::UnequalAtPoint { x : 2.0, expected_value : 4.0, actual_value : 5.0 }, comparison_result);
+            assert_eq!(Some(0.001), margin_factor);
+        }
+
+        #[test]
+        fn TEST_assert_fn_eq_approx_1() {
+            // small-angle approximation of sine
+            assert_fn_eq_approx!(f64::sin, |x : f64| x - x.powi(3) / 6.0, -0.1, 0.1, 21, margin(1e-7));
+
+            assert_fn_eq_approx!(|x : f64| 2.0 * x, |x : f64| x + x, -10.0, 10.0, 100);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for functions: at x=1.0 expected=1.0, actual=0.5, margin_factor=0.01")]
+        fn TEST_assert_fn_eq_approx_SHOULD_FAIL() {
+            assert_fn_eq_approx!(|x : f64| x, |x : f64| if x < 1.0 { x } else { x / 2.0 }, 0.0, 2.0, 5, margin(0.01));
+        }
+
+        #[test]
+        fn TEST_evaluate_fn_matches_poly_approx_1() {
+            // 1 - 2x + 3x^2
+            let coefficients = [1.0, -2.0, 3.0];
+
+            assert_eq!(
+                FunctionComparisonResult::ExactlyEqual,
+                evaluate_fn_matches_poly_approx(|x : f64| 1.0 - 2.0 * x + 3.0 * x * x, &coefficients, 0.0, 2.0, 5, &margin(1e-12)).0
+            );
+            assert_eq!(
+                FunctionComparisonResult::UnequalAtPoint { x : 1.0, expected_value : 2.0, actual_value : 2.5 },
+                evaluate_fn_matches_poly_approx(|x : f64| if x < 1.0 { 1.0 - 2.0 * x + 3.0 * x * x } else { 2.5 }, &coefficients, 0.0, 2.0, 5, &margin(0.1)).0
+            );
+            assert_eq!(FunctionComparisonResult::ExactlyEqual, evaluate_fn_matches_poly_approx(|_| 0, &[], -1.0, 1.0, 3, &margin(0.0)).0);
+        }
+
+        #[test]
+        fn TEST_assert_fn_matches_poly_approx_1() {
+            // Taylor polynomial of exp about 0
+            assert_fn_matches_poly_approx!(f64::exp, [1.0, 1.0, 0.5, 1.0 / 6.0, 1.0 / 24.0], -0.1, 0.1, 21, margin(1e-7));
+
+            assert_fn_matches_poly_approx!(|x : f64| (x + 1.0) * (x - 2.0), vec![-2.0, -1.0, 1.0], -10.0, 10.0, 41);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality of function and polynomial [1.0, 1.0]: at x=1.0 polynomial=2.0, function=2.718281828459045, margin_factor=0.2")]
+        fn TEST_assert_fn_matches_poly_approx_SHOULD_FAIL() {
+            assert_fn_matches_poly_approx!(f64::exp, [1.0, 1.0], 0.0, 1.0, 3, margin(0.2));
+        }
+    }
+
+
+    mod TEST_RANGE_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_scalar_in_range_approx,
+            RangeComparisonResult,
+        };
+
+
+        #[test]
+        fn TEST_evaluate_scalar_in_range_approx_1() {
+            let m = margin(0.01);
+
+            assert_eq!(RangeComparisonResult::WithinRange, evaluate_scalar_in_range_approx(&1.0, &1.0, &2.0, &m).0);
+            assert_eq!(RangeComparisonResult::WithinRange, evaluate_scalar_in_range_approx(&1.5, &1.0, &2.0, &m).0);
+            assert_eq!(RangeComparisonResult::WithinRange, evaluate_scalar_in_range_approx(&2.0, &1.0, &2.0, &m).0);
+            assert_eq!(RangeComparisonResult::ApproximatelyWithinRange, evaluate_scalar_in_range_approx(&0.995, &1.0, &2.0, &m).0);
+            assert_eq!(RangeComparisonResult::ApproximatelyWithinRange, evaluate_scalar_in_range_approx(&2.005, &1.0, &2.0, &m).0);
+            assert_eq!(RangeComparisonResult::BelowLowerBound { lower_bound : 1.0, shortfall : 0.5 }, evaluate_scalar_in_range_approx(&0.5, &1.0, &2.0, &m).0);
+            assert_eq!(RangeComparisonResult::AboveUpperBound { upper_bound : 2.0, excess : 0.5 }, evaluate_scalar_in_range_approx(&2.5, &1.0, &2.0, &m).0);
+            assert!(matches!(evaluate_scalar_in_range_approx(&f64::NAN, &1.0, &2.0, &m).0, RangeComparisonResult::BelowLowerBound { .. }));
+        }
+
+        #[test]
+        fn TEST_assert_in_range_approx_1() {
+            assert_in_range_approx!(1.5, 1.0, 2.0, margin(1e-6));
+            assert_in_range_approx!(0.9999995, 1.0, 2.0, margin(1e-6));
+            assert_in_range_approx!(2.0000005, 1, 2, margin(1e-6));
+            assert_in_range_approx!(2.000000001, 1.0, 2.0);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify value within range approximately: value=0.5, range=[1.0, 2.0]: below lower bound 1.0 by 0.5, margin_factor=0.001")]
+        fn TEST_assert_in_range_approx_BELOW_SHOULD_FAIL() {
+            assert_in_range_approx!(0.5, 1.0, 2.0, margin(0.001));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify value within range approximately: value=2.25, range=[1.0, 2.0]: above upper bound 2.0 by 0.25, margin_factor=0.001")]
+        fn TEST_assert_in_range_approx_ABOVE_SHOULD_FAIL() {
+            assert_in_range_approx!(2.25, 1.0, 2.0, margin(0.001));
+        }
+    }
+
+
+    mod TEST_BAND_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::evaluate_scalar_in_band;
+
+
+        #[test]
+        fn TEST_evaluate_scalar_in_band_AT_AND_BEYOND_BOUNDS() {
+            assert_eq!(ComparisonResult::ApproximatelyEqual, evaluate_scalar_in_band(&1.5, &1.0, &2.0));
+            assert_eq!(ComparisonResult::ApproximatelyEqual, evaluate_scalar_in_band(&1.0, &1.0, &2.0));
+            assert_eq!(ComparisonResult::ApproximatelyEqual, evaluate_scalar_in_band(&2.0, &1.0, &2.0));
+            assert_eq!(ComparisonResult::Unequal, evaluate_scalar_in_band(&1.0f64.next_down(), &1.0, &2.0));
+            assert_eq!(ComparisonResult::Unequal, evaluate_scalar_in_band(&2.0f64.next_up(), &1.0, &2.0));
+
+            assert_eq!(ComparisonResult::ApproximatelyEqual, evaluate_scalar_in_band(&3.0, &3.0, &3.0));
+            assert_eq!(ComparisonResult::Unequal, evaluate_scalar_in_band(&1.5, &2.0, &1.0));
+            assert_eq!(ComparisonResult::Unequal, evaluate_scalar_in_band(&f64::NAN, &f64::NEG_INFINITY, &f64::INFINITY));
+            assert_eq!(ComparisonResult::ApproximatelyEqual, evaluate_scalar_in_band(&5, &0, &10.0f32));
+        }
+
+        #[test]
+        fn TEST_assert_scalar_in_band_1() {
+            assert_scalar_in_band!(1.0, 1.0, 2.0);
+            assert_scalar_in_band!(2.0, 1.0, 2.0);
+            assert_scalar_in_band!(-0.5, -1, 0);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify value within band: actual=0.9, band=[1.0, 2.0]")]
+        fn TEST_assert_scalar_in_band_BELOW_SHOULD_FAIL() {
+            assert_scalar_in_band!(0.9, 1.0, 2.0);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify value within band: actual=2.1, band=[1.0, 2.0]")]
+        fn TEST_assert_scalar_in_band_ABOVE_SHOULD_FAIL() {
+            assert_scalar_in_band!(2.1, 1.0, 2.0);
+        }
+    }
+
+
+    mod TEST_UNCERTAINTY_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_scalar_eq_approx_with_uncertainty,
+            UncertaintyCriterion,
+        };
+
+
+        #[test]
+        fn TEST_evaluate_scalar_eq_approx_with_uncertainty_OVERLAPPING() {
+            // separation 0.5, combined uncertainty 0.5 (= sqrt(0.3^2 + 0.4^2))
+            assert_eq!(
+                (ComparisonResult::ApproximatelyEqual, 1.0),
+                evaluate_scalar_eq_approx_with_uncertainty(&10.0, 0.3, &10.5, 0.4, UncertaintyCriterion::IntervalsOverlap)
+            );
+            assert_eq!(
+                (ComparisonResult::ApproximatelyEqual, 1.0),
+                evaluate_scalar_eq_approx_with_uncertainty(&10.0, 0.3, &10.5, 0.4, UncertaintyCriterion::CombinedUncertainty)
+            );
+
+            // separation 0.6: intervals overlap, but exceeds combined uncertainty
+            let (r, sigmas) = evaluate_scalar_eq_approx_with_uncertainty(&10.0, 0.3, &10.6, 0.4, UncertaintyCriterion::IntervalsOverlap);
+
+            assert_eq!(ComparisonResult::ApproximatelyEqual, r);
+            assert_scalar_eq_approx!(1.2, sigmas, margin(1e-12));
+            assert_eq!(
+                ComparisonResult::Unequal,
+                evaluate_scalar_eq_approx_with_uncertainty(&10.0, 0.3, &10.6, 0.4, UncertaintyCriterion::CombinedUncertainty).0
+            );
+
+            assert_eq!((ComparisonResult::ExactlyEqual, 0.0), evaluate_scalar_eq_approx_with_uncertainty(&7, 0.0, &7.0, 0.0, UncertaintyCriterion::default()));
+        }
+
+        #[test]
+        fn TEST_evaluate_scalar_eq_approx_with_uncertainty_NON_OVERLAPPING() {
+            // separation 0.8 exceeds 0.3 + 0.4
+            let (r, sigmas) = evaluate_scalar_eq_approx_with_uncertainty(&10.0, 0.3, &10.8, -0.4, UncertaintyCriterion::IntervalsOverlap);
+
+            assert_eq!(ComparisonResult::Unequal, r);
+            assert_scalar_eq_approx!(1.6, sigmas, margin(1e-12));
+
+            assert_eq!((ComparisonResult::Unequal, f64::INFINITY), evaluate_scalar_eq_approx_with_uncertainty(&1.0, 0.0, &1.1, 0.0, UncertaintyCriterion::IntervalsOverlap));
+        }
+
+        #[test]
+        fn TEST_assert_scalar_eq_approx_with_uncertainty_1() {
+            assert_scalar_eq_approx_with_uncertainty!(9.81, 0.02, 9.79, 0.01);
+            assert_scalar_eq_approx_with_uncertainty!(10.0, 0.3, 10.5, 0.4, UncertaintyCriterion::CombinedUncertainty);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify equality within uncertainty: expected=10.0 ± 0.3, actual=10.85 ± 0.4: separation 1.7σ (IntervalsOverlap)")]
+        fn TEST_assert_scalar_eq_approx_with_uncertainty_SHOULD_FAIL() {
+            assert_scalar_eq_approx_with_uncertainty!(10.0, 0.3, 10.85, 0.4);
+        }
+    }
+
+
+    mod TEST_APPROX_INTEGER_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::evaluate_approx_integer;
+
+
+        #[test]
+        fn TEST_evaluate_approx_integer_1() {
+            let e = margin(1e-9);
+
+            assert_eq!((ComparisonResult::ExactlyEqual, 3.0, Some(1e-9), None), evaluate_approx_integer(&3.0, &e));
+            assert_eq!((ComparisonResult::ExactlyEqual, -7.0, Some(1e-9), None), evaluate_approx_integer(&-7, &e));
+            assert_eq!((ComparisonResult::ApproximatelyEqual, 3.0, Some(1e-9), None), evaluate_approx_integer(&(3.0 + 5e-10), &e));
+            assert_eq!((ComparisonResult::ApproximatelyEqual, 3.0, Some(1e-9), None), evaluate_approx_integer(&(3.0 - 5e-10), &e));
+            assert_eq!((ComparisonResult::Unequal, 3.0, Some(1e-9), None), evaluate_approx_integer(&(3.0 + 2e-9), &e));
+            assert_eq!(ComparisonResult::Unequal, evaluate_approx_integer(&f64::INFINITY, &e).0);
+            assert_eq!(ComparisonResult::Unequal, evaluate_approx_integer(&f64::NAN, &e).0);
+        }
+
+        #[test]
+        fn TEST_assert_approx_integer_1() {
+            assert_approx_integer!(42.0);
+            assert_approx_integer!(0.1 * 30.0, margin(1e-9));
+            assert_approx_integer!(1e6 - 5e-10, margin(1e-9));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate integer: value=2.000000002, nearest-integer=2.0, margin_factor=0.000000001")]
+        fn TEST_assert_approx_integer_JUST_OUTSIDE_SHOULD_FAIL() {
+            assert_approx_integer!(2.000000002, margin(1e-9));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate integer: value=2.5, nearest-integer=3.0")]
+        fn TEST_assert_approx_integer_SHOULD_FAIL() {
+            assert_approx_integer!(2.5);
+        }
+    }
+
+
+    mod TEST_RELATIVE_ERROR_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::evaluate_relative_error;
+
+
+        #[test]
+        fn TEST_evaluate_relative_error_1() {
+            assert_eq!((0.0, false), evaluate_relative_error(&2.0, &2.0));
+            assert_eq!((0.5, false), evaluate_relative_error(&2.0, &3.0));
+            assert_eq!((0.5, false), evaluate_relative_error(&-2.0, &-1.0));
+            assert_eq!((0.25, true), evaluate_relative_error(&0.0, &-0.25));
+        }
+
+        #[test]
+        fn TEST_assert_relative_error_below_1() {
+            let error = assert_relative_error_below!(1000.0, 1000.0005, 1e-6);
+
+            assert!(error > 4.9e-7 && error < 5.1e-7);
+
+            assert_eq!(0.0, assert_relative_error_below!(1.0, 1.0, 0.0));
+            assert_eq!(0.0, assert_relative_error_below!(0.0, 0.0, 0.0));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify relative error: expected=1.0, actual=1.5: relative error 5e-1 exceeds 1e-6")]
+        fn TEST_assert_relative_error_below_SHOULD_FAIL_1() {
+            assert_relative_error_below!(1.0, 1.5, 1e-6);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify relative error: expected=0.0, actual=0.25: absolute error 2.5e-1 (expected is zero) exceeds 1e-6")]
+        fn TEST_assert_relative_error_below_SHOULD_FAIL_2() {
+            assert_relative_error_below!(0.0, 0.25, 1e-6);
+        }
+
+        #[test]
+        #[should_panic(expected = "relative error NaN exceeds 1e-6")]
+        fn TEST_assert_relative_error_below_SHOULD_FAIL_WITH_NAN() {
+            assert_relative_error_below!(1.0, f64::NAN, 1e-6);
+        }
+    }
+
+
+    mod TEST_CORRELATION_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_correlation,
+            CorrelationError,
+        };
+
+
+        #[test]
+        fn TEST_evaluate_correlation_1() {
+            assert_eq!(Ok(1.0), evaluate_correlation(&[1.0, 2.0, 3.0], &[10.0, 20.0, 30.0]));
+            assert_eq!(Ok(1.0), evaluate_correlation(&[1.0, 2.0, 3.0], &vec![101, 102, 103]));
+            assert_eq!(Ok(-1.0), evaluate_correlation(&[1.0, 2.0, 3.0], &[3.0, 2.0, 1.0]));
+            // means 2.5; covariance 0; => 0
+            assert_eq!(Ok(0.0), evaluate_correlation(&[1.0, 2.0, 3.0, 4.0], &[1.0, -1.0, -1.0, 1.0]));
+        }
+
+        #[test]
+        fn TEST_evaluate_correlation_ERRORS() {
+            assert_eq!(
+                Err(CorrelationError::DifferentLengths { expected_length : 2, actual_length : 3 }),
+                evaluate_correlation(&[1.0, 2.0], &[1.0, 2.0, 3.0])
+            );
+            assert_eq!(Err(CorrelationError::InsufficientElements { length : 1 }), evaluate_correlation(&[1.0], &[1.0]));
+            assert_eq!(
+                Err(CorrelationError::ZeroVariance { expected_is_constant : true, actual_is_constant : false }),
+                evaluate_correlation(&[2.0, 2.0, 2.0], &[1.0, 2.0, 3.0])
+            );
+            assert_eq!(
+                Err(CorrelationError::ZeroVariance { expected_is_constant : true, actual_is_constant : true }),
+                evaluate_correlation(&[0.0, 0.0], &[0.0, 0.0])
+            );
+        }
+
+        #[test]
+        fn TEST_assert_correlation_above_1() {
+            let correlation = assert_correlation_above!([0.0, 1.0, 0.0, -1.0, 0.0], [0.1, 2.1, 0.0, -1.9, 0.2], 0.99);
+
+            assert!(correlation > 0.99 && correlation <= 1.0);
+
+            assert_eq!(1.0, assert_correlation_above!([1.0, 2.0, 3.0], [5.0, 7.0, 9.0], 1.0));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify correlation for vectors: correlation -1 is below 0.9")]
+        fn TEST_assert_correlation_above_SHOULD_FAIL_1() {
+            assert_correlation_above!([1.0, 2.0, 3.0], [3.0, 2.0, 1.0], 0.9);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify correlation for vectors: correlation is undefined because actual is constant (zero variance)")]
+        fn TEST_assert_correlation_above_SHOULD_FAIL_WITH_ZERO_VARIANCE() {
+            assert_correlation_above!([1.0, 2.0, 3.0], [0.0, 0.0, 0.0], 0.9);
+        }
+    }
+
+
+    mod TEST_PERIODIC_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_periodic_approx,
+            PeriodInterpolation,
+            PeriodicComparisonResult,
+        };
+
+
+        fn sine_(
+            length : usize,
+            period_in_samples : f64,
+        ) -> Vec<f64> {
+            (0..length)
+                .map(|i| (std::f64::consts::TAU * i as f64 / period_in_samples).sin())
+                .collect()
+        }
+
+
+        #[test]
+        fn TEST_evaluate_periodic_approx_1() {
+            let e = margin(1e-9);
+
+            assert_eq!(
+                PeriodicComparisonResult::ExactlyEqual,
+                evaluate_periodic_approx(&[1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 1.0], 3.0, &e, PeriodInterpolation::Linear).0
+            );
+            assert_eq!(
+                PeriodicComparisonResult::InsufficientSamples { length : 3, period : 3.0 },
+                evaluate_periodic_approx(&[1.0, 2.0, 3.0], 3.0, &e, PeriodInterpolation::Linear).0
+            );
+            assert_eq!(
+                PeriodicComparisonResult::ExactlyEqual,
+                evaluate_periodic_approx(&vec![1, 2, 3, 1, 2], 3.0, &e, PeriodInterpolation::Linear).0
+            );
+            assert_eq!(
+                PeriodicComparisonResult::UnequalSamples { index : 2, value : 3.0, period_value : 3.5 },
+                evaluate_periodic_approx(&[1.0, 2.0, 3.0, 1.0, 2.0, 3.5], 3.0, &e, PeriodInterpolation::Linear).0
+            );
+        }
+
+        #[test]
+        fn TEST_evaluate_periodic_approx_NON_INTEGRAL_PERIOD() {
+            let samples = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+
+            assert_eq!(
+                PeriodicComparisonResult::UnequalSamples { index : 0, value : 0.0, period_value : 2.5 },
+                evaluate_periodic_approx(&samples, 2.5, &margin(0.1), PeriodInterpolation::Linear).0
+            );
+            assert_eq!(
+                PeriodicComparisonResult::UnequalSamples { index : 0, value : 0.0, period_value : 3.0 },
+                evaluate_periodic_approx(&samples, 2.5, &margin(0.1), PeriodInterpolation::Nearest).0
+            );
+
+            let sine = sine_(40, 12.5);
+
+            assert_eq!(
+                PeriodicComparisonResult::ApproximatelyEqual,
+                evaluate_periodic_approx(&sine, 12.5, &margin(0.1), PeriodInterpolation::Linear).0
+            );
+        }
+
+        #[test]
+        fn TEST_assert_periodic_approx_CLEAN_SINE_AT_INTEGER_PERIOD() {
+            let sine = sine_(100, 20.0);
+
+            assert_periodic_approx!(sine, 20, margin(1e-12));
+            assert_periodic_approx!(sine, 40.0, margin(1e-12), PeriodInterpolation::Nearest);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate periodicity: samples[3]=1.0 differs from samples[3 + 4.0]=1.5, margin_factor=0.01")]
+        fn TEST_assert_periodic_approx_SHOULD_FAIL() {
+            assert_periodic_approx!([0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.5], 4, margin(0.01));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate periodicity: 3 sample(s) insufficient for period 4.0")]
+        fn TEST_assert_periodic_approx_INSUFFICIENT_SAMPLES_SHOULD_FAIL() {
+            assert_periodic_approx!([0.0, 1.0, 0.0], 4);
+        }
+    }
+
+
+    mod TEST_RMSE_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_rmse,
+            RmseError,
+        };
+
+
+        #[test]
+        fn TEST_evaluate_rmse_1() {
+            assert_eq!(Ok(0.0), evaluate_rmse(&[] as &[f64; 0], &[] as &[f64; 0]));
+            assert_eq!(Ok(0.0), evaluate_rmse(&[1.0, 2.0, 3.0], &vec![1, 2, 3]));
+            assert_eq!(Ok(1.0), evaluate_rmse(&[0.0, 0.0, 0.0, 0.0], &[1.0, -1.0, 1.0, -1.0]));
+            // sqrt((9 + 16) / 2)
+            assert_eq!(Ok(12.5_f64.sqrt()), evaluate_rmse(&[0.0, 0.0], &[3.0, 4.0]));
+            assert_eq!(
+                Err(RmseError::DifferentLengths { expected_length : 2, actual_length : 3 }),
+                evaluate_rmse(&[0.0, 0.0], &[0.0, 0.0, 0.0])
+            );
+        }
+
+        #[test]
+        fn TEST_assert_rmse_below_1() {
+            // differences 0.1, -0.1, 0.2, -0.2 => sqrt(0.10 / 4) = 0.158113883...
+            let rmse = assert_rmse_below!([1.0, 2.0, 3.0, 4.0], [1.1, 1.9, 3.2, 3.8], 0.16);
+
+            assert_scalar_eq_approx!(0.158113883008419, rmse, margin(1e-12));
+
+            assert_eq!(0.0, assert_rmse_below!([1.0, 2.0], [1.0, 2.0], 0.0));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify root-mean-square error for vectors: RMSE 1.0000e0 exceeds 5.0000e-1")]
+        fn TEST_assert_rmse_below_SHOULD_FAIL_1() {
+            assert_rmse_below!([0.0, 0.0, 0.0, 0.0], [1.0, -1.0, 1.0, -1.0], 0.5);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify root-mean-square error for vectors: expected-length 2 differs from actual-length 3")]
+        fn TEST_assert_rmse_below_SHOULD_FAIL_WITH_DIFFERENT_LENGTHS() {
+            assert_rmse_below!([0.0, 0.0], [0.0, 0.0, 0.0], 1.0);
+        }
+    }
+
+
+    mod TEST_STREAMING_VECTOR_COMPARATOR {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            StreamingVectorComparator,
+            VectorComparisonResult,
+        };
+
+
+        #[test]
+        fn TEST_StreamingVectorComparator_MATCHING_STREAM() {
+            let expected = vec![ 1.0, 2.0, 3.0 ];
+            let mut comparator = StreamingVectorComparator::with_evaluator(&expected, margin(0.01));
+
+            assert_eq!(ComparisonResult::ExactlyEqual, comparator.push(1.0));
+            assert_eq!(ComparisonResult::ApproximatelyEqual, comparator.push(2.005));
+            assert_eq!(ComparisonResult::ExactlyEqual, comparator.push(3));
+            assert_eq!(3, comparator.num_pushed());
+
+            let (comparison_result, margin_factor, _) = comparator.finish();
+
+            assert!(matches!(comparison_result, VectorComparisonResult::ApproximatelyEqual));
+            assert_eq!(Some(0.01), margin_factor);
+        }
+
+        #[test]
+        fn TEST_StreamingVectorComparator_UNEQUAL_ELEMENT() {
+            let expected = [ 1.0, 2.0, 3.0 ];
+            let mut comparator = StreamingVectorComparator::new(&expected);
+
+            assert_eq!(ComparisonResult::ExactlyEqual, comparator.push(1.0));
+            assert_eq!(ComparisonResult::Unequal, comparator.push(2.5));
+            assert_eq!(ComparisonResult::Unequal, comparator.push(3.5));
+
+            assert!(matches!(
+                comparator.finish().0,
+                VectorComparisonResult::UnequalElements {
+                    index_of_first_unequal_element : 1,
+                    expected_value_of_first_unequal_element,
+                    actual_value_of_first_unequal_element,
+                } if 2.0 == expected_value_of_first_unequal_element && 2.5 == actual_value_of_first_unequal_element
+            ));
+        }
+
+        #[test]
+        fn TEST_StreamingVectorComparator_LENGTH_MISMATCHES() {
+            let expected = [ 1.0, 2.0 ];
+
+            let mut comparator = StreamingVectorComparator::new(&expected[..]);
+
+            comparator.push(1.0);
+
+            assert!(matches!(comparator.finish().0, VectorComparisonResult::DifferentLengths { expected_length : 2, actual_length : 1 }));
+
+            let mut comparator = StreamingVectorComparator::new(&expected);
+
+            comparator.push(1.0);
+            comparator.push(2.0);
+
+            assert_eq!(ComparisonResult::Unequal, comparator.push(3.0));
+            assert!(matches!(comparator.finish().0, VectorComparisonResult::DifferentLengths { expected_length : 2, actual_length : 3 }));
+        }
+    }
+
+
+    mod TEST_IN_MEMORY_METRICS_SINK {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            traits::MetricsSink,
+            InMemoryMetricsSink,
+        };
+
+
+        #[test]
+        fn TEST_InMemoryMetricsSink_EMPTY() {
+            let sink = InMemoryMetricsSink::new();
+
+            assert_eq!(0, sink.num_comparisons());
+            assert_eq!(None, sink.min_headroom());
+            assert_eq!(None, sink.max_headroom());
+            assert_eq!(None, sink.mean_headroom());
+        }
+
+        #[test]
+        fn TEST_InMemoryMetricsSink_record() {
+            let sink = InMemoryMetricsSink::new();
+
+            sink.record(0.0, 0.1);
+            sink.record(0.5, 1.0);
+            sink.record(0.3, 0.2);
+
+            assert_eq!(3, sink.num_comparisons());
+            assert_eq!(Some(1.0), sink.max_headroom());
+            assert_scalar_eq_approx!(-0.5, sink.min_headroom().unwrap(), margin(1e-12));
+            assert_scalar_eq_approx!(1.0 / 3.0, sink.mean_headroom().unwrap(), margin(1e-12));
+        }
+    }
+
+
+    mod TEST_VECTOR_SMART_POINTER_COMPARANDS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_vector_eq_approx,
+            VectorComparisonResult,
+        };
+
+        use std::{
+            borrow::Cow,
+            sync::Arc,
+        };
+
+
+        #[test]
+        fn TEST_assert_vector_eq_approx_WITH_Cow() {
+            let values = [ 1.0, 2.0, 3.0 ];
+            let borrowed : Cow<'_, [f64]> = Cow::Borrowed(&values);
+            let owned : Cow<'_, [f64]> = Cow::Owned(vec![ 1.0, 2.0, 3.0000000001 ]);
+
+            assert_vector_eq_approx!(borrowed, owned);
+            assert_vector_eq_approx!(Cow::Borrowed(&values[..]), [ 1.0, 2.0, 3.0 ]);
+            assert_vector_ne_approx!(borrowed, [ 1.0, 2.0, 3.1 ]);
+        }
+
+        #[test]
+        fn TEST_assert_vector_eq_approx_WITH_Arc_AND_Rc() {
+            let arc : Arc<[f64]> = Arc::from(vec![ 1.0, 2.0, 3.0 ]);
+            let rc : std_rc::Rc<[f64]> = std_rc::Rc::from(vec![ 1.0, 2.0, 3.0000000001 ]);
+
+            assert_vector_eq_approx!(arc, rc);
+            assert_vector_eq_approx!(arc, vec![ 1.0, 2.0, 3.0 ], margin(0.001));
+            assert_vector_ne_approx!(rc, [ 1.0, 2.0 ]);
+
+            assert!(matches!(evaluate_vector_eq_approx(&arc, &rc, &margin(0.001)).0, VectorComparisonResult::ApproximatelyEqual));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for vectors: at index 2 expected=3.0, actual=3.5")]
+        fn TEST_assert_vector_eq_approx_WITH_Arc_SHOULD_FAIL() {
+            let arc : Arc<[f64]> = Arc::from([ 1.0, 2.0, 3.0 ]);
+
+            assert_vector_eq_approx!(arc, Cow::Borrowed(&[ 1.0, 2.0, 3.5 ][..]));
+        }
+    }
+
+
+    mod TEST_VECTOR_WEIGHTED {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_vector_eq_approx_weighted,
+            VectorComparisonResult,
+            WeightsError,
+        };
+
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_weighted_HIGH_WEIGHT_SMALL_ERROR_FAILS() {
+            let expected = [ 10.0, 1.0 ];
+            let actual = [ 10.0, 1.001 ];
+            let weights = [ 1.0, 100.0 ];
+
+            let (comparison_result, margin_factor, _) = evaluate_vector_eq_approx_weighted(&expected, &actual, &weights, 0.01).unwrap();
+
+            assert!(matches!(comparison_result, VectorComparisonResult::UnequalElements { index_of_first_unequal_element : 1, .. }));
+            assert_eq!(Some(0.01), margin_factor);
+
+            // unweighted, the same error is within the margin
+            let (comparison_result, ..) = evaluate_vector_eq_approx_weighted(&expected, &actual, &[ 1.0, 1.0 ], 0.01).unwrap();
+
+            assert!(matches!(comparison_result, VectorComparisonResult::ApproximatelyEqual));
+        }
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_weighted_LOW_WEIGHT_LARGE_ERROR_PASSES() {
+            let expected = vec![ 10.0, 1.0, 5.0 ];
+            let actual = vec![ 10.5, 1.0, 5.0 ];
+            let weights = vec![ 0.01, 100.0, 1.0 ];
+
+            let (comparison_result, ..) = evaluate_vector_eq_approx_weighted(&expected, &actual, &weights, 0.01).unwrap();
+
+            assert!(matches!(comparison_result, VectorComparisonResult::ApproximatelyEqual));
+
+            let (comparison_result, ..) = evaluate_vector_eq_approx_weighted(&expected, &expected, &weights, 0.01).unwrap();
+
+            assert!(matches!(comparison_result, VectorComparisonResult::ExactlyEqual));
+        }
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_weighted_LENGTH_VALIDATION() {
+            let expected = [ 1.0, 2.0 ];
+
+            assert_eq!(
+                Err(WeightsError::DifferentLengths { expected_length : 2, weights_length : 3 }),
+                evaluate_vector_eq_approx_weighted(&expected, &expected, &[ 1.0, 1.0, 1.0 ], 0.01).map(|_| ())
+            );
+
+            let (comparison_result, ..) = evaluate_vector_eq_approx_weighted(&expected, &[ 1.0 ], &[ 1.0, 1.0 ], 0.01).unwrap();
+
+            assert!(matches!(comparison_result, VectorComparisonResult::DifferentLengths { expected_length : 2, actual_length : 1 }));
+        }
+    }
+
+
+    mod TEST_VECTOR_UNORDERED {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_vector_eq_approx_unordered,
+            evaluate_vector_eq_approx_unordered_with_nan_ordering,
+            NanOrdering,
+            UnorderedComparisonResult,
+        };
+
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_unordered_DIFFERENT_ORDERS() {
+            let expected = [ 1.0, 2.0, 3.0, 2.0 ];
+
+            assert_eq!(UnorderedComparisonResult::ExactlyEqual, evaluate_vector_eq_approx_unordered(&expected, &[ 2.0, 3.0, 2.0, 1.0 ], &margin(0.01)).0);
+
+            let (comparison_result, margin_factor, _) = evaluate_vector_eq_approx_unordered(&expected, &vec![ 3.001, 2.0, 0.999, 2.0 ], &margin(0.01));
+
+            assert_eq!(UnorderedComparisonResult::ApproximatelyEqual, comparison_result);
+            assert_eq!(Some(0.01), margin_factor);
+        }
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_unordered_UNMATCHED() {
+            assert_eq!(
+                UnorderedComparisonResult::Unmatched { unmatched_expected_values : vec![ 2.0 ], unmatched_actual_values : vec![ 5.0 ] },
+                evaluate_vector_eq_approx_unordered(&[ 1.0, 2.0, 3.0 ], &[ 3.0, 5.0, 1.0 ], &margin(0.01)).0
+            );
+
+            // multiplicity matters
+            assert_eq!(
+                UnorderedComparisonResult::Unmatched { unmatched_expected_values : vec![], unmatched_actual_values : vec![ 1.0 ] },
+                evaluate_vector_eq_approx_unordered(&[ 1.0, 2.0 ], &[ 1.0, 2.0, 1.0 ], &margin(0.01)).0
+            );
+        }
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_unordered_with_nan_ordering_TOTAL_CMP() {
+            let e = margin(0.01);
+            let expected = [ f64::NAN, 1.0, -f64::NAN ];
+
+            assert_eq!(
+                UnorderedComparisonResult::ExactlyEqual,
+                evaluate_vector_eq_approx_unordered_with_nan_ordering(&expected, &[ -f64::NAN, 1.0, f64::NAN ], &e, NanOrdering::TotalCmp).0
+            );
+
+            let comparison_result = evaluate_vector_eq_approx_unordered_with_nan_ordering(&expected, &[ f64::NAN, 1.0, f64::NAN ], &e, NanOrdering::TotalCmp).0;
+
+            assert!(matches!(
+                comparison_result,
+                UnorderedComparisonResult::Unmatched { ref unmatched_expected_values, ref unmatched_actual_values }
+                    if unmatched_expected_values.len() == 1 && unmatched_expected_values[0].is_sign_negative() && unmatched_actual_values.len() == 1 && unmatched_actual_values[0].is_sign_positive()
+            ));
+
+            // by default NaNs are matched only by virtue of the "nan-equality" feature
+            #[cfg(feature = "nan-equality")]
+            assert_eq!(UnorderedComparisonResult::ExactlyEqual, evaluate_vector_eq_approx_unordered(&[ f64::NAN ], &[ -f64::NAN ], &e).0);
+            #[cfg(not(feature = "nan-equality"))]
+            assert!(matches!(evaluate_vector_eq_approx_unordered(&[ f64::NAN ], &[ f64::NAN ], &e).0, UnorderedComparisonResult::Unmatched { .. }));
+        }
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_unordered_GREEDY_LIMITATION() {
+            assert_eq!(
+                UnorderedComparisonResult::Unmatched { unmatched_expected_values : vec![ 1.1 ], unmatched_actual_values : vec![ 0.95 ] },
+                evaluate_vector_eq_approx_unordered(&[ 1.0, 1.1 ], &[ 1.05, 0.95 ], &margin(0.1)).0
+            );
+        }
+    }
+
+
+    mod TEST_VECTOR_SORTED {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_vector_eq_approx_sorted,
+            evaluate_vector_eq_approx_sorted_with_nan_ordering,
+            NanOrdering,
+            VectorComparisonResult,
+        };
 
-    /// T.B.C.
-    pub(crate) fn compare_approximate_equality_by_margin(
-        expected : f64,
-        actual : f64,
-        margin_factor : f64,
-    ) -> ComparisonResult {
-        debug_assert!(
-            margin_factor >= 0.0,
-            "`margin_factor` must not be negative, but {margin_factor} given"
-        );
 
-        if expected == actual {
-            return ComparisonResult::ExactlyEqual;
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_sorted_PERMUTATIONS() {
+            let expected = [ 3.0, 1.0, 2.0, 2.0 ];
+
+            assert_eq!(VectorComparisonResult::ExactlyEqual, evaluate_vector_eq_approx_sorted(&expected, &[ 2.0, 2.0, 3.0, 1.0 ], &margin(0.01)).0);
+            assert_eq!(VectorComparisonResult::ApproximatelyEqual, evaluate_vector_eq_approx_sorted(&expected, &vec![ 1.001, 2.0, 2.0, 2.999 ], &margin(0.01)).0);
         }
 
-        #[cfg(feature = "nan-equality")]
-        {
-            if expected.is_nan() && actual.is_nan() {
-                return ComparisonResult::ExactlyEqual;
-            }
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_sorted_REPORTS_SORTED_INDEX() {
+            assert_eq!(
+                VectorComparisonResult::UnequalElements {
+                    index_of_first_unequal_element :          1,
+                    expected_value_of_first_unequal_element : 2.0,
+                    actual_value_of_first_unequal_element :   2.5,
+                },
+                evaluate_vector_eq_approx_sorted(&[ 3.0, 2.0, 1.0 ], &[ 1.0, 3.0, 2.5 ], &margin(0.01)).0
+            );
         }
 
-        // TODO: determine if can elide this explicit check
-        if 0.0 == margin_factor {
-            return ComparisonResult::Unequal;
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_sorted_NANS_LAST() {
+            let e = margin(0.01);
+
+            // NaNs sort last irrespective of sign, so are compared with each other
+            let expected = [ f64::NAN, 1.0, -f64::NAN ];
+            let actual = [ -f64::NAN, f64::NAN, 1.0 ];
+
+            #[cfg(feature = "nan-equality")]
+            assert_eq!(VectorComparisonResult::ExactlyEqual, evaluate_vector_eq_approx_sorted(&expected, &actual, &e).0);
+            #[cfg(not(feature = "nan-equality"))]
+            assert!(matches!(
+                evaluate_vector_eq_approx_sorted(&expected, &actual, &e).0,
+                VectorComparisonResult::UnequalElements { index_of_first_unequal_element : 1, .. }
+            ));
         }
 
-        let expected_lo = expected - margin_factor;
-        let expected_hi = expected + margin_factor;
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_sorted_with_nan_ordering_TOTAL_CMP() {
+            let e = margin(0.01);
 
-        result_from_range_(expected_lo, expected_hi, actual)
+            // -NaN sorts first and +NaN last, and each matches only its like
+            let expected = [ f64::NAN, 1.0, -f64::NAN, 2.0 ];
+
+            assert_eq!(
+                VectorComparisonResult::ApproximatelyEqual,
+                evaluate_vector_eq_approx_sorted_with_nan_ordering(&expected, &[ 2.0, -f64::NAN, f64::NAN, 1.005 ], &e, NanOrdering::TotalCmp).0
+            );
+            assert!(matches!(
+                evaluate_vector_eq_approx_sorted_with_nan_ordering(&expected, &[ 2.0, f64::NAN, f64::NAN, 1.0 ], &e, NanOrdering::TotalCmp).0,
+                VectorComparisonResult::UnequalElements { index_of_first_unequal_element : 0, .. }
+            ));
+
+            // evaluate_vector_eq_approx_sorted() uses the default
+            assert_eq!(NanOrdering::Last, NanOrdering::default());
+        }
+
+        #[test]
+        fn TEST_assert_vector_eq_approx_sorted_1() {
+            assert_vector_eq_approx_sorted!([ 0.5, -1.0, 4.0 ], [ 4.0, 0.5, -1.0 ]);
+            assert_vector_eq_approx_sorted!(vec![ 10.0, 20.0, 30.0 ], [ 29.99, 10.01, 20.0 ], margin(0.05));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for sorted vectors: at sorted index 1 expected=2.0, actual=2.5, margin_factor=0.01")]
+        fn TEST_assert_vector_eq_approx_sorted_SHOULD_FAIL() {
+            assert_vector_eq_approx_sorted!([ 1.0, 2.0, 3.0 ], [ 3.0, 1.0, 2.5 ], margin(0.01));
+        }
     }
 
-    /// T.B.C.
-    pub(crate) fn compare_approximate_equality_by_multiplier(
-        expected : f64,
-        actual : f64,
-        multiplier_factor : f64,
-    ) -> ComparisonResult {
-        debug_assert!(
-            multiplier_factor >= 0.0,
-            "`multiplier_factor` must not be negative, but {multiplier_factor} given"
-        );
 
-        if expected == actual {
-            return ComparisonResult::ExactlyEqual;
+    mod TEST_VECTOR_BOXED_ITER {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_boxed_iter_eq_approx,
+            VectorComparisonResult,
+        };
+
+
+        #[test]
+        fn TEST_evaluate_boxed_iter_eq_approx_EQUAL() {
+            let e = margin(0.01);
+
+            assert_eq!(VectorComparisonResult::ExactlyEqual, evaluate_boxed_iter_eq_approx(Box::new(std::iter::empty()), Box::new(std::iter::empty()), &e).0);
+            assert_eq!(VectorComparisonResult::ExactlyEqual, evaluate_boxed_iter_eq_approx(Box::new((1..=3).map(f64::from)), Box::new(vec![ 1.0, 2.0, 3.0 ].into_iter()), &e).0);
+            assert_eq!(
+                (VectorComparisonResult::ApproximatelyEqual, Some(0.01), None),
+                evaluate_boxed_iter_eq_approx(Box::new((1..=3).map(f64::from)), Box::new([ 1.0, 2.005, 3.0 ].into_iter()), &e)
+            );
         }
 
-        #[cfg(feature = "nan-equality")]
-        {
-            if expected.is_nan() && actual.is_nan() {
-                return ComparisonResult::ExactlyEqual;
+        #[test]
+        fn TEST_evaluate_boxed_iter_eq_approx_DIFFERENT_LENGTHS() {
+            let e = margin(0.01);
+
+            assert_eq!(
+                VectorComparisonResult::DifferentLengths { expected_length : 5, actual_length : 3 },
+                evaluate_boxed_iter_eq_approx(Box::new((0..5).map(f64::from)), Box::new((0..3).map(f64::from)), &e).0
+            );
+            assert_eq!(
+                VectorComparisonResult::DifferentLengths { expected_length : 0, actual_length : 2 },
+                evaluate_boxed_iter_eq_approx(Box::new(std::iter::empty()), Box::new([ 1.0, 2.0 ].into_iter()), &e).0
+            );
+
+            // a difference in length is reported in preference to an unequal element
+            assert_eq!(
+                VectorComparisonResult::DifferentLengths { expected_length : 2, actual_length : 3 },
+                evaluate_boxed_iter_eq_approx(Box::new([ 1.0, 2.0 ].into_iter()), Box::new([ 9.0, 2.0, 3.0 ].into_iter()), &e).0
+            );
+        }
+
+        #[test]
+        fn TEST_evaluate_boxed_iter_eq_approx_UNEQUAL_ELEMENTS() {
+            let values = [ 1.0, 2.0, 3.0, 4.0 ];
+
+            assert_eq!(
+                (
+                    VectorComparisonResult::UnequalElements {
+                        index_of_first_unequal_element :          2,
+                        expected_value_of_first_unequal_element : 3.0,
+                        actual_value_of_first_unequal_element :   3.5,
+                    },
+                    Some(0.01),
+                    None
+                ),
+                evaluate_boxed_iter_eq_approx(Box::new(values.iter().copied()), Box::new(values.iter().map(|&v| if v > 2.5 { v + 0.5 } else { v })), &margin(0.01))
+            );
+        }
+
+        /// Evaluator that counts the number of evaluations it performs.
+        struct CountingEvaluator<'a> {
+            evaluations : &'a std::cell::Cell<usize>,
+        }
+
+        impl ApproximateEqualityEvaluator for CountingEvaluator<'_> {
+            fn evaluate(
+                &self,
+                expected : f64,
+                actual : f64,
+            ) -> (ComparisonResult, Option<f64>, Option<f64>) {
+                self.evaluations.set(self.evaluations.get() + 1);
+
+                margin(0.01).evaluate(expected, actual)
             }
         }
 
-        // TODO: determine if can elide this explicit check
-        if 0.0 == multiplier_factor {
-            return ComparisonResult::Unequal;
+        #[test]
+        fn TEST_evaluate_boxed_iter_eq_approx_CEASES_EVALUATION_AT_FIRST_UNEQUAL_ELEMENT() {
+            let evaluations = std::cell::Cell::new(0);
+            let e = CountingEvaluator { evaluations : &evaluations };
+
+            // the items beyond the mismatch at index 1 are not evaluated
+            assert_eq!(
+                VectorComparisonResult::UnequalElements {
+                    index_of_first_unequal_element :          1,
+                    expected_value_of_first_unequal_element : 2.0,
+                    actual_value_of_first_unequal_element :   9.0,
+                },
+                evaluate_boxed_iter_eq_approx(Box::new((1..=5).map(f64::from)), Box::new([ 1.0, 9.0, 3.0, 4.0, 5.0 ].into_iter()), &e).0
+            );
+            assert_eq!(2, evaluations.get());
+
+            // ... but are still counted, so that a difference in length is
+            // reported in preference to the mismatch
+            evaluations.set(0);
+
+            assert_eq!(
+                VectorComparisonResult::DifferentLengths { expected_length : 5, actual_length : 6 },
+                evaluate_boxed_iter_eq_approx(Box::new((1..=5).map(f64::from)), Box::new([ 1.0, 9.0, 3.0, 4.0, 5.0, 6.0 ].into_iter()), &e).0
+            );
+            assert_eq!(2, evaluations.get());
         }
+    }
 
-        let expected_lo = expected * (1.0 - multiplier_factor);
-        let expected_hi = expected * (1.0 + multiplier_factor);
 
-        result_from_range_(expected_lo, expected_hi, actual)
+    mod TEST_SET {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_set_eq_approx,
+            SetComparisonResult,
+        };
+
+        use std::collections::HashSet;
+
+
+        #[test]
+        fn TEST_evaluate_set_eq_approx_EQUAL() {
+            let e = margin(0.01);
+
+            assert_eq!(SetComparisonResult::ExactlyEqual, evaluate_set_eq_approx(&[] as &[f64; 0], &[] as &[f64; 0], &e).0);
+            assert_eq!(SetComparisonResult::ExactlyEqual, evaluate_set_eq_approx(&[ 3.0, 1.0, 2.0 ], &[ 1.0, 2.0, 3.0 ], &e).0);
+            assert_eq!(
+                (SetComparisonResult::ApproximatelyEqual, Some(0.01), None),
+                evaluate_set_eq_approx(&[ 3.0, 1.0, 2.0 ], &vec![ 2.001, 2.999, 1.0 ], &e)
+            );
+
+            // duplicates are ignored
+            assert_eq!(SetComparisonResult::ExactlyEqual, evaluate_set_eq_approx(&[ 1.0, 2.0, 1.0 ], &[ 2.0, 1.0, 2.0, 2.0 ], &e).0);
+        }
+
+        #[test]
+        fn TEST_evaluate_set_eq_approx_FROM_HashSet() {
+            // e.g. values held as their bit patterns, which are hashable
+            let expected : HashSet<u64> = [ 0.5f64, 1.5, 2.5 ].iter().map(|v| v.to_bits()).collect();
+            let actual : HashSet<u64> = [ 2.5004f64, 0.4998, 1.5 ].iter().map(|v| v.to_bits()).collect();
+
+            let expected : Vec<f64> = expected.into_iter().map(f64::from_bits).collect();
+            let actual : Vec<f64> = actual.into_iter().map(f64::from_bits).collect();
+
+            assert_eq!(SetComparisonResult::ApproximatelyEqual, evaluate_set_eq_approx(&expected, &actual, &margin(0.001)).0);
+        }
+
+        #[test]
+        fn TEST_evaluate_set_eq_approx_UNMATCHED() {
+            let e = margin(0.01);
+
+            assert_eq!(
+                SetComparisonResult::UnmatchedExpectedValue { expected_value : 2.0 },
+                evaluate_set_eq_approx(&[ 1.0, 2.0, 3.0, 4.0 ], &[ 1.0, 3.0, 5.0, 6.0 ], &e).0
+            );
+            assert_eq!(
+                SetComparisonResult::UnmatchedActualValue { actual_value : 5.0 },
+                evaluate_set_eq_approx(&[ 1.0, 3.0 ], &[ 3.0, 5.0, 1.0 ], &e).0
+            );
+
+            // distinct values may not share a partner
+            assert_eq!(
+                SetComparisonResult::UnmatchedExpectedValue { expected_value : 1.005 },
+                evaluate_set_eq_approx(&[ 1.0, 1.005 ], &[ 1.002 ], &e).0
+            );
+        }
+
+        #[test]
+        fn TEST_evaluate_set_eq_approx_OVERLAPPING_TOLERANCES() {
+            // 1.0 is greedily partnered with 1.05 (rather than 0.95), leaving
+            // 1.1 without a partner, though a matching exists
+            assert_eq!(
+                SetComparisonResult::UnmatchedExpectedValue { expected_value : 1.1 },
+                evaluate_set_eq_approx(&[ 1.0, 1.1 ], &[ 1.05, 0.95 ], &margin(0.1)).0
+            );
+        }
     }
 
-    /// T.B.C.
-    pub(crate) fn compare_approximate_equality_by_zero_margin_or_multiplier(
-        expected : f64,
-        actual : f64,
-        multiplier_factor : f64,
-        margin_factor : f64,
-    ) -> ComparisonResult {
-        debug_assert!(
-            multiplier_factor >= 0.0,
-            "`multiplier_factor` must not be negative, but {multiplier_factor} given"
-        );
-        debug_assert!(
-            margin_factor >= 0.0,
-            "`margin_factor` must not be negative, but {margin_factor} given"
-        );
 
-        if expected == actual {
-            return ComparisonResult::ExactlyEqual;
+    mod TEST_BYTES_AS_FLOATS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_bytes_as_f32_eq_approx,
+            evaluate_bytes_as_f64_eq_approx,
+            BytesComparisonResult,
+            Endianness,
+        };
+
+
+        fn f64s_to_bytes(
+            values : &[f64],
+            endianness : Endianness,
+        ) -> Vec<u8> {
+            values
+                .iter()
+                .flat_map(|v| match endianness {
+                    Endianness::Little => v.to_le_bytes(),
+                    Endianness::Big => v.to_be_bytes(),
+                })
+                .collect()
         }
 
-        #[cfg(feature = "nan-equality")]
-        {
-            if expected.is_nan() && actual.is_nan() {
-                return ComparisonResult::ExactlyEqual;
+        #[test]
+        fn TEST_evaluate_bytes_as_f64_eq_approx_1() {
+            let e = margin(0.01);
+
+            for endianness in [Endianness::Little, Endianness::Big] {
+                let expected = f64s_to_bytes(&[1.0, 2.0, 3.0], endianness);
+
+                assert_eq!(BytesComparisonResult::ExactlyEqual, evaluate_bytes_as_f64_eq_approx(&[], &[], endianness, &e).0);
+                assert_eq!(BytesComparisonResult::ExactlyEqual, evaluate_bytes_as_f64_eq_approx(&expected, &expected, endianness, &e).0);
+                assert_eq!(BytesComparisonResult::ApproximatelyEqual, evaluate_bytes_as_f64_eq_approx(&expected, &f64s_to_bytes(&[1.0, 2.005, 3.0], endianness), endianness, &e).0);
+                assert_eq!(
+                    BytesComparisonResult::UnequalElements {
+                        byte_offset_of_first_unequal_element :    16,
+                        expected_value_of_first_unequal_element : 3.0,
+                        actual_value_of_first_unequal_element :   3.5,
+                    },
+                    evaluate_bytes_as_f64_eq_approx(&expected, &f64s_to_bytes(&[1.0, 2.0, 3.5], endianness), endianness, &e).0
+                );
             }
         }
 
-        let (expected_lo, expected_hi) = if 0.0 == expected || 0.0 == actual {
-            // TODO: determine if can elide this explicit check
-            if 0.0 == margin_factor {
-                return ComparisonResult::Unequal;
-            }
+        #[test]
+        fn TEST_evaluate_bytes_as_f64_eq_approx_WRONG_ENDIANNESS() {
+            let expected = f64s_to_bytes(&[1.0], Endianness::Little);
+            let actual = f64s_to_bytes(&[1.0], Endianness::Big);
+
+            assert!(evaluate_bytes_as_f64_eq_approx(&expected, &actual, Endianness::Little, &margin(0.01)).0 != BytesComparisonResult::ExactlyEqual);
+        }
+
+        #[test]
+        fn TEST_evaluate_bytes_as_f64_eq_approx_LENGTH_MISMATCHES() {
+            let e = margin(0.01);
+
+            assert_eq!(
+                BytesComparisonResult::DifferentByteLengths { expected_byte_length : 16, actual_byte_length : 8 },
+                evaluate_bytes_as_f64_eq_approx(&[0; 16], &[0; 8], Endianness::Little, &e).0
+            );
+            assert_eq!(
+                BytesComparisonResult::IncompleteChunk { byte_length : 12, chunk_size : 8 },
+                evaluate_bytes_as_f64_eq_approx(&[0; 12], &[0; 12], Endianness::Little, &e).0
+            );
+            assert_eq!(BytesComparisonResult::ExactlyEqual, evaluate_bytes_as_f32_eq_approx(&[0; 12], &[0; 12], Endianness::Little, &e).0);
+        }
+
+        #[test]
+        fn TEST_evaluate_bytes_as_f32_eq_approx_1() {
+            let e = margin(0.01);
+
+            let expected : Vec<u8> = [1.0f32, 2.0].iter().flat_map(|v| v.to_be_bytes()).collect();
+            let actual : Vec<u8> = [1.0f32, 2.5].iter().flat_map(|v| v.to_be_bytes()).collect();
+
+            assert_eq!(BytesComparisonResult::ExactlyEqual, evaluate_bytes_as_f32_eq_approx(&expected, &expected, Endianness::Big, &e).0);
+            assert_eq!(
+                BytesComparisonResult::UnequalElements {
+                    byte_offset_of_first_unequal_element :    4,
+                    expected_value_of_first_unequal_element : 2.0,
+                    actual_value_of_first_unequal_element :   2.5,
+                },
+                evaluate_bytes_as_f32_eq_approx(&expected, &actual, Endianness::Big, &e).0
+            );
+        }
+    }
+
+
+    mod TEST_VECTOR_CONSTANT {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_vector_constant_approx,
+            ConstantComparisonResult,
+            ConstantReference,
+        };
+
+
+        #[test]
+        fn TEST_evaluate_vector_constant_approx_1() {
+            let e = margin(0.01);
+
+            assert_eq!(ConstantComparisonResult::ExactlyEqual, evaluate_vector_constant_approx(&[] as &[f64; 0], &e, ConstantReference::First).0);
+            assert_eq!(ConstantComparisonResult::ExactlyEqual, evaluate_vector_constant_approx(&[1.5, 1.5, 1.5], &e, ConstantReference::First).0);
+            assert_eq!(ConstantComparisonResult::ApproximatelyEqual, evaluate_vector_constant_approx(&[1.5, 1.505, 1.495], &e, ConstantReference::First).0);
+            assert_eq!(ConstantComparisonResult::ExactlyEqual, evaluate_vector_constant_approx(&vec![1, 1, 1, 1], &e, ConstantReference::Mean).0);
+        }
+
+        #[test]
+        fn TEST_evaluate_vector_constant_approx_FIRST_VERSUS_MEAN() {
+            let values = [2.0, 2.5, 2.0, 2.5];
+
+            assert_eq!(
+                ConstantComparisonResult::StrayElement { index : 1, reference_value : 2.0, value : 2.5, deviation : 0.5 },
+                evaluate_vector_constant_approx(&values, &margin(0.3), ConstantReference::First).0
+            );
+            assert_eq!(ConstantComparisonResult::ApproximatelyEqual, evaluate_vector_constant_approx(&values, &margin(0.3), ConstantReference::Mean).0);
+            assert_eq!(
+                ConstantComparisonResult::StrayElement { index : 0, reference_value : 2.25, value : 2.0, deviation : -0.25 },
+                evaluate_vector_constant_approx(&values, &margin(0.2), ConstantReference::Mean).0
+            );
+        }
+
+        #[test]
+        fn TEST_assert_vector_constant_approx_1() {
+            assert_vector_constant_approx!([3.0, 3.0, 3.0]);
+            assert_vector_constant_approx!(vec![3.0, 3.0000001, 2.9999999], margin(1e-6));
+            assert_vector_constant_approx!([2.0, 2.5, 2.0, 2.5], margin(0.3), ConstantReference::Mean);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate constancy for vectors: at index 1 value=2.5 strays from reference=2.0 (First) by deviation=0.5, margin_factor=0.3")]
+        fn TEST_assert_vector_constant_approx_SHOULD_FAIL() {
+            assert_vector_constant_approx!([2.0, 2.5, 2.0, 2.5], margin(0.3));
+        }
+
+        #[test]
+        fn TEST_evaluate_vector_constant_approx_TARGET() {
+            let values = [5.0, 5.001, 4.999, 5.2];
+
+            assert_eq!(
+                ConstantComparisonResult::ApproximatelyEqual,
+                evaluate_vector_constant_approx(&[5.0, 5.001, 4.999], &margin(0.01), ConstantReference::Target(5.0)).0
+            );
+            assert_eq!(
+                ConstantComparisonResult::StrayElement { index : 0, reference_value : 5.5, value : 5.0, deviation : -0.5 },
+                evaluate_vector_constant_approx(&values, &margin(0.01), ConstantReference::Target(5.5)).0
+            );
+        }
+
+        #[test]
+        fn TEST_assert_vector_all_approx_1() {
+            assert_vector_all_approx!([5.0, 5.0, 5.0], 5.0);
+            assert_vector_all_approx!(vec![5, 5, 5], 5.0);
+            assert_vector_all_approx!([5.0, 5.001, 4.999], 5, margin(0.01));
+            assert_vector_all_approx!([] as [f64; 0], 5.0);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality of all elements to target: at index 2 value=5.5 deviates from target=5.0 by deviation=0.5, margin_factor=0.01")]
+        fn TEST_assert_vector_all_approx_SHOULD_FAIL_WITH_STRAY_ELEMENT() {
+            assert_vector_all_approx!([5.0, 5.001, 5.5, 4.999], 5.0, margin(0.01));
+        }
+    }
+
+
+    mod TEST_CUMSUM_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_cumsum_eq_approx,
+            VectorComparisonResult,
+        };
+
+
+        #[test]
+        fn TEST_evaluate_cumsum_eq_approx_1() {
+            let e = margin(0.01);
+
+            assert_eq!(VectorComparisonResult::ExactlyEqual, evaluate_cumsum_eq_approx(&[] as &[f64; 0], &[] as &[f64; 0], &e).0);
+            assert_eq!(VectorComparisonResult::ExactlyEqual, evaluate_cumsum_eq_approx(&[1.0, 2.0, 3.0], &vec![1, 2, 3], &e).0);
+            assert_eq!(
+                VectorComparisonResult::DifferentLengths { expected_length : 2, actual_length : 3 },
+                evaluate_cumsum_eq_approx(&[1.0, 2.0], &[1.0, 2.0, 0.0], &e).0
+            );
+            assert_eq!(
+                VectorComparisonResult::UnequalElements {
+                    index_of_first_unequal_element :          1,
+                    expected_value_of_first_unequal_element : 3.0,
+                    actual_value_of_first_unequal_element :   3.5,
+                },
+                evaluate_cumsum_eq_approx(&[1.0, 2.0, 3.0], &[1.0, 2.5, 2.5], &e).0
+            );
+        }
+
+        #[test]
+        fn TEST_assert_cumsum_eq_approx_WHERE_DIFFERENCES_CANCEL() {
+            // the elements differ by +/-0.25, but the running totals agree
+            // at every other index, and end equal
+            assert_vector_ne_approx!([1.0, 1.0, 1.0, 1.0], [1.25, 0.75, 1.25, 0.75], margin(0.1));
+            assert_cumsum_eq_approx!([1.0, 1.0, 1.0, 1.0], [1.25, 0.75, 1.25, 0.75], margin(0.3));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality of cumulative sums for vectors: at index 2 expected-total=3.0, actual-total=3.012, margin_factor=0.01")]
+        fn TEST_assert_cumsum_eq_approx_CATCHES_DRIFT() {
+            // each element is within the margin, but the running total drifts
+            let expected = [1.0, 1.0, 1.0, 1.0, 1.0];
+            let actual = [1.004, 1.004, 1.004, 1.004, 1.004];
+
+            assert_vector_eq_approx!(expected, actual, margin(0.01));
+            assert_cumsum_eq_approx!(expected, actual, margin(0.01));
+        }
+    }
+
+
+    mod TEST_VECTOR_IGNORING {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_vector_eq_approx_ignoring,
+            VectorComparisonResult,
+        };
+
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_ignoring_1() {
+            let e = margin(0.01);
+
+            assert_eq!(VectorComparisonResult::ExactlyEqual, evaluate_vector_eq_approx_ignoring(&[1.0, 2.0], &vec![1, 2], &e, &[]).0);
+            assert_eq!(
+                VectorComparisonResult::DifferentLengths { expected_length : 2, actual_length : 3 },
+                evaluate_vector_eq_approx_ignoring(&[1.0, 2.0], &[1.0, 2.0, 3.0], &e, &[2]).0
+            );
+            assert_eq!(
+                VectorComparisonResult::UnequalElements {
+                    index_of_first_unequal_element :          3,
+                    expected_value_of_first_unequal_element : 4.0,
+                    actual_value_of_first_unequal_element :   4.5,
+                },
+                evaluate_vector_eq_approx_ignoring(&[1.0, 2.0, 3.0, 4.0], &[1.0, 9.0, 3.0, 4.5], &e, &[1]).0
+            );
+        }
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_ignoring_ONLY_DIFFERENCE_IS_IGNORED() {
+            // element 2 is an embedded timestamp
+            let expected = [1.0, 2.0, 1_700_000_000.0, 4.0];
+            let actual = [1.0, 2.005, 1_700_000_123.0, 4.0];
+
+            assert_vector_ne_approx!(expected, actual, margin(0.01));
+            assert_eq!(
+                VectorComparisonResult::ApproximatelyEqual,
+                evaluate_vector_eq_approx_ignoring(&expected, &actual, &margin(0.01), &[2]).0
+            );
+            assert_eq!(
+                VectorComparisonResult::ApproximatelyEqual,
+                evaluate_vector_eq_approx_ignoring(&expected, &actual, &margin(0.01), &[7, 2, 2]).0
+            );
+
+            assert_vector_eq_approx_ignoring!(expected, actual, [2], margin(0.01));
+            assert_vector_eq_approx_ignoring!([0.0, f64::NAN], [0.0, 1.0], vec![1]);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for vectors (ignoring indices [2]): at index 3 expected=4.0, actual=4.5, margin_factor=0.01")]
+        fn TEST_assert_vector_eq_approx_ignoring_SHOULD_FAIL() {
+            assert_vector_eq_approx_ignoring!([1.0, 2.0, 3.0, 4.0], [1.0, 2.0, 99.0, 4.5], [2], margin(0.01));
+        }
+    }
 
-            let expected_lo = expected - margin_factor;
-            let expected_hi = expected + margin_factor;
 
-            (expected_lo, expected_hi)
-        } else {
-            // TODO: determine if can elide this explicit check
-            if 0.0 == multiplier_factor {
-                return ComparisonResult::Unequal;
-            }
+    mod TEST_VECTOR_NEGATION {
+        #![allow(non_snake_case)]
 
-            let expected_lo = expected * (1.0 - multiplier_factor);
-            let expected_hi = expected * (1.0 + multiplier_factor);
+        use super::*;
 
-            (expected_lo, expected_hi)
+        use test_helpers::{
+            evaluate_vector_eq_neg_approx,
+            VectorComparisonResult,
         };
 
-        result_from_range_(expected_lo, expected_hi, actual)
-    }
 
-    fn result_from_range_(
-        lo : f64,
-        hi : f64,
-        actual : f64,
-    ) -> ComparisonResult {
-        let r = if lo <= hi { lo..=hi } else { hi..=lo };
+        #[test]
+        fn TEST_evaluate_vector_eq_neg_approx_1() {
+            let e = margin(0.01);
+
+            assert_eq!(VectorComparisonResult::ExactlyEqual, evaluate_vector_eq_neg_approx(&[] as &[f64; 0], &[] as &[f64; 0], &e).0);
+            assert_eq!(VectorComparisonResult::ExactlyEqual, evaluate_vector_eq_neg_approx(&[1.0, -2.0, 0.0], &vec![-1, 2, 0], &e).0);
+            assert_eq!(VectorComparisonResult::ApproximatelyEqual, evaluate_vector_eq_neg_approx(&[1.0, -2.0], &[-1.005, 2.0], &e).0);
+            assert_eq!(
+                VectorComparisonResult::DifferentLengths { expected_length : 2, actual_length : 1 },
+                evaluate_vector_eq_neg_approx(&[1.0, 2.0], &[-1.0], &e).0
+            );
+            assert_eq!(
+                VectorComparisonResult::UnequalElements {
+                    index_of_first_unequal_element :          1,
+                    expected_value_of_first_unequal_element : -2.0,
+                    actual_value_of_first_unequal_element :   2.0,
+                },
+                evaluate_vector_eq_neg_approx(&[1.0, 2.0, 3.0], &[-1.0, 2.0, -3.0], &e).0
+            );
+        }
 
-        if r.contains(&actual) {
-            ComparisonResult::ApproximatelyEqual
-        } else {
-            ComparisonResult::Unequal
+        #[test]
+        fn TEST_assert_vector_eq_neg_approx_1() {
+            assert_vector_eq_neg_approx!([1.0, -2.0, 3.5], [-1.0, 2.0, -3.5]);
+            assert_vector_eq_neg_approx!(vec![0.5, -0.25], [-0.501, 0.25], margin(0.01));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate negation for vectors: at index 2 negated-expected=-3.0, actual=3.0, margin_factor=0.01")]
+        fn TEST_assert_vector_eq_neg_approx_SIGN_ERROR_SHOULD_FAIL() {
+            assert_vector_eq_neg_approx!([1.0, -2.0, 3.0, -4.0], [-1.0, 2.0, 3.0, 4.0], margin(0.01));
         }
     }
 
 
-    #[cfg(test)]
-    #[rustfmt::skip]
-    mod tests {
+    mod TEST_VECTOR_LABELED {
         #![allow(non_snake_case)]
 
+        use super::*;
 
-        use super::{
-            compare_approximate_equality_by_margin,
-            compare_approximate_equality_by_multiplier,
-            compare_approximate_equality_by_zero_margin_or_multiplier,
+        use test_helpers::{
+            evaluate_vector_eq_approx_labeled,
+            LabeledVectorComparisonResult,
         };
 
-        use super::super::ComparisonResult;
 
+        const LABELS : [&str; 3] = ["velocity_x", "velocity_y", "velocity_z"];
 
         #[test]
-        fn TEST_compare_approximate_equality_by_margin_1() {
+        fn TEST_evaluate_vector_eq_approx_labeled_1() {
+            let e = margin(0.01);
+
+            assert_eq!(LabeledVectorComparisonResult::ExactlyEqual, evaluate_vector_eq_approx_labeled(&[1.0, 2.0, 3.0], &vec![1, 2, 3], &LABELS, &e).0);
+            assert_eq!(LabeledVectorComparisonResult::ApproximatelyEqual, evaluate_vector_eq_approx_labeled(&[1.0, 2.0, 3.0], &[1.0, 2.005, 3.0], &LABELS, &e).0);
+            assert_eq!(
+                LabeledVectorComparisonResult::UnequalElements {
+                    label_of_first_unequal_element :          "velocity_z".into(),
+                    index_of_first_unequal_element :          2,
+                    expected_value_of_first_unequal_element : 3.0,
+                    actual_value_of_first_unequal_element :   3.5,
+                },
+                evaluate_vector_eq_approx_labeled(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.5], &LABELS, &e).0
+            );
+        }
 
-            // expected == actual == 0.0
-            {
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.0));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.0000001));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.000001));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.00001));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.0001));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.001));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.01));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.1));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_margin(0.0, 0.0, 0.5));
-            }
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_labeled_WITH_MISMATCHED_COUNTS() {
+            let e = margin(0.01);
 
-            // expected == 0.0, actual == 0.1, f == *
-            {
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.0, 0.1, 0.0));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.0, 0.1, 0.0000001));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.0, 0.1, 0.000001));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.0, 0.1, 0.00001));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.0, 0.1, 0.0001));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.0, 0.1, 0.001));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.0, 0.1, 0.01));
-                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_margin(0.0, 0.1, 0.1));
-                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_margin(0.0, 0.1, 0.5));
-            }
+            assert_eq!(
+                LabeledVectorComparisonResult::DifferentLengths { expected_length : 3, actual_length : 2 },
+                evaluate_vector_eq_approx_labeled(&[1.0, 2.0, 3.0], &[1.0, 2.0], &LABELS, &e).0
+            );
+            assert_eq!(
+                LabeledVectorComparisonResult::DifferentLabelCount { length : 2, label_count : 3 },
+                evaluate_vector_eq_approx_labeled(&[1.0, 2.0], &[1.0, 2.0], &LABELS, &e).0
+            );
+        }
 
-            // expected == 0.099, actual == 0.1, f == *
-            {
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.099, 0.1, 0.0));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.099, 0.1, 0.0000001));        // expected [ 0.0989999-0.0990001 ]
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.099, 0.1, 0.000001));         // expected [  0.098999-0.099001  ]
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.099, 0.1, 0.00001));          // expected [   0.09899-0.09901   ]
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_margin(0.099, 0.1, 0.0001));           // expected [    0.0989-0.0991    ]
-                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_margin(0.099, 0.1, 0.001)); // expected [     0.098-0.1       ]
-                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_margin(0.099, 0.1, 0.01));  // expected [     0.089-0.109     ]
-                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_margin(0.099, 0.1, 0.02));  // expected [     0.089-0.119     ]
-            }
+        #[test]
+        fn TEST_assert_vector_eq_approx_labeled_1() {
+            assert_vector_eq_approx_labeled!([1.0, 2.0, 3.0], [1.0, 2.0, 3.0], LABELS);
+            assert_vector_eq_approx_labeled!(vec![1.0, 2.0], [1.0, 2.001], ["p", "q"], margin(0.01));
         }
 
         #[test]
-        fn TEST_compare_approximate_equality_by_multiplier_1() {
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for vectors: at 'velocity_z' expected=3.0, actual=3.5, margin_factor=0.01")]
+        fn TEST_assert_vector_eq_approx_labeled_SHOULD_FAIL() {
+            assert_vector_eq_approx_labeled!([1.0, 2.0, 3.0], [1.0, 2.0, 3.5], LABELS, margin(0.01));
+        }
 
-            // expected == actual == 0.0
-            {
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.0));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.0000001));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.000001));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.00001));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.0001));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.001));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.01));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.1));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_multiplier(0.0, 0.0, 0.5));
-            }
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for vectors: label-count 3 differs from length 2")]
+        fn TEST_assert_vector_eq_approx_labeled_WITH_WRONG_LABEL_COUNT_SHOULD_FAIL() {
+            assert_vector_eq_approx_labeled!([1.0, 2.0], [1.0, 2.0], LABELS);
+        }
+    }
 
-            // expected == 0.0, actual == 0.1, f == *
-            {
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.0));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.0000001));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.000001));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.00001));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.0001));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.001));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.01));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.1));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.0, 0.1, 0.5));
-            }
 
-            // expected == 0.099, actual == 0.1, f == *
-            {
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.0));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.0000001)); // expected [ 0.0989999901-0.0990000099 ]
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.000001));   // expected [  0.098999901-0.099000099  ]
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.00001));     // expected [   0.09899901-0.09900099   ]
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.0001));       // expected [    0.0989901-0.0990099    ]
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.001));         // expected [     0.098901-0.099099     ]
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.01));           // expected [      0.09801-0.09999      ]
-                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.1));  // expected [       0.0891-0.1089       ]
-                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_multiplier(0.099, 0.1, 0.5));  // expected [       0.0495-0.1485       ]
-            }
-        }
+    mod TEST_VECTOR_UP_TO_SCALE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_vector_eq_approx_up_to_scale,
+            VectorComparisonResult,
+        };
+
 
         #[test]
-        fn TEST_compare_approximate_equality_by_zero_margin_or_multiplier_1() {
+        fn TEST_evaluate_vector_eq_approx_up_to_scale_1() {
+            let e = margin(1e-9);
+
+            assert_eq!((VectorComparisonResult::ExactlyEqual, 2.0, None, None), evaluate_vector_eq_approx_up_to_scale(&[2.0, 4.0, -6.0], &[1.0, 2.0, -3.0], &e));
+            assert_eq!((VectorComparisonResult::ExactlyEqual, 0.5, None, None), evaluate_vector_eq_approx_up_to_scale(&[1, 2, 3], &vec![2, 4, 6], &e));
+            assert_eq!((VectorComparisonResult::ExactlyEqual, 1.0, None, None), evaluate_vector_eq_approx_up_to_scale(&[0.0, 0.0], &[0.0, 0.0], &e));
+            assert_eq!(
+                (VectorComparisonResult::DifferentLengths { expected_length : 2, actual_length : 3 }, 1.0, None, None),
+                evaluate_vector_eq_approx_up_to_scale(&[1.0, 2.0], &[1.0, 2.0, 3.0], &e)
+            );
+        }
 
-            // expected == actual == 0.0
-            {
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.0, 0.0));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.0000001, 0.0000001));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.000001, 0.000001));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.00001, 0.00001));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.0001, 0.0001));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.001, 0.001));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.01, 0.01));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.1, 0.1));
-                assert_eq!(ComparisonResult::ExactlyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.0, 0.5, 0.5));
-            }
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_up_to_scale_WITH_MISMATCH() {
+            // expected = 2 * actual + [0, 3, -2], the latter being orthogonal
+            // to actual, so k = (2 + 14 + 12) / (1 + 4 + 9) = 2
+            let (r, k, _, _) = evaluate_vector_eq_approx_up_to_scale(&[2.0, 7.0, 4.0], &[1.0, 2.0, 3.0], &margin(0.01));
+
+            assert_eq!(2.0, k);
+            assert_eq!(
+                VectorComparisonResult::UnequalElements {
+                    index_of_first_unequal_element :          1,
+                    expected_value_of_first_unequal_element : 7.0,
+                    actual_value_of_first_unequal_element :   4.0,
+                },
+                r
+            );
+        }
 
-            // expected == 0.0, actual == 0.1, f == *
-            {
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.0, 0.0));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.0000001, 0.0000001));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.000001, 0.000001));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.00001, 0.00001));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.0001, 0.0001));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.001, 0.001));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.01, 0.01));
-                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.1, 0.1));
-                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.0, 0.1, 0.5, 0.5));
-            }
+        #[test]
+        fn TEST_assert_vector_eq_approx_up_to_scale_1() {
+            assert_vector_eq_approx_up_to_scale!([2.0, 4.0, 6.0], [1.0, 2.0, 3.0]);
+            assert_vector_eq_approx_up_to_scale!([0.6, 0.8], [-3.0, -4.0], margin(1e-12));
+        }
 
-            // expected == 0.099, actual == 0.1, f == *
-            {
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.0, 0.0));
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.0000001, 0.0000001)); // expected [ 0.0989999901-0.0990000099 ]
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.000001, 0.000001));     // expected [  0.098999901-0.099000099  ]
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.00001, 0.00001));         // expected [   0.09899901-0.09900099   ]
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.0001, 0.0001));             // expected [    0.0989901-0.0990099    ]
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.001, 0.001));                 // expected [     0.098901-0.099099     ]
-                assert_eq!(ComparisonResult::Unequal, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.01, 0.01));                     // expected [      0.09801-0.09999      ]
-                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.1, 0.1));              // expected [       0.0891-0.1089       ]
-                assert_eq!(ComparisonResult::ApproximatelyEqual, compare_approximate_equality_by_zero_margin_or_multiplier(0.099, 0.1, 0.5, 0.5));              // expected [       0.0495-0.1485       ]
-            }
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality up to scale for vectors: with estimated scale k=2.0, at index 1 expected=7.0, scaled-actual=4.0, margin_factor=0.01")]
+        fn TEST_assert_vector_eq_approx_up_to_scale_SHOULD_FAIL() {
+            assert_vector_eq_approx_up_to_scale!([2.0, 7.0, 4.0], [1.0, 2.0, 3.0], margin(0.01));
         }
     }
-}
 
 
-// /////////////////////////////////////////////////////////
-// API functions
+    mod TEST_VECTOR_TUPLES {
+        #![allow(non_snake_case)]
 
-pub fn evaluate_scalar_eq_approx<T_expected, T_actual>(
-    expected : &T_expected,
-    actual : &T_actual,
-    evaluator : &dyn traits::ApproximateEqualityEvaluator,
-) -> (
-    ComparisonResult, // comparison_result
-    Option<f64>,      // margin_factor
-    Option<f64>,      // multiplier_factor
-)
-where
-    T_expected : traits::TestableAsF64 + std_fmt::Debug,
-    T_actual : traits::TestableAsF64 + std_fmt::Debug,
-{
-    let (expected, actual) = {
-        let expected : &dyn traits::TestableAsF64 = expected;
-        let actual : &dyn traits::TestableAsF64 = actual;
+        use super::*;
 
-        let expected = expected.testable_as_f64();
-        let actual = actual.testable_as_f64();
+        use test_helpers::{
+            evaluate_tuple_vector_eq_approx,
+            TupleVectorComparisonResult,
+        };
 
-        (expected, actual)
-    };
 
-    evaluator.evaluate(expected, actual)
-}
+        #[test]
+        fn TEST_evaluate_tuple_vector_eq_approx_1() {
+            let e = margin(0.01);
+
+            assert_eq!(TupleVectorComparisonResult::ExactlyEqual, evaluate_tuple_vector_eq_approx(&[(1.0, 2.0, 3.0)], &vec![(1.0, 2.0, 3.0)], &e).0);
+            assert_eq!(TupleVectorComparisonResult::ApproximatelyEqual, evaluate_tuple_vector_eq_approx(&[(1.0, 2.0), (3.0, 4.0)], &[(1.0, 2.0), (3.0, 4.005)], &e).0);
+            assert_eq!(TupleVectorComparisonResult::ExactlyEqual, evaluate_tuple_vector_eq_approx(&[(1, 2.0_f32, 3u8, 4.0)], &[(1, 2.0_f32, 3u8, 4.0)], &e).0);
+            assert_eq!(
+                TupleVectorComparisonResult::DifferentLengths { expected_length : 1, actual_length : 0 },
+                evaluate_tuple_vector_eq_approx(&[(1.0, 2.0, 3.0)], &[] as &[(f64, f64, f64); 0], &e).0
+            );
+            assert_eq!(
+                TupleVectorComparisonResult::UnequalComponents { index : 1, component : 2, expected_value : 0.6, actual_value : 0.7 },
+                evaluate_tuple_vector_eq_approx(&[(0.1, 0.2, 0.3), (0.4, 0.5, 0.6)], &[(0.1, 0.2, 0.3), (0.4, 0.5, 0.7)], &e).0
+            );
+        }
 
-pub fn evaluate_vector_eq_approx<T_expected, T_actual, T_expectedElement, T_actualElement>(
-    expected : &T_expected,
-    actual : &T_actual,
-    evaluator : &dyn traits::ApproximateEqualityEvaluator,
-) -> (
-    VectorComparisonResult, // comparison_result
-    Option<f64>,            // margin_factor
-    Option<f64>,            // multiplier_factor
-)
-where
-    T_expected : std_convert::AsRef<[T_expectedElement]>,
-    T_actual : std_convert::AsRef<[T_actualElement]>,
-    T_expectedElement : traits::TestableAsF64 + std_fmt::Debug,
-    T_actualElement : traits::TestableAsF64 + std_fmt::Debug,
-{
-    /*
-    let expected_param = expected;
-    let actual_param = actual;
-     */
+        #[test]
+        fn TEST_assert_tuple3_eq_approx_1() {
+            let rgb = vec![(0.1, 0.2, 0.3), (1.0, 0.5, 0.0)];
 
-    let expected = expected.as_ref();
-    let actual = actual.as_ref();
+            assert_tuple3_eq_approx!(rgb, [(0.1, 0.2, 0.3), (1.0, 0.5, 0.0)]);
+            assert_tuple3_eq_approx!(rgb, [(0.1, 0.2, 0.3), (1.0, 0.5, 0.001)], margin(0.01));
 
-    let expected_length = expected.len();
-    let actual_length = actual.len();
+            // the expected and actual component types may differ
+            assert_tuple3_eq_approx!([(0.5f32, 0.25f32, 1.0f32)], vec![(0.5, 0.25, 1)]);
+        }
 
-    if expected_length != actual_length {
-        (
-            VectorComparisonResult::DifferentLengths {
-                expected_length,
-                actual_length,
-            },
-            None,
-            None,
-        )
-    } else {
-        let mut any_inexact = false;
-        let mut margin_factor = None;
-        let mut multiplier_factor = None;
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for tuples: at index 1 component 2 expected=0.0, actual=0.25, margin_factor=0.01")]
+        fn TEST_assert_tuple3_eq_approx_SHOULD_FAIL_IN_THIRD_COMPONENT() {
+            assert_tuple3_eq_approx!([(0.1, 0.2, 0.3), (1.0, 0.5, 0.0)], [(0.1, 0.2, 0.3), (1.0, 0.5, 0.25)], margin(0.01));
+        }
+    }
+
+
+    mod TEST_VECTOR_OPTIONAL {
+        #![allow(non_snake_case)]
 
-        for ix in 0..expected_length {
-            let expected_element = &expected[ix];
-            let actual_element = &actual[ix];
+        use super::*;
 
-            let (scalar_comparison_result, scalar_margin_factor, scalar_multiplier_factor) =
-                evaluate_scalar_eq_approx(expected_element, actual_element, evaluator);
+        use test_helpers::{
+            evaluate_optional_vector_eq_approx,
+            OptionalVectorComparisonResult,
+        };
 
-            match scalar_comparison_result {
-                ComparisonResult::ExactlyEqual => (),
-                ComparisonResult::ApproximatelyEqual => {
-                    if !any_inexact {
-                        any_inexact = true;
-                        margin_factor = scalar_margin_factor;
-                        multiplier_factor = scalar_multiplier_factor;
-                    }
-                },
-                ComparisonResult::Unequal => {
-                    let (expected_value_of_first_unequal_element, actual_value_of_first_unequal_element) = {
-                        let expected : &dyn traits::TestableAsF64 = &expected[ix];
-                        let actual : &dyn traits::TestableAsF64 = &actual[ix];
 
-                        let expected = expected.testable_as_f64();
-                        let actual = actual.testable_as_f64();
+        #[test]
+        fn TEST_evaluate_optional_vector_eq_approx_1() {
+            let e = margin(0.01);
+
+            assert_eq!(OptionalVectorComparisonResult::ExactlyEqual, evaluate_optional_vector_eq_approx(&[] as &[Option<f64>; 0], &[] as &[Option<f64>; 0], &e).0);
+            assert_eq!(OptionalVectorComparisonResult::ExactlyEqual, evaluate_optional_vector_eq_approx(&[None::<f64>, None], &[None::<f64>, None], &e).0);
+            assert_eq!(OptionalVectorComparisonResult::ExactlyEqual, evaluate_optional_vector_eq_approx(&[Some(1.0), None, Some(3.0)], &vec![Some(1), None, Some(3)], &e).0);
+            assert_eq!(OptionalVectorComparisonResult::ApproximatelyEqual, evaluate_optional_vector_eq_approx(&[Some(1.0), None, Some(3.0)], &[Some(1.005), None, Some(3.0)], &e).0);
+        }
 
-                        (expected, actual)
-                    };
+        #[test]
+        fn TEST_evaluate_optional_vector_eq_approx_FAILURES() {
+            let e = margin(0.01);
 
-                    return (
-                        VectorComparisonResult::UnequalElements {
-                            index_of_first_unequal_element : ix,
-                            expected_value_of_first_unequal_element,
-                            actual_value_of_first_unequal_element,
-                        },
-                        scalar_margin_factor,
-                        scalar_multiplier_factor,
-                    );
+            assert_eq!(
+                OptionalVectorComparisonResult::DifferentLengths { expected_length : 2, actual_length : 3 },
+                evaluate_optional_vector_eq_approx(&[Some(1.0), None], &[Some(1.0), None, None], &e).0
+            );
+            assert_eq!(
+                OptionalVectorComparisonResult::MismatchedPresence { index : 1, expected_value : None, actual_value : Some(2.0) },
+                evaluate_optional_vector_eq_approx(&[Some(1.0), None], &[Some(1.0), Some(2.0)], &e).0
+            );
+            assert_eq!(
+                OptionalVectorComparisonResult::MismatchedPresence { index : 0, expected_value : Some(1.0), actual_value : None },
+                evaluate_optional_vector_eq_approx(&[Some(1.0), None], &[None::<f64>, None], &e).0
+            );
+            assert_eq!(
+                OptionalVectorComparisonResult::UnequalElements {
+                    index_of_first_unequal_element :          2,
+                    expected_value_of_first_unequal_element : 3.0,
+                    actual_value_of_first_unequal_element :   3.5,
                 },
-            };
+                evaluate_optional_vector_eq_approx(&[Some(1.0), None, Some(3.0)], &[Some(1.0), None, Some(3.5)], &e).0
+            );
         }
 
-        (
-            if any_inexact {
-                VectorComparisonResult::ApproximatelyEqual
-            } else {
-                VectorComparisonResult::ExactlyEqual
-            },
-            margin_factor,
-            multiplier_factor,
-        )
-    }
-}
+        #[test]
+        fn TEST_assert_optional_vector_eq_approx_1() {
+            assert_optional_vector_eq_approx!([Some(1.0), None, Some(3.0)], [Some(1.0), None, Some(3.0)]);
+            assert_optional_vector_eq_approx!(vec![None, Some(2.0)], [None, Some(2.001)], margin(0.01));
+        }
 
-/// Creates an [`ApproximateEqualityEvaluator`] that operates by applying
-/// the given `factor` as a margin to determine approximate equality.
-pub fn margin(factor : f64) -> impl traits::ApproximateEqualityEvaluator {
-    internal::MarginEvaluator {
-        factor,
-    }
-}
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for optional vectors: at index 1 expected is None: expected=None, actual=Some(2.0)")]
+        fn TEST_assert_optional_vector_eq_approx_SHOULD_FAIL_WHEN_EXPECTED_IS_NONE() {
+            assert_optional_vector_eq_approx!([Some(1.0), None], [Some(1.0), Some(2.0)]);
+        }
 
-/// Creates an [`ApproximateEqualityEvaluator`] that operates by applying
-/// the given `factor` as a multiplier to determine approximate equality.
-pub fn multiplier(factor : f64) -> impl traits::ApproximateEqualityEvaluator {
-    internal::MultiplierEvaluator {
-        factor,
-    }
-}
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for optional vectors: at index 0 actual is None: expected=Some(1.0), actual=None")]
+        fn TEST_assert_optional_vector_eq_approx_SHOULD_FAIL_WHEN_ACTUAL_IS_NONE() {
+            assert_optional_vector_eq_approx!([Some(1.0), None], [None::<f64>, None]);
+        }
 
-/// Creates an [`ApproximateEqualityEvaluator`] that operates by applying
-/// the given `multiplier_factor` as a multiplier to determine approximate
-/// equality in all cases except when or both comparands is zero, in which
-/// case it applies the `zero_margin_factor` as a margin to determine
-/// approximate equality.
-pub fn zero_margin_or_multiplier(
-    multiplier_factor : f64,
-    zero_margin_factor : f64,
-) -> impl traits::ApproximateEqualityEvaluator {
-    internal::ZeroMarginOrMultiplierEvaluator {
-        multiplier_factor,
-        zero_margin_factor,
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for optional vectors: at index 2 expected=3.0, actual=3.5, margin_factor=0.01")]
+        fn TEST_assert_optional_vector_eq_approx_SHOULD_FAIL_WHEN_UNEQUAL() {
+            assert_optional_vector_eq_approx!([Some(1.0), None, Some(3.0)], [Some(1.0), None, Some(3.5)], margin(0.01));
+        }
     }
-}
-
-
-// /////////////////////////////////////////////////////////
-// macros
 
-#[macro_export]
-macro_rules! assert_scalar_eq_approx {
-    ($expected:expr, $actual:expr, $evaluator:expr) => {
-        let expected_param = &$expected;
-        let actual_param = &$actual;
 
-        let (expected, actual) = {
-            let expected : &dyn $crate::traits::TestableAsF64 = expected_param;
-            let actual : &dyn $crate::traits::TestableAsF64 = actual_param;
+    mod TEST_RESULT {
+        #![allow(non_snake_case)]
 
-            let expected = expected.testable_as_f64();
-            let actual = actual.testable_as_f64();
+        use super::*;
 
-            (expected, actual)
+        use test_helpers::{
+            evaluate_result_eq_approx,
+            BothErrDisposition,
+            ResultComparisonResult,
         };
-        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
 
-        // scope to protect against multiple `use`s of crate type(s)
-        {
-            use $crate::ComparisonResult as CR;
 
-            let (comparison_result, margin_factor, multiplier_factor) = evaluator.evaluate(expected, actual);
+        #[test]
+        fn TEST_evaluate_result_eq_approx_OK_OK() {
+            let e = margin(0.01);
 
-            match comparison_result {
-                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
-                CR::Unequal => {
-                    match margin_factor {
-                        Some(margin_factor) => {
-                            match multiplier_factor {
-                                Some(multiplier_factor) => {
-                                    assert!(
-                                        false,
-                                        "assertion failed: failed to verify approximate equality: expected={expected_param:?}, actual={actual_param:?}, margin_factor={margin_factor}, multiplier_factor={multiplier_factor}",
-                                    );
-                                },
-                                None => {
-                                    assert!(
-                                        false,
-                                        "assertion failed: failed to verify approximate equality: expected={expected_param:?}, actual={actual_param:?}, margin_factor={margin_factor}",
-                                    );
-                                },
-                            };
-                        },
-                        None => {
-                            match multiplier_factor {
-                                Some(multiplier_factor) => {
-                                    assert!(
-                                        false,
-                                        "assertion failed: failed to verify approximate equality: expected={expected_param:?}, actual={actual_param:?}, multiplier_factor={multiplier_factor}",
-                                    );
-                                },
-                                None => {
-                                    panic!("VIOLATION: This should not occur, and may only result from an improperly written implementor of `ApproximateEqualityEvaluator`");
-                                }
-                            };
-                        },
-                    };
-                },
-            };
+            assert_eq!(ResultComparisonResult::Ok(ComparisonResult::ExactlyEqual), evaluate_result_eq_approx(&Ok::<f64, ()>(1.0), &Ok::<i32, String>(1), &e, BothErrDisposition::default()).0);
+            assert_eq!(ResultComparisonResult::Ok(ComparisonResult::ApproximatelyEqual), evaluate_result_eq_approx(&Ok::<f64, ()>(1.0), &Ok::<f64, ()>(1.005), &e, BothErrDisposition::default()).0);
+            assert_eq!(ResultComparisonResult::Ok(ComparisonResult::Unequal), evaluate_result_eq_approx(&Ok::<f64, ()>(1.0), &Ok::<f64, ()>(1.5), &e, BothErrDisposition::default()).0);
         }
-    };
-    ($expected:expr, $actual:expr) => {
-        let evaluator = $crate::zero_margin_or_multiplier($crate::constants::DEFAULT_MULTIPLIER, $crate::constants::DEFAULT_MARGIN);
 
-        assert_scalar_eq_approx!($expected, $actual, evaluator);
-    };
-}
+        #[test]
+        fn TEST_evaluate_result_eq_approx_OK_ERR() {
+            let e = margin(0.01);
 
-#[macro_export]
-macro_rules! assert_scalar_ne_approx {
-    ($expected:expr, $actual:expr, $evaluator:expr) => {
-        let expected_param = &$expected;
-        let actual_param = &$actual;
+            assert_eq!(
+                ResultComparisonResult::MismatchedErr { expected : Ok(1.0), actual : Err("\"overflow\"".into()) },
+                evaluate_result_eq_approx(&Ok::<f64, &str>(1.0), &Err::<f64, &str>("overflow"), &e, BothErrDisposition::Equal).0
+            );
+            assert_eq!(
+                ResultComparisonResult::MismatchedErr { expected : Err("()".into()), actual : Ok(2.0) },
+                evaluate_result_eq_approx(&Err::<f64, ()>(()), &Ok::<f64, ()>(2.0), &e, BothErrDisposition::Equal).0
+            );
+        }
 
-        let (expected, actual) = {
-            let expected : &dyn $crate::traits::TestableAsF64 = expected_param;
-            let actual : &dyn $crate::traits::TestableAsF64 = actual_param;
+        #[test]
+        fn TEST_evaluate_result_eq_approx_ERR_ERR() {
+            let e = margin(0.01);
+            let expected = Err::<f64, &str>("overflow");
+            let actual = Err::<f64, String>("underflow".into());
+
+            assert_eq!(ResultComparisonResult::BothErr, evaluate_result_eq_approx(&expected, &Err::<f64, String>("overflow".into()), &e, BothErrDisposition::CompareDebug).0);
+            assert_eq!(
+                ResultComparisonResult::UnequalErrs { expected_error : "\"overflow\"".into(), actual_error : "\"underflow\"".into() },
+                evaluate_result_eq_approx(&expected, &actual, &e, BothErrDisposition::CompareDebug).0
+            );
+            assert_eq!(ResultComparisonResult::BothErr, evaluate_result_eq_approx(&expected, &actual, &e, BothErrDisposition::Equal).0);
+            assert!(matches!(evaluate_result_eq_approx(&expected, &expected, &e, BothErrDisposition::Unequal).0, ResultComparisonResult::UnequalErrs { .. }));
+        }
 
-            let expected = expected.testable_as_f64();
-            let actual = actual.testable_as_f64();
+        #[test]
+        fn TEST_assert_result_eq_approx_1() {
+            assert_result_eq_approx!(Ok::<f64, ()>(0.5), Ok::<f64, ()>(0.5));
+            assert_result_eq_approx!("2.5".parse::<f64>(), Ok::<f64, ()>(2.501), margin(0.01));
+            assert_result_eq_approx!("x".parse::<f64>(), "y".parse::<f64>());
+            assert_result_eq_approx!(Err::<f64, &str>("a"), Err::<f64, &str>("b"), margin(0.01), BothErrDisposition::Equal);
+        }
 
-            (expected, actual)
-        };
-        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for results: expected=Ok(1.0), actual=Ok(1.5), margin_factor=0.01")]
+        fn TEST_assert_result_eq_approx_OK_OK_SHOULD_FAIL() {
+            assert_result_eq_approx!(Ok::<f64, ()>(1.0), Ok::<f64, ()>(1.5), margin(0.01));
+        }
 
-        // scope to protect against multiple `use`s of crate type(s)
-        {
-            use $crate::ComparisonResult as CR;
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for results: expected=Ok(1000000.0), actual=Ok(1000003.2), multiplier_factor=0.000001: error 3.200 ppm exceeds 1.000 ppm")]
+        fn TEST_assert_result_eq_approx_OK_OK_WITH_DESCRIPTION_SHOULD_FAIL() {
+            assert_result_eq_approx!(Ok::<f64, ()>(1e6), Ok::<f64, ()>(1e6 + 3.2), ppm(1.0));
+        }
 
-            let (comparison_result, margin_factor, multiplier_factor) = evaluator.evaluate(expected, actual);
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for results: actual is Err: expected=Ok(1.0), actual=Err(\"overflow\")")]
+        fn TEST_assert_result_eq_approx_OK_ERR_SHOULD_FAIL() {
+            assert_result_eq_approx!(Ok::<f64, &str>(1.0), Err::<f64, &str>("overflow"), margin(0.01));
+        }
 
-            match comparison_result {
-                CR::Unequal => (),
-                CR::ExactlyEqual | CR::ApproximatelyEqual => {
-                    match margin_factor {
-                        Some(margin_factor) => {
-                            match multiplier_factor {
-                                Some(multiplier_factor) => {
-                                    assert!(
-                                        false,
-                                        "assertion failed: failed to verify approximate inequality: expected={expected_param:?}, actual={actual_param:?}, margin_factor={margin_factor}, multiplier_factor={multiplier_factor}",
-                                    );
-                                },
-                                None => {
-                                    assert!(
-                                        false,
-                                        "assertion failed: failed to verify approximate inequality: expected={expected_param:?}, actual={actual_param:?}, margin_factor={margin_factor}",
-                                    );
-                                },
-                            };
-                        },
-                        None => {
-                            match multiplier_factor {
-                                Some(multiplier_factor) => {
-                                    assert!(
-                                        false,
-                                        "assertion failed: failed to verify approximate inequality: expected={expected_param:?}, actual={actual_param:?}, multiplier_factor={multiplier_factor}",
-                                    );
-                                },
-                                None => {
-                                    panic!("VIOLATION: This should not occur, and may only result from an improperly written implementor of `ApproximateEqualityEvaluator`");
-                                }
-                            };
-                        }
-                    };
-                },
-            };
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for results: both are Err: expected=Err(\"overflow\"), actual=Err(\"underflow\")")]
+        fn TEST_assert_result_eq_approx_ERR_ERR_SHOULD_FAIL() {
+            assert_result_eq_approx!(Err::<f64, &str>("overflow"), Err::<f64, &str>("underflow"), margin(0.01));
         }
-    };
-    ($expected:expr, $actual:expr) => {
-        let evaluator = $crate::zero_margin_or_multiplier($crate::constants::DEFAULT_MULTIPLIER, $crate::constants::DEFAULT_MARGIN);
+    }
 
-        assert_scalar_ne_approx!($expected, $actual, evaluator);
-    };
-}
 
-#[macro_export]
-macro_rules! assert_vector_eq_approx {
-    ($expected:expr, $actual:expr, $evaluator:expr) => {
-        /*
-        let expected_param = &$expected;
-        let actual_param = &$actual;
-         */
-        let expected = &$expected;
-        let actual = &$actual;
-        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+    mod TEST_VECTOR_PREFIX {
+        #![allow(non_snake_case)]
 
-        // scope to protect against multiple `use`s of crate type(s)
-        {
-            use $crate::VectorComparisonResult as CR;
+        use super::*;
 
-            let (comparison_result, margin_factor, multiplier_factor) = $crate::evaluate_vector_eq_approx(&expected, &actual, evaluator);
+        use test_helpers::{
+            evaluate_vector_eq_approx_prefix,
+            VectorComparisonResult,
+        };
 
-            match comparison_result {
-                CR::ExactlyEqual | CR::ApproximatelyEqual => (),
-                CR::DifferentLengths {
-                    expected_length,
-                    actual_length,
-                } => {
-                    assert!(
-                        false,
-                        "assertion failed: failed to verify approximate equality for vectors: expected-length {expected_length} differs from actual-length {actual_length}",
-                    );
-                },
-                CR::UnequalElements {
-                    index_of_first_unequal_element,
-                    expected_value_of_first_unequal_element,
-                    actual_value_of_first_unequal_element,
-                } => {
-                    match margin_factor {
-                        Some(margin_factor) => {
-                            match multiplier_factor {
-                                Some(multiplier_factor) => {
-                                    assert!(
-                                        false,
-                                        "assertion failed: failed to verify approximate equality for vectors: at index {index_of_first_unequal_element} expected={expected_value_of_first_unequal_element:?}, actual={actual_value_of_first_unequal_element:?}, margin_factor={margin_factor}, multiplier_factor={multiplier_factor}",
-                                    );
-                                },
-                                None => {
-                                    assert!(
-                                        false,
-                                        "assertion failed: failed to verify approximate equality for vectors: at index {index_of_first_unequal_element} expected={expected_value_of_first_unequal_element:?}, actual={actual_value_of_first_unequal_element:?}, margin_factor={margin_factor}",
-                                    );
-                                },
-                            };
-                        },
-                        None => {
-                            match multiplier_factor {
-                                Some(multiplier_factor) => {
-                                    assert!(
-                                        false,
-                                        "assertion failed: failed to verify approximate equality for vectors: at index {index_of_first_unequal_element} expected={expected_value_of_first_unequal_element:?}, actual={actual_value_of_first_unequal_element:?}, multiplier_factor={multiplier_factor}",
-                                    );
-                                },
-                                None => {
-                                    panic!("VIOLATION: This should not occur, and may only result from an improperly written implementor of `ApproximateEqualityEvaluator`");
-                                }
-                            };
-                        },
-                    };
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_prefix_EQUAL_PREFIX_DIFFERENT_LENGTHS() {
+            let e = margin(0.01);
+
+            assert_eq!(VectorComparisonResult::ExactlyEqual, evaluate_vector_eq_approx_prefix(&[1.0, 2.0, 3.0], &[1.0, 2.0], &e).0);
+            assert_eq!(VectorComparisonResult::ExactlyEqual, evaluate_vector_eq_approx_prefix(&[1.0], &vec![1.0, 99.0, -99.0], &e).0);
+            assert_eq!(VectorComparisonResult::ApproximatelyEqual, evaluate_vector_eq_approx_prefix(&[1.0, 2.0], &[1.0, 2.005, 3.0], &e).0);
+            assert_eq!(VectorComparisonResult::ExactlyEqual, evaluate_vector_eq_approx_prefix(&[] as &[f64; 0], &[1.0, 2.0], &e).0);
+        }
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_prefix_PREFIX_MISMATCH() {
+            let e = margin(0.01);
+
+            assert_eq!(
+                VectorComparisonResult::UnequalElements {
+                    index_of_first_unequal_element :          1,
+                    expected_value_of_first_unequal_element : 2.0,
+                    actual_value_of_first_unequal_element :   2.5,
                 },
-            };
+                evaluate_vector_eq_approx_prefix(&[1.0, 2.0, 3.0], &[1.0, 2.5], &e).0
+            );
+            assert_eq!(Some(0.01), evaluate_vector_eq_approx_prefix(&[1.0, 2.0, 3.0], &[1.0, 2.5], &e).1);
         }
-    };
-    ($expected:expr, $actual:expr) => {
-        let evaluator = $crate::zero_margin_or_multiplier($crate::constants::DEFAULT_MULTIPLIER, $crate::constants::DEFAULT_MARGIN);
+    }
 
-        assert_vector_eq_approx!($expected, $actual, evaluator);
-    };
-}
 
-#[macro_export]
-macro_rules! assert_vector_ne_approx {
-    ($expected:expr, $actual:expr, $evaluator:expr) => {
-        /*
-        let expected_param = &$expected;
-        let actual_param = &$actual;
-         */
-        let expected = &$expected;
-        let actual = &$actual;
-        let evaluator : &dyn $crate::traits::ApproximateEqualityEvaluator = &$evaluator;
+    mod TEST_VECTOR_FIRST_K {
+        #![allow(non_snake_case)]
 
-        // scope to protect against multiple `use`s of crate type(s)
-        {
-            use $crate::VectorComparisonResult as CR;
+        use super::*;
 
-            let (comparison_result, margin_factor, multiplier_factor) = $crate::evaluate_vector_eq_approx(&expected, &actual, evaluator);
+        use test_helpers::{
+            evaluate_vector_eq_approx_first_k,
+            evaluate_vector_eq_approx_worst_first,
+            VectorComparisonResult,
+            VectorElementMismatch,
+        };
 
-            match comparison_result {
-                CR::DifferentLengths { ..} | CR::UnequalElements {..} => (),
-                CR::ExactlyEqual | CR::ApproximatelyEqual => {
-                    match margin_factor {
-                        Some(margin_factor) => {
-                            match multiplier_factor {
-                                Some(multiplier_factor) => {
-                                    assert!(
-                                        false,
-                                        "assertion failed: failed to verify approximate inequality for vectors; margin_factor={margin_factor},  multiplier_factor={multiplier_factor}",
-                                    );
-                                },
-                                None => {
-                                    assert!(
-                                        false,
-                                        "assertion failed: failed to verify approximate inequality for vectors; margin_factor={margin_factor}",
-                                    );
-                                },
-                            };
-                        },
-                        None => {
-                            match multiplier_factor {
-                                Some(multiplier_factor) => {
-                                    assert!(
-                                        false,
-                                        "assertion failed: failed to verify approximate inequality for vectors; multiplier_factor={multiplier_factor}",
-                                    );
-                                },
-                                None => {
-                                    assert!(
-                                        false,
-                                        "assertion failed: failed to verify approximate inequality for vectors",
-                                    );
-                                }
-                            };
-                        }
-                    };
-                },
-            };
+
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_first_k_MORE_THAN_K_MISMATCHES() {
+            let expected : Vec<f64> = (0..20).map(f64::from).collect();
+            let actual : Vec<f64> = expected.iter().map(|v| v + 0.5).collect();
+
+            let (comparison_result, mismatches, margin_factor, _) = evaluate_vector_eq_approx_first_k(&expected, &actual, &margin(0.01), 3);
+
+            assert!(matches!(comparison_result, VectorComparisonResult::UnequalElements { index_of_first_unequal_element : 0, .. }));
+            assert_eq!(
+                vec![
+                    VectorElementMismatch { index : 0, expected_value : 0.0, actual_value : 0.5 },
+                    VectorElementMismatch { index : 1, expected_value : 1.0, actual_value : 1.5 },
+                    VectorElementMismatch { index : 2, expected_value : 2.0, actual_value : 2.5 },
+                ],
+                mismatches
+            );
+            assert_eq!(Some(0.01), margin_factor);
         }
-    };
-    ($expected:expr, $actual:expr) => {
-        let evaluator =
-            $crate::zero_margin_or_multiplier($crate::constants::DEFAULT_MULTIPLIER, $crate::constants::DEFAULT_MARGIN);
 
-        assert_vector_ne_approx!($expected, $actual, evaluator);
-    };
-}
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_first_k_FEWER_THAN_K_MISMATCHES() {
+            let expected = [ 1.0, 2.0, 3.0, 4.0 ];
+            let actual = [ 1.0, 2.001, 3.5, 4.0 ];
 
+            let (comparison_result, mismatches, ..) = evaluate_vector_eq_approx_first_k(&expected, &actual, &margin(0.01), 10);
 
-#[cfg(test)]
-#[rustfmt::skip]
-mod tests {
-    #![allow(non_snake_case)]
+            assert!(matches!(comparison_result, VectorComparisonResult::UnequalElements { index_of_first_unequal_element : 2, .. }));
+            assert_eq!(1, mismatches.len());
 
-    use crate as test_helpers;
+            let (comparison_result, mismatches, ..) = evaluate_vector_eq_approx_first_k(&expected, &expected, &margin(0.01), 10);
 
-    use test_helpers::{
-        traits::ApproximateEqualityEvaluator,
-        ComparisonResult,
-        margin,
-        multiplier,
-        zero_margin_or_multiplier,
-    };
+            assert!(matches!(comparison_result, VectorComparisonResult::ExactlyEqual));
+            assert!(mismatches.is_empty());
 
-    use std::rc as std_rc;
+            let (comparison_result, mismatches, ..) = evaluate_vector_eq_approx_first_k(&expected, &[ 1.0 ], &margin(0.01), 10);
 
+            assert!(matches!(comparison_result, VectorComparisonResult::DifferentLengths { expected_length : 4, actual_length : 1 }));
+            assert!(mismatches.is_empty());
+        }
 
-    mod TEST_margin {
-        #![allow(non_snake_case)]
+        #[test]
+        fn TEST_evaluate_vector_eq_approx_worst_first_SORT_ORDER() {
+            let expected = [ 0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0 ];
+            let actual = [ 0.2, 1.0, 2.9, 3.05, 4.5, 5.7, 6.001 ];
 
-        use super::*;
+            let (comparison_result, mismatches, margin_factor, _) = evaluate_vector_eq_approx_worst_first(&expected, &actual, &margin(0.01), 3);
 
+            // the result is still that of the first-indexed mismatch ...
+            assert!(matches!(comparison_result, VectorComparisonResult::UnequalElements { index_of_first_unequal_element : 0, .. }));
+            assert_eq!(Some(0.01), margin_factor);
 
-        #[test]
-        fn TEST_margin_TEST_1() {
-            let margin_factor = 0.0;
-            let m = margin(margin_factor);
+            // ... whereas the mismatches are the worst, worst first
+            assert_eq!(vec![ 2, 5, 4 ], mismatches.iter().map(|m| m.index).collect::<Vec<_>>());
+            assert_eq!(VectorElementMismatch { index : 2, expected_value : 2.0, actual_value : 2.9 }, mismatches[0]);
 
-            assert_eq!(ComparisonResult::ExactlyEqual, m.evaluate(0.0, 0.0).0);
+            let (_, mismatches, ..) = evaluate_vector_eq_approx_worst_first(&expected, &actual, &margin(0.01), 10);
 
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.000001, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.00001, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.0001, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.001, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.01, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.1, 0.0).0);
+            assert_eq!(vec![ 2, 5, 4, 0, 3 ], mismatches.iter().map(|m| m.index).collect::<Vec<_>>());
         }
 
         #[test]
-        fn TEST_margin_TEST_2() {
-            let margin_factor = 0.001;
-            let m = margin(margin_factor);
+        fn TEST_evaluate_vector_eq_approx_worst_first_TIES_AND_NAN() {
+            let expected = [ 1.0, 2.0, 3.0, 4.0 ];
+            let actual = [ 1.5, f64::NAN, 3.5, 3.0 ];
 
-            assert_eq!(ComparisonResult::ExactlyEqual, m.evaluate(0.0, 0.0).0);
+            let (_, mismatches, ..) = evaluate_vector_eq_approx_worst_first(&expected, &actual, &margin(0.01), 0);
 
-            assert_eq!(ComparisonResult::ApproximatelyEqual, m.evaluate(0.000001, 0.0).0);
-            assert_eq!(ComparisonResult::ApproximatelyEqual, m.evaluate(0.00001, 0.0).0);
-            assert_eq!(ComparisonResult::ApproximatelyEqual, m.evaluate(0.0001, 0.0).0);
-            assert_eq!(ComparisonResult::ApproximatelyEqual, m.evaluate(0.001, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.0010001, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.001001, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.00101, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.0011, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.01, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.1, 0.0).0);
+            assert_eq!(vec![ 1 ], mismatches.iter().map(|m| m.index).collect::<Vec<_>>());
+
+            let (_, mismatches, ..) = evaluate_vector_eq_approx_worst_first(&expected, &actual, &margin(0.01), 4);
+
+            assert_eq!(vec![ 1, 3, 0, 2 ], mismatches.iter().map(|m| m.index).collect::<Vec<_>>());
+
+            let (comparison_result, mismatches, ..) = evaluate_vector_eq_approx_worst_first(&expected, &expected, &margin(0.01), 4);
+
+            assert!(matches!(comparison_result, VectorComparisonResult::ExactlyEqual));
+            assert!(mismatches.is_empty());
+        }
+
+        #[test]
+        fn TEST_assert_vector_eq_approx_first_k_1() {
+            assert_vector_eq_approx_first_k!([ 1.0, 2.0 ], [ 1.0, 2.0000000001 ], 5);
+            assert_vector_eq_approx_first_k!([ 1.0, 2.0 ], [ 1.0, 2.001 ], 5, margin(0.01));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for vectors: first 2 unequal element(s): [1] expected=1.0, actual=1.5; [3] expected=3.0, actual=3.5, margin_factor=0.01")]
+        fn TEST_assert_vector_eq_approx_first_k_SHOULD_FAIL() {
+            assert_vector_eq_approx_first_k!([ 0.0, 1.0, 2.0, 3.0, 4.0 ], [ 0.0, 1.5, 2.0, 3.5, 4.5 ], 2, margin(0.01));
         }
     }
 
 
-    mod TEST_multiplier {
+    #[cfg(feature = "num-complex")]
+    mod TEST_ENERGY_ASSERTS {
         #![allow(non_snake_case)]
 
         use super::*;
 
+        use test_helpers::evaluate_energy_eq_approx;
+
+        use num_complex::Complex;
+
 
         #[test]
-        fn TEST_multiplier_TEST_1() {
-            let multiplier_factor = 0.0;
-            let m = multiplier(multiplier_factor);
+        fn TEST_evaluate_energy_eq_approx_1() {
+            let expected = [ Complex::new(3.0, 4.0), Complex::new(1.0, 0.0) ];
 
-            assert_eq!(ComparisonResult::ExactlyEqual, m.evaluate(0.0, 0.0).0);
+            // bins differ, but energy (25 + 1 = 26) is preserved
+            let (comparison_result, expected_energy, actual_energy, _, _) =
+                evaluate_energy_eq_approx(&expected, &vec![ Complex::new(5.0, 0.0), Complex::new(0.0, -1.0) ], &margin(1e-9));
 
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.000001, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.00001, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.0001, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.001, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.01, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.1, 0.0).0);
+            assert_eq!(ComparisonResult::ExactlyEqual, comparison_result);
+            assert_eq!(26.0, expected_energy);
+            assert_eq!(26.0, actual_energy);
+
+            assert_eq!(ComparisonResult::ApproximatelyEqual, evaluate_energy_eq_approx(&expected, &[ Complex::new(3.0f32, 4.0f32), Complex::new(1.0f32, 0.1f32) ], &multiplier(0.01)).0);
+            assert_eq!(ComparisonResult::Unequal, evaluate_energy_eq_approx(&expected, &[ Complex::new(3.0, 4.0) ], &multiplier(0.01)).0);
         }
 
         #[test]
-        fn TEST_multiplier_TEST_2() {
-            let multiplier_factor = 0.001;
-            let m = multiplier(multiplier_factor);
-
-            assert_eq!(ComparisonResult::ExactlyEqual, m.evaluate(0.0, 0.0).0);
+        fn TEST_assert_energy_eq_approx_ROUND_TRIP() {
+            // a naive DFT, with 1/N scaling of the energy
+            let signal = [ 1.0, -0.5, 2.0, 0.25, -1.5, 0.0, 0.75, -2.0 ];
+            let n = signal.len() as f64;
+            let spectrum : Vec<Complex<f64>> = (0..signal.len())
+                .map(|k| {
+                    signal.iter().enumerate().map(|(t, &x)| Complex::from_polar(x, -2.0 * std::f64::consts::PI * (k * t) as f64 / n)).sum::<Complex<f64>>() / n.sqrt()
+                })
+                .collect();
+            let signal : Vec<Complex<f64>> = signal.iter().map(|&x| Complex::new(x, 0.0)).collect();
+
+            assert_energy_eq_approx!(signal, spectrum, multiplier(1e-12));
+            assert_energy_eq_approx!(signal, spectrum);
+        }
 
-            assert_eq!(ComparisonResult::ExactlyEqual, m.evaluate(1.0, 1.0).0);
-            assert_eq!(ComparisonResult::ApproximatelyEqual, m.evaluate(1.000001, 1.0).0);
-            assert_eq!(ComparisonResult::ApproximatelyEqual, m.evaluate(1.00001, 1.0).0);
-            assert_eq!(ComparisonResult::ApproximatelyEqual, m.evaluate(1.0001, 1.0).0);
-            assert_eq!(ComparisonResult::ApproximatelyEqual, m.evaluate(1.001, 1.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.0010001, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.001001, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.00101, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.0011, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.01, 0.0).0);
-            assert_eq!(ComparisonResult::Unequal, m.evaluate(0.1, 0.0).0);
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality of energy for spectra: expected-energy=26.0, actual-energy=25.0, margin_factor=0.1")]
+        fn TEST_assert_energy_eq_approx_SHOULD_FAIL() {
+            assert_energy_eq_approx!([ Complex::new(3.0, 4.0), Complex::new(1.0, 0.0) ], [ Complex::new(0.0, 5.0) ], margin(0.1));
         }
     }
 
 
-    mod TEST_SCALAR_ASSERTS {
+    #[cfg(feature = "num-complex")]
+    mod TEST_COMPLEX_POLAR_ASSERTS {
         #![allow(non_snake_case)]
 
         use super::*;
 
+        use test_helpers::{
+            evaluate_complex_polar_eq_approx,
+            ComplexPolarComparisonResult,
+        };
 
-        struct CustomEvaluator{}
+        use num_complex::Complex;
 
-        impl ApproximateEqualityEvaluator for CustomEvaluator {
-            fn evaluate(
-                &self,
-                expected : f64,
-                actual : f64,
-            ) -> (
-                ComparisonResult, // comparison_result
-                Option<f64>,      // margin_factor
-                Option<f64>,      // multiplier_factor
-            )
-            {
-                (
-                    if expected == actual {
-                        ComparisonResult::ExactlyEqual
-                    } else {
-                        ComparisonResult::Unequal
-                    },
-                    Some(0.0),
-                    Some(0.0),
-                )
-            }
+        use std::f64::consts::PI;
+
+
+        #[test]
+        fn TEST_evaluate_complex_polar_eq_approx_1() {
+            let m = multiplier(0.01);
+            let p = margin(0.05);
+
+            assert_eq!(ComplexPolarComparisonResult::ExactlyEqual, evaluate_complex_polar_eq_approx(&Complex::new(1.0, 1.0), &Complex::new(1.0, 1.0), &m, &p).0);
+            assert_eq!(ComplexPolarComparisonResult::ApproximatelyEqual, evaluate_complex_polar_eq_approx(&Complex::new(1.0, 0.0), &Complex::from_polar(1.005, 0.02), &m, &p).0);
+            assert_eq!(ComplexPolarComparisonResult::ExactlyEqual, evaluate_complex_polar_eq_approx(&Complex::new(0.0, 0.0), &Complex::new(-0.0, 0.0), &m, &p).0);
+            assert!(matches!(
+                evaluate_complex_polar_eq_approx(&Complex::new(1.0, 0.0), &Complex::new(1.1, 0.0), &m, &p).0,
+                ComplexPolarComparisonResult::UnequalMagnitudes { .. }
+            ));
+            assert!(matches!(
+                evaluate_complex_polar_eq_approx(&Complex::new(1.0, 0.0), &Complex::new(0.0, 1.0), &m, &p).0,
+                ComplexPolarComparisonResult::UnequalPhases { .. }
+            ));
         }
 
+        #[test]
+        fn TEST_evaluate_complex_polar_eq_approx_PHASE_WRAPS() {
+            let expected = Complex::from_polar(2.0, PI - 0.01);
+            let actual = Complex::from_polar(2.0, -PI + 0.01);
+
+            assert_eq!(ComplexPolarComparisonResult::ApproximatelyEqual, evaluate_complex_polar_eq_approx(&expected, &actual, &margin(0.001), &margin(0.05)).0);
+
+            match evaluate_complex_polar_eq_approx(&expected, &actual, &margin(0.001), &margin(0.01)).0 {
+                ComplexPolarComparisonResult::UnequalPhases { phase_difference, .. } => {
+                    assert_scalar_eq_approx!(0.02, phase_difference, margin(1e-9));
+                },
+                r => panic!("unexpected result {r:?}"),
+            };
+        }
 
         #[test]
-        fn TEST_assert_scalar_eq_approx_2_PARAMETER_FOR_EXACTLY_EQUAL_VALUES() {
+        fn TEST_assert_complex_polar_eq_approx_1() {
+            assert_complex_polar_eq_approx!(Complex::new(3.0, 4.0), Complex::from_polar(5.02, 0.9273), multiplier(0.01), margin(0.001));
+            assert_complex_polar_eq_approx!(Complex::new(3.0f32, 4.0f32), Complex::new(3.0, 4.0), margin(0.0), margin(0.0));
+        }
 
-            assert_scalar_eq_approx!(-1.23456789e-10, -1.23456789e-10);
-            assert_scalar_eq_approx!(-0.123456789, -0.123456789);
-            assert_scalar_eq_approx!(-0.1, -0.1);
-            assert_scalar_eq_approx!(0.0, 0.0);
-            assert_scalar_eq_approx!(0.1, 0.1);
-            assert_scalar_eq_approx!(0.123456789, 0.123456789);
-            assert_scalar_eq_approx!(1.23456789e+10, 1.23456789e+10);
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for complex numbers: magnitude diverged: expected=Complex { re: 3.0, im: 4.0 }, actual=Complex { re: 6.0, im: 8.0 }, expected-magnitude=5.0, actual-magnitude=10.0, multiplier_factor=0.01")]
+        fn TEST_assert_complex_polar_eq_approx_MAGNITUDE_SHOULD_FAIL() {
+            assert_complex_polar_eq_approx!(Complex::new(3.0, 4.0), Complex::new(6.0, 8.0), multiplier(0.01), margin(0.001));
+        }
 
-            assert_scalar_eq_approx!(f64::INFINITY, f64::INFINITY);
-            assert_scalar_eq_approx!(f64::NEG_INFINITY, f64::NEG_INFINITY);
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for complex numbers: phase diverged: expected=Complex { re: 1.0, im: 0.0 }, actual=Complex { re: 0.0, im: 1.0 }, expected-phase=0.0, actual-phase=1.5707963267948966, phase-difference=1.5707963267948966, margin_factor=0.1")]
+        fn TEST_assert_complex_polar_eq_approx_PHASE_SHOULD_FAIL() {
+            assert_complex_polar_eq_approx!(Complex::new(1.0, 0.0), Complex::new(0.0, 1.0), multiplier(0.01), margin(0.1));
+        }
 
-            assert_scalar_eq_approx!(f64::MIN, f64::MIN);
-            assert_scalar_eq_approx!(f64::MIN_POSITIVE, f64::MIN_POSITIVE);
-            assert_scalar_eq_approx!(f64::MAX, f64::MAX);
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for complex numbers: phase diverged: expected=Complex { re: 1.0, im: 0.0 }, actual=Complex { re: 0.0, im: 1.0 }, expected-phase=0.0, actual-phase=1.5707963267948966, phase-difference=1.5707963267948966: |actual - expected| = 1.5707963267948966e0 exceeds atol + rtol * |actual| = 1e-1 (rtol=0, atol=0.1)")]
+        fn TEST_assert_complex_polar_eq_approx_PHASE_WITH_DESCRIPTION_SHOULD_FAIL() {
+            assert_complex_polar_eq_approx!(Complex::new(1.0, 0.0), Complex::new(0.0, 1.0), multiplier(0.01), numpy_close(0.0, 0.1));
+        }
+    }
 
-            #[cfg(feature = "nan-equality")]
-            {
-                assert_scalar_eq_approx!(f64::NAN, f64::NAN);
-            }
-            #[cfg(not(feature = "nan-equality"))]
-            {
-                assert_scalar_ne_approx!(f64::NAN, f64::NAN);
-            }
 
-            {
-                use std::f64::consts::*;
+    #[cfg(feature = "serde_json")]
+    mod TEST_JSON_ASSERTS {
+        #![allow(non_snake_case)]
 
-                assert_scalar_eq_approx!(PI, PI);
-                assert_scalar_eq_approx!(TAU, TAU);
-                assert_scalar_eq_approx!(PHI, PHI);
-                assert_scalar_eq_approx!(EGAMMA, EGAMMA);
-                assert_scalar_eq_approx!(FRAC_PI_2, FRAC_PI_2);
-                assert_scalar_eq_approx!(FRAC_PI_3, FRAC_PI_3);
-                assert_scalar_eq_approx!(FRAC_PI_4, FRAC_PI_4);
-                assert_scalar_eq_approx!(FRAC_PI_6, FRAC_PI_6);
-                assert_scalar_eq_approx!(FRAC_PI_8, FRAC_PI_8);
-                assert_scalar_eq_approx!(FRAC_1_PI, FRAC_1_PI);
-                assert_scalar_eq_approx!(FRAC_1_SQRT_PI, FRAC_1_SQRT_PI);
-                assert_scalar_eq_approx!(FRAC_1_SQRT_2PI, FRAC_1_SQRT_2PI);
-                assert_scalar_eq_approx!(FRAC_2_PI, FRAC_2_PI);
-                assert_scalar_eq_approx!(FRAC_2_SQRT_PI, FRAC_2_SQRT_PI);
-                assert_scalar_eq_approx!(SQRT_2, SQRT_2);
-                assert_scalar_eq_approx!(FRAC_1_SQRT_2, FRAC_1_SQRT_2);
-                assert_scalar_eq_approx!(SQRT_3, SQRT_3);
-                assert_scalar_eq_approx!(FRAC_1_SQRT_3, FRAC_1_SQRT_3);
-                assert_scalar_eq_approx!(E, E);
-                assert_scalar_eq_approx!(LOG2_10, LOG2_10);
-                assert_scalar_eq_approx!(LOG2_E, LOG2_E);
-                assert_scalar_eq_approx!(LOG10_2, LOG10_2);
-                assert_scalar_eq_approx!(LOG10_E, LOG10_E);
-                assert_scalar_eq_approx!(LN_2, LN_2);
-                assert_scalar_eq_approx!(LN_10, LN_10);
-            }
+        use super::*;
+
+        use test_helpers::{
+            evaluate_json_eq_approx,
+            JsonComparisonResult,
+        };
+
+        use serde_json::json;
+
+
+        #[test]
+        fn TEST_evaluate_json_eq_approx_FLAT() {
+            let e = multiplier(0.01);
+
+            assert_eq!(JsonComparisonResult::ExactlyEqual, evaluate_json_eq_approx(&json!([]), &json!([]), &e).0);
+            assert_eq!(JsonComparisonResult::ExactlyEqual, evaluate_json_eq_approx(&json!([1, 2.5, -3]), &json!([1.0, 2.5, -3.0]), &e).0);
+            assert_eq!(
+                (JsonComparisonResult::ApproximatelyEqual, None, Some(0.01)),
+                evaluate_json_eq_approx(&json!([1.0, 2.0]), &json!([1.0, 2.01]), &e)
+            );
+            assert_eq!(
+                JsonComparisonResult::DifferentLengths { path : "".into(), expected_length : 2, actual_length : 3 },
+                evaluate_json_eq_approx(&json!([1.0, 2.0]), &json!([1.0, 2.0, 3.0]), &e).0
+            );
+            assert_eq!(
+                (JsonComparisonResult::UnequalElements { path : "/1".into(), expected_value : 2.0, actual_value : 2.5 }, None, Some(0.01)),
+                evaluate_json_eq_approx(&json!([1.0, 2.0, 3.0]), &json!([1.0, 2.5, 3.5]), &e)
+            );
         }
 
         #[test]
-        #[cfg_attr(not(feature = "nan-equality"), should_panic(expected = "assertion failed: failed to verify approximate equality: expected=NaN, actual=NaN, margin_factor=0.0001, multiplier_factor=0.000001"))]
-        fn TEST_assert_scalar_eq_approx_2_PARAMETER_WITH_NAN() {
+        fn TEST_evaluate_json_eq_approx_NESTED() {
+            let e = multiplier(0.01);
 
-            assert_scalar_eq_approx!(f64::NAN, f64::NAN);
+            assert_eq!(
+                JsonComparisonResult::ApproximatelyEqual,
+                evaluate_json_eq_approx(&json!([[1.0, 2.0], [3.0, [4.0, 5.0]]]), &json!([[1.0, 2.0], [3.0, [4.0, 5.01]]]), &e).0
+            );
+            assert_eq!(
+                JsonComparisonResult::UnequalElements { path : "/1/1/0".into(), expected_value : 4.0, actual_value : 4.5 },
+                evaluate_json_eq_approx(&json!([[1.0, 2.0], [3.0, [4.0, 5.0]]]), &json!([[1.0, 2.0], [3.0, [4.5, 5.0]]]), &e).0
+            );
+            assert_eq!(
+                JsonComparisonResult::DifferentLengths { path : "/1".into(), expected_length : 2, actual_length : 1 },
+                evaluate_json_eq_approx(&json!([[1.0], [2.0, 3.0]]), &json!([[1.0], [2.0]]), &e).0
+            );
+            assert_eq!(
+                JsonComparisonResult::MismatchedStructure { path : "/0".into(), expected_value : json!([1.0]), actual_value : json!(1.0) },
+                evaluate_json_eq_approx(&json!([[1.0]]), &json!([1.0]), &e).0
+            );
         }
+
         #[test]
-        #[cfg_attr(feature = "nan-equality", should_panic(expected = "assertion failed: failed to verify approximate inequality: expected=NaN, actual=NaN, margin_factor=0.0001, multiplier_factor=0.000001"))]
-        fn TEST_assert_scalar_ne_approx_2_PARAMETER_WITH_NAN() {
+        fn TEST_evaluate_json_eq_approx_NON_NUMERIC() {
+            let e = multiplier(0.01);
 
-            assert_scalar_ne_approx!(f64::NAN, f64::NAN);
+            assert_eq!(
+                JsonComparisonResult::NonNumericElement { path : "/1".into(), expected_value : json!(2.0), actual_value : json!("2.0") },
+                evaluate_json_eq_approx(&json!([1.0, 2.0]), &json!([1.0, "2.0"]), &e).0
+            );
+            assert_eq!(
+                JsonComparisonResult::NonNumericElement { path : "/0/0".into(), expected_value : json!(null), actual_value : json!(null) },
+                evaluate_json_eq_approx(&json!([[null]]), &json!([[null]]), &e).0
+            );
+            assert_eq!(
+                JsonComparisonResult::NonNumericElement { path : "".into(), expected_value : json!({ "a" : 1 }), actual_value : json!([1]) },
+                evaluate_json_eq_approx(&json!({ "a" : 1 }), &json!([1]), &e).0
+            );
         }
 
-        /// Demonstrate that feature `"nan-equality"` only changes stock behaviour
         #[test]
-        fn TEST_assert_scalar_ne_approx_3_PARAMETER_WITH_CustomEvaluator() {
+        fn TEST_assert_json_eq_approx_1() {
+            let fixture : serde_json::Value = serde_json::from_str("[[0.1, 0.2], [0.30000000000000004]]").unwrap();
 
-            assert_scalar_ne_approx!(f64::NAN, f64::NAN, CustomEvaluator{});
+            assert_json_eq_approx!(fixture, json!([[0.1, 0.2], [0.3]]));
+            assert_json_eq_approx!(json!([1.0, [2.0, 3.0]]), json!([1.0, [2.0, 3.01]]), multiplier(0.01));
         }
 
         #[test]
-        fn TEST_assert_scalar_eq_approx_2_PARAMETER_FOR_APPROXIMATELY_EQUAL_VALUES() {
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for JSON arrays: at '/1/1' expected=3.0, actual=3.5, multiplier_factor=0.01")]
+        fn TEST_assert_json_eq_approx_NESTED_SHOULD_FAIL() {
+            assert_json_eq_approx!(json!([1.0, [2.0, 3.0]]), json!([1.0, [2.0, 3.5]]), multiplier(0.01));
+        }
 
-            assert_scalar_eq_approx!(0.12345678, 0.12345679);
-            assert_scalar_eq_approx!(0.12345678, 0.12345677);
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for JSON arrays: at '/2' non-numeric element: expected=3, actual=\"three\"")]
+        fn TEST_assert_json_eq_approx_NON_NUMERIC_SHOULD_FAIL() {
+            assert_json_eq_approx!(json!([1, 2, 3]), json!([1, 2, "three"]));
         }
+    }
+
+
+    #[cfg(feature = "ndarray")]
+    mod TEST_NDARRAY_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_ndarray_eq_approx,
+            NdarrayComparisonResult,
+        };
+
+        use ndarray::{
+            array,
+            Array1,
+            Array2,
+            Array3,
+        };
+
 
         #[test]
-        fn TEST_assert_scalar_eq_approx_3_PARAMETER_margin_FOR_APPROXIMATELY_EQUAL_VALUES() {
-            assert_scalar_eq_approx!(0.12345678, 0.12345679, margin(0.1));
-            assert_scalar_eq_approx!(0.12345678, 0.12345679, margin(0.01));
-            assert_scalar_eq_approx!(0.12345678, 0.12345679, margin(0.001));
-            assert_scalar_eq_approx!(0.12345678, 0.12345679, margin(0.0001));
-            assert_scalar_eq_approx!(0.12345678, 0.12345679, margin(0.00001));
-            assert_scalar_eq_approx!(0.12345678, Box::new(0.12345679), margin(0.000001));
-            assert_scalar_eq_approx!(std_rc::Rc::new(0.123456780), 0.12345679, margin(0.0000001));
-            assert_scalar_eq_approx!(0.12345678, 0.12345679, margin(0.00000001));
+        fn TEST_evaluate_ndarray_eq_approx_1() {
+            let e = multiplier(0.01);
+
+            assert_eq!(NdarrayComparisonResult::ExactlyEqual, evaluate_ndarray_eq_approx(&Array1::<f64>::zeros(0), &Array1::<f64>::zeros(0), &e).0);
+            assert_eq!(NdarrayComparisonResult::ExactlyEqual, evaluate_ndarray_eq_approx(&array![1.0, 2.0], &array![1.0, 2.0], &e).0);
+            assert_eq!(NdarrayComparisonResult::ApproximatelyEqual, evaluate_ndarray_eq_approx(&array![[1.0, 2.0], [3.0, 4.0]], &array![[1.0, 2.0], [3.0, 4.01]], &e).0);
+            assert_eq!(
+                NdarrayComparisonResult::DifferentShapes { expected_shape : vec![2, 3], actual_shape : vec![3, 2] },
+                evaluate_ndarray_eq_approx(&Array2::<f64>::zeros((2, 3)), &Array2::<f64>::zeros((3, 2)), &e).0
+            );
+            assert_eq!(
+                NdarrayComparisonResult::UnequalElements { index : vec![1, 0], expected_value : 3.0, actual_value : 3.5 },
+                evaluate_ndarray_eq_approx(&array![[1.0, 2.0], [3.0, 4.0]], &array![[1.0, 2.0], [3.5, 4.5]], &e).0
+            );
         }
 
         #[test]
-        #[should_panic(expected = "assertion failed: failed to verify approximate equality: expected=0.12345678, actual=0.12345679, margin_factor=0.000000001")]
-        fn TEST_assert_scalar_eq_approx_3_PARAMETER_margin_SHOULD_FAIL_1() {
-            assert_scalar_eq_approx!(0.12345678, 0.12345679, margin(0.000000001));
+        fn TEST_evaluate_ndarray_eq_approx_VIEWS_AND_MIXED_TYPES() {
+            let expected = array![[1.0f32, 2.0], [3.0, 4.0]];
+            let actual = array![[1, 3], [2, 4]];
+
+            assert_eq!(NdarrayComparisonResult::ExactlyEqual, evaluate_ndarray_eq_approx(&expected.view(), &actual.t(), &margin(0.0)).0);
+            assert_eq!(NdarrayComparisonResult::ExactlyEqual, evaluate_ndarray_eq_approx(&expected.column(1), &array![2.0, 4.0], &margin(0.0)).0);
+            assert_eq!(NdarrayComparisonResult::ExactlyEqual, evaluate_ndarray_eq_approx(&expected, &actual.t().into_dyn(), &margin(0.0)).0);
         }
 
         #[test]
-        #[should_panic(expected = "assertion failed: failed to verify approximate inequality: expected=0.12345678, actual=0.12345678, margin_factor=0.0001, multiplier_factor=0.000001")]
-        fn TEST_assert_scalar_ne_approx_2_PARAMETER_FOR_APPROXIMATELY_EQUAL_VALUES_SHOULD_FAIL_1() {
+        fn TEST_assert_ndarray_eq_approx_1() {
+            assert_ndarray_eq_approx!(array![1.0, 2.0, 3.0], array![1.0, 2.0, 3.0]);
+            assert_ndarray_eq_approx!(array![[1.0, 2.0], [3.0, 4.0]], array![[1.0, 2.0], [3.0, 4.01]], multiplier(0.01));
+            assert_ndarray_eq_approx!(Array3::<f64>::ones((2, 3, 4)), Array3::<f64>::ones((2, 3, 4)));
+        }
 
-            assert_scalar_ne_approx!(0.12345678, 0.12345678);
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for arrays: expected-shape [2, 3] differs from actual-shape [3, 2]")]
+        fn TEST_assert_ndarray_eq_approx_DIFFERENT_SHAPES_SHOULD_FAIL() {
+            assert_ndarray_eq_approx!(Array2::<f64>::zeros((2, 3)), Array2::<f64>::zeros((3, 2)));
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for arrays: at [1, 2, 0] expected=1.0, actual=2.0, multiplier_factor=0.01")]
+        fn TEST_assert_ndarray_eq_approx_UNEQUAL_ELEMENTS_SHOULD_FAIL() {
+            let mut actual = Array3::<f64>::ones((2, 3, 4));
+
+            actual[[1, 2, 0]] = 2.0;
+
+            assert_ndarray_eq_approx!(Array3::<f64>::ones((2, 3, 4)), actual, multiplier(0.01));
         }
     }
 
 
-    mod TEST_VECTOR_ASSERTS {
+    mod TEST_MATRIX_ASSERTS {
         #![allow(non_snake_case)]
 
         use super::*;
 
+        use test_helpers::{
+            evaluate_matrix_eq_approx,
+            evaluate_matrix_eq_approx_or_transpose,
+            MatrixComparisonResult,
+            TransposableMatrixComparisonResult,
+        };
+
 
         #[test]
-        fn TEST_assert_vector_eq_approx_2_PARAMETER_EMPTY_ARRAY_INSTANCES() {
-            let expected : [f64; 0] = [];
-            let actual : [f64; 0] = [];
+        fn TEST_evaluate_matrix_eq_approx_1() {
+            let expected = vec![ vec![ 1.0, 2.0 ], vec![ 3.0, 4.0 ] ];
+            let m = margin(0.01);
+
+            assert_eq!(MatrixComparisonResult::ExactlyEqual, evaluate_matrix_eq_approx(&expected, &[ [ 1.0, 2.0 ], [ 3.0, 4.0 ] ], &m).0);
+            assert_eq!(MatrixComparisonResult::ApproximatelyEqual, evaluate_matrix_eq_approx(&expected, &[ [ 1.0, 2.0 ], [ 3.0, 4.001 ] ], &m).0);
+            assert_eq!(MatrixComparisonResult::DifferentRowCounts { expected_row_count : 2, actual_row_count : 1 }, evaluate_matrix_eq_approx(&expected, &[ [ 1.0, 2.0 ] ], &m).0);
+            assert_eq!(MatrixComparisonResult::DifferentRowLengths { row_index : 1, expected_length : 2, actual_length : 3 }, evaluate_matrix_eq_approx(&expected, &[ vec![ 1.0, 2.0 ], vec![ 3.0, 4.0, 5.0 ] ], &m).0);
+            assert_eq!(MatrixComparisonResult::UnequalElements { row_index : 1, column_index : 0, expected_value : 3.0, actual_value : 3.5 }, evaluate_matrix_eq_approx(&expected, &[ [ 1.0, 2.0 ], [ 3.5, 4.0 ] ], &m).0);
+        }
 
-            assert_vector_eq_approx!(expected, actual);
+        #[test]
+        fn TEST_assert_matrix_eq_approx_1() {
+            assert_matrix_eq_approx!(vec![ vec![ 1.0, 2.0 ], vec![ 3.0, 4.0 ] ], [ [ 1.0, 2.0 ], [ 3.0, 4.0000000001 ] ]);
         }
 
         #[test]
-        #[should_panic(expected = "assertion failed: failed to verify approximate inequality for vectors")]
-        fn TEST_assert_vector_ne_approx_2_PARAMETER_EMPTY_ARRAY_INSTANCES() {
-            let expected : [f64; 0] = [];
-            let actual : [f64; 0] = [];
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for matrices: at (1, 0) expected=3.0, actual=3.5, margin_factor=0.01")]
+        fn TEST_assert_matrix_eq_approx_SHOULD_FAIL() {
+            assert_matrix_eq_approx!(vec![ vec![ 1.0, 2.0 ], vec![ 3.0, 4.0 ] ], [ [ 1.0, 2.0 ], [ 3.5, 4.0 ] ], margin(0.01));
+        }
 
-            assert_vector_ne_approx!(expected, actual);
+        #[test]
+        fn TEST_evaluate_matrix_eq_approx_or_transpose_1() {
+            let expected = vec![ vec![ 1.0, 2.0, 3.0 ], vec![ 4.0, 5.0, 6.0 ] ];
+            let m = margin(0.01);
+
+            assert_eq!(TransposableMatrixComparisonResult::ExactlyEqual { transposed : false }, evaluate_matrix_eq_approx_or_transpose(&expected, &expected.clone(), &m).0);
+            assert_eq!(TransposableMatrixComparisonResult::ExactlyEqual { transposed : true }, evaluate_matrix_eq_approx_or_transpose(&expected, &[ [ 1.0, 4.0 ], [ 2.0, 5.0 ], [ 3.0, 6.0 ] ], &m).0);
+            assert_eq!(TransposableMatrixComparisonResult::ApproximatelyEqual { transposed : true }, evaluate_matrix_eq_approx_or_transpose(&expected, &[ [ 1.0, 4.0 ], [ 2.0, 5.001 ], [ 3.0, 6.0 ] ], &m).0);
+            assert_eq!(
+                TransposableMatrixComparisonResult::Unequal {
+                    direct :     MatrixComparisonResult::DifferentRowCounts { expected_row_count : 2, actual_row_count : 3 },
+                    transposed : Some(MatrixComparisonResult::UnequalElements { row_index : 1, column_index : 1, expected_value : 5.0, actual_value : 5.5 }),
+                },
+                evaluate_matrix_eq_approx_or_transpose(&expected, &[ [ 1.0, 4.0 ], [ 2.0, 5.5 ], [ 3.0, 6.0 ] ], &m).0
+            );
+            assert_eq!(
+                TransposableMatrixComparisonResult::Unequal {
+                    direct :     MatrixComparisonResult::DifferentRowLengths { row_index : 1, expected_length : 3, actual_length : 2 },
+                    transposed : None,
+                },
+                evaluate_matrix_eq_approx_or_transpose(&expected, &[ vec![ 1.0, 2.0, 3.0 ], vec![ 4.0, 5.0 ] ], &m).0
+            );
         }
 
         #[test]
-        fn TEST_assert_vector_eq_approx_3_PARAMETER_EMPTY_SLICE_INSTANCES() {
-            let expected : &[f64] = &[];
-            let actual : &[f64] = &[];
+        fn TEST_assert_matrix_eq_approx_or_transpose_WITH_TRANSPOSED_ACTUAL() {
+            // e.g. a wrapper that returns column-major results
+            let expected = vec![ vec![ 1.0, 2.0, 3.0 ], vec![ 4.0, 5.0, 6.0 ] ];
+            let actual = [ [ 1.0, 4.0 ], [ 2.0, 5.0 ], [ 3.0, 6.0000000001 ] ];
 
-            assert_vector_eq_approx!(expected, actual, margin(0.0001));
+            assert_matrix_eq_approx_or_transpose!(expected, actual);
+            assert_matrix_eq_approx_or_transpose!(expected, expected.clone(), margin(0.01));
         }
 
         #[test]
-        fn TEST_assert_vector_eq_approx_2_PARAMETER_EMPTY_Vec_INSTANCES() {
-            let expected : Vec<f64> = Vec::new();
-            let actual : Vec<f64> = Vec::new();
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for matrices or transpose: as given: at (0, 1) expected=2.0, actual=3.0; transposed: at (0, 1) expected=2.0, actual=3.0, margin_factor=0.01")]
+        fn TEST_assert_matrix_eq_approx_or_transpose_SHOULD_FAIL() {
+            assert_matrix_eq_approx_or_transpose!(vec![ vec![ 1.0, 2.0 ], vec![ 3.0, 4.0 ] ], [ [ 1.0, 3.0 ], [ 3.0, 4.0 ] ], margin(0.01));
+        }
 
-            assert_vector_eq_approx!(expected, actual);
+        #[test]
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for matrices or transpose: as given: at (0, 0) expected=1000000.0, actual=1000003.2; transposed: at (0, 0) expected=1000000.0, actual=1000003.2, multiplier_factor=0.000001: error 3.200 ppm exceeds 1.000 ppm")]
+        fn TEST_assert_matrix_eq_approx_or_transpose_WITH_DESCRIPTION_SHOULD_FAIL() {
+            assert_matrix_eq_approx_or_transpose!(vec![ vec![ 1e6 ] ], [ [ 1e6 + 3.2 ] ], ppm(1.0));
         }
+    }
+
+
+    mod TEST_TENSOR3_ASSERTS {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use test_helpers::{
+            evaluate_tensor3_eq_approx,
+            Tensor3ComparisonResult,
+        };
+
 
         #[test]
-        #[should_panic(expected = "assertion failed: failed to verify approximate equality for vectors: expected-length 2 differs from actual-length 1")]
-        fn TEST_assert_vector_eq_approx_2_PARAMETER_SLICE_INSTANCES_DIFFERENT_LENGTHS() {
-            let expected : &[f64] = &[ -2.0, -3.0 ];
-            let actual : &[f64] = &[ 0.0 ];
+        fn TEST_evaluate_tensor3_eq_approx_1() {
+            let expected = vec![ vec![ vec![ 1.0, 2.0 ], vec![ 3.0, 4.0 ] ], vec![ vec![ 5.0, 6.0 ], vec![ 7.0, 8.0 ] ] ];
+            let m = margin(0.01);
+
+            assert_eq!(Tensor3ComparisonResult::ExactlyEqual, evaluate_tensor3_eq_approx(&expected, &expected.clone(), &m).0);
+            assert_eq!(Tensor3ComparisonResult::ApproximatelyEqual, evaluate_tensor3_eq_approx(&expected, &[ [ [ 1.0, 2.0 ], [ 3.0, 4.0 ] ], [ [ 5.0, 6.0 ], [ 7.0, 8.001 ] ] ], &m).0);
+            assert_eq!(Tensor3ComparisonResult::DifferentOuterLengths { expected_length : 2, actual_length : 1 }, evaluate_tensor3_eq_approx(&expected, &[ [ [ 1.0, 2.0 ], [ 3.0, 4.0 ] ] ], &m).0);
+            assert_eq!(Tensor3ComparisonResult::DifferentMiddleLengths { i : 1, expected_length : 2, actual_length : 1 }, evaluate_tensor3_eq_approx(&expected, &[ vec![ [ 1.0, 2.0 ], [ 3.0, 4.0 ] ], vec![ [ 5.0, 6.0 ] ] ], &m).0);
+            assert_eq!(Tensor3ComparisonResult::DifferentInnerLengths { i : 1, j : 1, expected_length : 2, actual_length : 1 }, evaluate_tensor3_eq_approx(&expected, &[ vec![ vec![ 1.0, 2.0 ], vec![ 3.0, 4.0 ] ], vec![ vec![ 5.0, 6.0 ], vec![ 7.0 ] ] ], &m).0);
+            assert_eq!(Tensor3ComparisonResult::UnequalElements { i : 1, j : 0, k : 1, expected_value : 6.0, actual_value : 6.5 }, evaluate_tensor3_eq_approx(&expected, &[ [ [ 1.0, 2.0 ], [ 3.0, 4.0 ] ], [ [ 5.0, 6.5 ], [ 7.0, 8.0 ] ] ], &m).0);
+        }
 
-            assert_vector_eq_approx!(expected, actual);
+        #[test]
+        fn TEST_assert_tensor3_eq_approx_1() {
+            let expected = vec![ vec![ vec![ 1.0, 2.0, 3.0 ] ] ];
+
+            assert_tensor3_eq_approx!(expected, [ [ [ 1.0, 2.0, 3.0000000001 ] ] ]);
+            assert_tensor3_eq_approx!(expected, [ [ [ 1.0, 2.001, 3.0 ] ] ], margin(0.01));
         }
 
         #[test]
-        #[should_panic(expected = "assertion failed: failed to verify approximate equality for vectors: at index 1 expected=-3.0, actual=-3.001, margin_factor=0.01, multiplier_factor=0.0001")]
-        fn TEST_assert_vector_eq_approx_3_PARAMETER_VECTORS_SAME_LENGTH_DIFFERENT_ELEMENTS() {
-            let expected : &[f64] = &[ -2.0, -3.0, -4.0 ];
-            let actual = Vec::from([ -2.0, -3.001, -4.0 ]);
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for tensors: at (0, 1) expected-length 2 differs from actual-length 3")]
+        fn TEST_assert_tensor3_eq_approx_INNER_SHAPE_MISMATCH_SHOULD_FAIL() {
+            let expected = vec![ vec![ vec![ 1.0, 2.0 ], vec![ 3.0, 4.0 ] ] ];
+            let actual = vec![ vec![ vec![ 1.0, 2.0 ], vec![ 3.0, 4.0, 5.0 ] ] ];
 
-            assert_vector_eq_approx!(expected, actual, zero_margin_or_multiplier(0.0001, 0.01));
+            assert_tensor3_eq_approx!(expected, actual, margin(0.01));
         }
 
         #[test]
-        fn TEST_assert_vector_eq_approx_3_PARAMETER_VECTORS_SAME_LENGTH_DIFFERENT_ELEMENTS_WITH_PERMISSIVE_multiplier() {
-            let expected : &[f64] = &[ -2.0, -3.0, -4.0 ];
-            let actual = Vec::from([ -2.0, -3.000001, -4.0 ]);
+        #[should_panic(expected = "assertion failed: failed to verify approximate equality for tensors: at (0, 1, 1) expected=4.0, actual=4.5, margin_factor=0.01")]
+        fn TEST_assert_tensor3_eq_approx_ELEMENT_MISMATCH_SHOULD_FAIL() {
+            let expected = vec![ vec![ vec![ 1.0, 2.0 ], vec![ 3.0, 4.0 ] ] ];
+            let actual = vec![ vec![ vec![ 1.0, 2.0 ], vec![ 3.0, 4.5 ] ] ];
 
-            assert_vector_eq_approx!(expected, actual, multiplier(0.01));
+            assert_tensor3_eq_approx!(expected, actual, margin(0.01));
         }
     }
 
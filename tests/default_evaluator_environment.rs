@@ -0,0 +1,48 @@
+// tests/default_evaluator_environment.rs : test_help-rs
+//
+// NOTE: these tests are in their own process because the default factors
+// are read from the environment only once, at first use.
+
+#![allow(non_snake_case)]
+
+use test_helpers::{
+    assert_scalar_eq_approx,
+    assert_scalar_ne_approx,
+    assert_vector_eq_approx,
+    constants,
+    margin,
+};
+
+
+#[test]
+fn TEST_default_evaluator_WITH_ENVIRONMENT_OVERRIDES() {
+    std::env::set_var(constants::DEFAULT_MARGIN_ENVIRONMENT_VARIABLE, "0.01");
+    std::env::set_var(constants::DEFAULT_MULTIPLIER_ENVIRONMENT_VARIABLE, "0.001");
+
+    // margin (applied when either comparand is zero) is loosened
+    assert_scalar_eq_approx!(0.0, 0.009);
+    assert_scalar_ne_approx!(0.0, 0.011);
+
+    // multiplier is loosened
+    assert_scalar_eq_approx!(1000.0, 1000.9);
+    assert_scalar_ne_approx!(1000.0, 1001.1);
+
+    assert_vector_eq_approx!([0.0, 1000.0], [0.009, 1000.9]);
+
+    // the defaults of `f32` are overridden likewise
+    assert_scalar_eq_approx!(0.0f32, 0.009f32);
+    assert_scalar_ne_approx!(0.0f32, 0.011f32);
+    assert_scalar_eq_approx!(1000.0f32, 1000.9f32);
+    assert_scalar_ne_approx!(1000.0f32, 1001.1f32);
+
+    // explicit evaluators are unaffected
+    assert_scalar_ne_approx!(0.0, 0.009, margin(constants::DEFAULT_MARGIN));
+
+    // the values are cached at first use
+    std::env::set_var(constants::DEFAULT_MARGIN_ENVIRONMENT_VARIABLE, "0.5");
+
+    assert_scalar_ne_approx!(0.0, 0.1);
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -0,0 +1,73 @@
+// tests/message_precision.rs : test_help-rs
+//
+// NOTE: these tests are in their own process because the message precision
+// is set for the whole process.
+
+#![allow(non_snake_case)]
+
+use test_helpers::{
+    assert_scalar_eq_approx,
+    assert_scalar_ne_approx,
+    assert_vector_eq_approx,
+    margin,
+    set_message_precision,
+};
+
+use std::panic;
+
+
+fn panic_message_of<F>(f : F) -> String
+where
+    F : FnOnce() + panic::UnwindSafe,
+{
+    let payload = panic::catch_unwind(f).expect_err("assertion should have failed");
+
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast::<&str>().map(|s| s.to_string()).unwrap(),
+    }
+}
+
+
+#[test]
+fn TEST_set_message_precision() {
+    // full `Debug` by default
+    assert!(panic_message_of(|| {
+        assert_scalar_eq_approx!(0.1 + 0.2, 0.3001, margin(1e-5));
+    })
+    .starts_with("assertion failed: failed to verify approximate equality: expected=0.30000000000000004, actual=0.3001, margin_factor=0.00001"));
+
+    assert_eq!(None, set_message_precision(Some(3)));
+
+    assert!(panic_message_of(|| {
+        assert_scalar_eq_approx!(0.1 + 0.2, 0.3001, margin(1e-5));
+    })
+    .starts_with(
+        "assertion failed: failed to verify approximate equality: expected=0.3, actual=0.3, margin_factor=0.00001"
+    ));
+
+    assert_eq!(
+        "assertion failed: failed to verify approximate inequality: expected=0.667, actual=0.667, margin_factor=0.01",
+        panic_message_of(|| {
+            assert_scalar_ne_approx!(2.0 / 3.0, 0.6667, margin(0.01));
+        })
+    );
+
+    assert_eq!(
+        "assertion failed: failed to verify approximate equality for vectors: at index 1 expected=0.667, actual=0.7, margin_factor=0.01",
+        panic_message_of(|| {
+            assert_vector_eq_approx!([1.0 / 3.0, 2.0 / 3.0], [0.333, 0.7], margin(0.01));
+        })
+    );
+
+    // restored
+    assert_eq!(Some(3), set_message_precision(None));
+
+    assert!(panic_message_of(|| {
+        assert_scalar_eq_approx!(2, 3, margin(0.5));
+    })
+    .starts_with("assertion failed: failed to verify approximate equality: expected=2, actual=3, margin_factor=0.5"));
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -0,0 +1,62 @@
+// tests/metrics_sink.rs : test_help-rs
+//
+// NOTE: these tests are in their own process because the metrics sink is
+// installed for the whole process.
+
+#![allow(non_snake_case)]
+
+use test_helpers::{
+    assert_angles_complementary_approx,
+    assert_delta_approx,
+    assert_scalar_eq_approx,
+    assert_scalar_reciprocal_approx,
+    assert_vector_eq_approx,
+    margin,
+    multiplier,
+    set_metrics_sink,
+    ulps,
+    InMemoryMetricsSink,
+};
+
+use std::sync::Arc;
+
+
+#[test]
+fn TEST_set_metrics_sink_COLLECTS_HEADROOM() {
+    let sink = Arc::new(InMemoryMetricsSink::new());
+
+    assert!(set_metrics_sink(Some(sink.clone())).is_none());
+
+    // delta 0.05 of band 0.1
+    assert_scalar_eq_approx!(1.0, 1.05, margin(0.1));
+
+    // delta 0.0 of band 0.2 (= 0.1 * 2.0)
+    assert_vector_eq_approx!([2.0], [2.0], multiplier(0.1));
+
+    // delta 0.0 of band 0.1, of the sum 90.0
+    assert_angles_complementary_approx!(30.0, 60.0, margin(0.1));
+
+    // delta 0.0 of band 0.1, of the product 1.0
+    assert_scalar_reciprocal_approx!(2.0, 0.5, margin(0.1));
+
+    // delta 0.05 of band 0.1, of the observed delta 0.5
+    assert_delta_approx!(1.0, 1.5, 0.45, margin(0.1));
+
+    // no band can be determined, so not reported
+    assert_scalar_eq_approx!(1.0, 1.0, ulps(4));
+
+    let previous = set_metrics_sink(None);
+
+    assert!(previous.is_some());
+
+    // no longer reported
+    assert_scalar_eq_approx!(1.0, 1.09, margin(0.1));
+
+    assert_eq!(5, sink.num_comparisons());
+    assert_scalar_eq_approx!(0.5, sink.min_headroom().unwrap(), margin(1e-9));
+    assert_scalar_eq_approx!(1.0, sink.max_headroom().unwrap(), margin(1e-9));
+    assert_scalar_eq_approx!(4.0 / 5.0, sink.mean_headroom().unwrap(), margin(1e-9));
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -0,0 +1,37 @@
+// tests/metrics_sink_ignoring.rs : test_help-rs
+//
+// NOTE: these tests are in their own process because the metrics sink is
+// installed for the whole process.
+
+#![allow(non_snake_case)]
+
+use test_helpers::{
+    assert_scalar_eq_approx,
+    assert_vector_eq_approx_ignoring,
+    margin,
+    set_metrics_sink,
+    InMemoryMetricsSink,
+};
+
+use std::sync::Arc;
+
+
+#[test]
+fn TEST_assert_vector_eq_approx_ignoring_DOES_NOT_REPORT_IGNORED_ELEMENTS() {
+    let sink = Arc::new(InMemoryMetricsSink::new());
+
+    assert!(set_metrics_sink(Some(sink.clone())).is_none());
+
+    // delta 0.05 of band 0.1 at index 0, and delta 0.0 of band 0.1 at
+    // index 3; indices 1 and 2 (and the out-of-range 9) are ignored
+    assert_vector_eq_approx_ignoring!([1.0, 2.0, 3.0, 4.0], [1.05, 9.0, -3.0, 4.0], [1, 2, 9], margin(0.1));
+
+    assert!(set_metrics_sink(None).is_some());
+
+    assert_eq!(2, sink.num_comparisons());
+    assert_scalar_eq_approx!(0.5, sink.min_headroom().unwrap(), margin(1e-9));
+    assert_scalar_eq_approx!(1.0, sink.max_headroom().unwrap(), margin(1e-9));
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
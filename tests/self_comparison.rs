@@ -0,0 +1,56 @@
+// tests/self_comparison.rs : test_help-rs
+//
+// NOTE: these tests are in their own process because the self-comparison
+// warning is enabled for the whole process.
+
+#![allow(non_snake_case)]
+
+use test_helpers::{
+    assert_vector_eq_approx,
+    set_warn_on_self_comparison,
+    utils::warn_if_self_comparison,
+};
+
+
+#[test]
+fn TEST_set_warn_on_self_comparison() {
+    let values = vec![1.0, 2.0, 3.0];
+    let aliased = &values;
+    let copy = values.clone();
+
+    // disabled by default
+    assert!(!warn_if_self_comparison("assert_vector_eq_approx!()", &values, aliased));
+
+    assert!(!set_warn_on_self_comparison(true));
+
+    assert!(warn_if_self_comparison("assert_vector_eq_approx!()", &values, aliased));
+    assert!(warn_if_self_comparison(
+        "assert_vector_eq_approx!()",
+        &values[1..],
+        &aliased[1..]
+    ));
+
+    // equal values in distinct memory, overlapping sub-slices, and empty
+    // slices are not self-comparisons
+    assert!(!warn_if_self_comparison("assert_vector_eq_approx!()", &values, &copy));
+    assert!(!warn_if_self_comparison(
+        "assert_vector_eq_approx!()",
+        &values[..2],
+        &values[1..]
+    ));
+    assert!(!warn_if_self_comparison(
+        "assert_vector_eq_approx!()",
+        &values[..0],
+        &aliased[..0]
+    ));
+
+    // the assertion still passes, after writing the warning
+    assert_vector_eq_approx!(values, aliased);
+
+    assert!(set_warn_on_self_comparison(false));
+
+    assert!(!warn_if_self_comparison("assert_vector_eq_approx!()", &values, aliased));
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //